@@ -4,12 +4,12 @@ use frame_support::{
     dispatch::{GetDispatchInfo, Vec, Weight},
     Parameter,
     traits::{EnsureOrigin, UnfilteredDispatchable, Imbalance},
+    traits::fungible::{Balanced, Credit},
 };
-use frame_support::codec::{Decode, Encode};
+use frame_support::codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::dispatch::DispatchResultWithPostInfo;
 use frame_support::scale_info::TypeInfo;
 use frame_support::sp_runtime::Percent;
-use frame_support::traits::tokens::Balance;
 use frame_system::{ensure_signed, RawOrigin};
 use sp_std::boxed::Box;
 
@@ -113,15 +113,15 @@ impl DistributionKey {
     }
 }
 
-pub trait RewardDistributor<I: Imbalance<B>, B: Balance> {
+pub trait RewardDistributor<F: Balanced<AccountId>, AccountId> {
 
-    fn payout_collators(reward: I);
+    fn payout_collators(reward: Credit<AccountId, F>);
 
-    fn payout_reserve(reward: I);
+    fn payout_reserve(reward: Credit<AccountId, F>);
 
-    fn payout_stakers(reward: I);
+    fn payout_stakers(reward: Credit<AccountId, F>);
 
-    fn distribute(amount: I, distribution_key: DistributionKey) {
+    fn distribute(amount: Credit<AccountId, F>, distribution_key: DistributionKey) {
         let amount_balance = amount.peek();
 
         let stakers_part = distribution_key.stakers_percent * amount_balance;
@@ -137,7 +137,23 @@ pub trait RewardDistributor<I: Imbalance<B>, B: Balance> {
 
 }
 
-pub type EuroCent = u32;
+/// A fiat currency, identified by its ISO 4217 code.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub enum FiatCurrency {
+    Eur,
+    Chf,
+    Usd,
+}
+
+/// An amount expressed in the minor unit of a fiat currency (e.g. cents).
+pub type MinorUnits = u32;
+
+/// A fiat price, e.g. `FiatAmount { currency: FiatCurrency::Eur, amount: 100_00 }` for 100.00 euros.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub struct FiatAmount {
+    pub currency: FiatCurrency,
+    pub amount: MinorUnits,
+}
 
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, Copy)]
 pub enum Beneficiary<AccountId> {
@@ -145,10 +161,16 @@ pub enum Beneficiary<AccountId> {
     LegalOfficer(AccountId),
 }
 
-pub trait LegalFee<I: Imbalance<B>, B: Balance, LocType, AccountId> {
+pub trait LegalFee<F: Balanced<AccountId>, LocType, AccountId> {
 
-    fn get_legal_fee(loc_type: LocType) -> EuroCent;
+    fn get_legal_fee(loc_type: LocType) -> FiatAmount;
 
     /// Determine, distribute to, and return the beneficiary of Legal fee.
-    fn distribute(amount: I, loc_type: LocType, loc_owner: AccountId) -> Beneficiary<AccountId>;
+    fn distribute(amount: Credit<AccountId, F>, loc_type: LocType, loc_owner: AccountId) -> Beneficiary<AccountId>;
+}
+
+pub trait LegalFeeSchedule<AccountId, LocType> {
+
+    /// The legal fee published by given legal officer for given LOC type, if any.
+    fn get_legal_fee(legal_officer: &AccountId, loc_type: LocType) -> Option<FiatAmount>;
 }
@@ -3,7 +3,8 @@
 pub use pallet::*;
 
 use frame_support::pallet_prelude::*;
-use frame_support::traits::{Currency, Get};
+use frame_support::traits::Get;
+use frame_support::traits::fungible::{Balanced, Credit, Inspect};
 use frame_system::pallet_prelude::*;
 use sp_std::vec;
 
@@ -23,19 +24,17 @@ pub mod pallet {
     pub struct Pallet<T>(PhantomData<T>);
 
     pub(crate) type BalanceOf<T> =
-        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
-    pub(crate) type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
-        <T as frame_system::Config>::AccountId,
-    >>::NegativeImbalance;
+    pub(crate) type CreditOf<T> = Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// The currency trait.
-        type Currency: Currency<Self::AccountId>;
+        type Currency: Balanced<Self::AccountId>;
 
         /// Used to payout rewards
-        type RewardDistributor: RewardDistributor<NegativeImbalanceOf<Self>, BalanceOf<Self>>;
+        type RewardDistributor: RewardDistributor<Self::Currency, Self::AccountId>;
 
         /// The amount of issuance for each block.
         #[pallet::constant]
@@ -60,7 +59,7 @@ pub mod pallet {
 
     impl<T: Config> Pallet<T> {
 
-        fn distribute(block_reward: NegativeImbalanceOf<T>) {
+        fn distribute(block_reward: CreditOf<T>) {
 
             T::RewardDistributor::distribute(block_reward, T::DistributionKey::get());
         }
@@ -1,7 +1,7 @@
-use crate::{self as pallet_block_reward, NegativeImbalanceOf};
+use crate::{self as pallet_block_reward, CreditOf};
 
 use frame_support::{
-    construct_runtime, parameter_types, traits::Currency,
+    construct_runtime, parameter_types, traits::fungible::Balanced,
 };
 
 use sp_core::H256;
@@ -93,19 +93,19 @@ pub const STAKERS_ACCOUNT: AccountId = 4;
 
 // Type used as beneficiary payout handle
 pub struct RewardDistributorImpl();
-impl RewardDistributor<NegativeImbalanceOf<Test>, Balance>
+impl RewardDistributor<Balances, AccountId>
 for RewardDistributorImpl
 {
-    fn payout_reserve(reward: NegativeImbalanceOf<Test>) {
-        Balances::resolve_creating(&RESERVE_ACCOUNT, reward);
+    fn payout_reserve(reward: CreditOf<Test>) {
+        let _ = Balances::resolve(&RESERVE_ACCOUNT, reward);
     }
 
-    fn payout_collators(reward: NegativeImbalanceOf<Test>) {
-        Balances::resolve_creating(&COLLATORS_ACCOUNT, reward);
+    fn payout_collators(reward: CreditOf<Test>) {
+        let _ = Balances::resolve(&COLLATORS_ACCOUNT, reward);
     }
 
-    fn payout_stakers(reward: NegativeImbalanceOf<Test>) {
-        Balances::resolve_creating(&STAKERS_ACCOUNT, reward);
+    fn payout_stakers(reward: CreditOf<Test>) {
+        let _ = Balances::resolve(&STAKERS_ACCOUNT, reward);
     }
 }
 
@@ -7,7 +7,8 @@ use frame_support::codec::{Decode, Encode};
 use frame_support::dispatch::{DispatchResultWithPostInfo, Vec};
 use frame_support::error::BadOrigin;
 use frame_support::traits::EnsureOrigin;
-use logion_shared::{IsLegalOfficer, LegalOfficerCreation};
+use frame_support::sp_runtime::traits::Convert;
+use logion_shared::{FiatAmount, FiatCurrency, IsLegalOfficer, LegalFeeSchedule, LegalOfficerCreation, MinorUnits};
 use scale_info::TypeInfo;
 use sp_core::OpaquePeerId as PeerId;
 use sp_std::collections::btree_set::BTreeSet;
@@ -83,6 +84,16 @@ pub mod pallet {
         /// The Legal Officers region
         type Region: frame_support::pallet_prelude::Member + frame_support::pallet_prelude::Parameter + Copy + FromStr + Default;
 
+        /// The type of LOC a legal fee applies to
+        type LocType: frame_support::pallet_prelude::Member + frame_support::pallet_prelude::Parameter + Copy;
+
+        /// The currency Legal Officers of a given region publish their legal fees in
+        type RegionalCurrency: Convert<Self::Region, FiatCurrency>;
+
+        /// The lowest and highest legal fees a Legal Officer may publish, in the minor unit of given currency.
+        /// Used for currencies without bounds set with `set_legal_fee_bounds`.
+        type LegalFeeBounds: Convert<FiatCurrency, (MinorUnits, MinorUnits)>;
+
         /// The overarching event type.
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
@@ -109,6 +120,28 @@ pub mod pallet {
     #[pallet::getter(fn legal_officer_nodes)]
     pub type LegalOfficerNodes<T> = StorageValue<_, BTreeSet<PeerId>, ValueQuery>;
 
+    /// Legal fees published by LOs in their regional currency, indexed by LO account ID and LOC type.
+    #[pallet::storage]
+    #[pallet::getter(fn legal_fee_schedule)]
+    pub type LegalFeeSchedules<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        Blake2_128Concat,
+        <T as Config>::LocType,
+        MinorUnits
+    >;
+
+    /// The lowest and highest legal fees set by governance, indexed by currency.
+    #[pallet::storage]
+    #[pallet::getter(fn legal_fee_bounds)]
+    pub type LegalFeeBoundsMap<T> = StorageMap<
+        _,
+        Blake2_128Concat,
+        FiatCurrency,
+        (MinorUnits, MinorUnits)
+    >;
+
     #[derive(Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
     pub enum StorageVersion {
         V1,
@@ -170,6 +203,10 @@ pub mod pallet {
         LoRemoved(T::AccountId),
         /// Issued when an LO is updated. [accountId]
         LoUpdated(T::AccountId),
+        /// Issued when an LO publishes or withdraws a legal fee. [accountId, locType, fee]
+        LegalFeeSet(T::AccountId, T::LocType, Option<MinorUnits>),
+        /// Issued when the legal fee bounds of a currency are set or reset to their default. [currency, bounds]
+        LegalFeeBoundsSet(FiatCurrency, Option<(MinorUnits, MinorUnits)>),
     }
 
     #[pallet::error]
@@ -192,6 +229,10 @@ pub mod pallet {
         GuestCannotUpdate,
         /// LO cannot change region
         CannotChangeRegion,
+        /// Legal fee is lower than the minimum or higher than the maximum
+        LegalFeeOutOfBounds,
+        /// Lowest legal fee is higher than the highest legal fee
+        InvalidLegalFeeBounds,
     }
 
     #[pallet::hooks]
@@ -230,6 +271,7 @@ pub mod pallet {
                 Err(Error::<T>::HostHasGuest)?
             } else {
                 <LegalOfficerSet<T>>::remove(&legal_officer_id);
+                let _ = <LegalFeeSchedules<T>>::clear_prefix(&legal_officer_id, u32::MAX, None);
                 Self::try_reset_legal_officer_nodes(&to_remove.unwrap())?;
 
                 Self::deposit_event(Event::LoRemoved(legal_officer_id));
@@ -293,6 +335,62 @@ pub mod pallet {
                 Ok(().into())
             }
         }
+
+        /// Publishes (or withdraws with `None`) a LO's legal fee for given LOC type
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_legal_fee())]
+        pub fn set_legal_fee(
+            origin: OriginFor<T>,
+            legal_officer_id: T::AccountId,
+            loc_type: T::LocType,
+            fee: Option<MinorUnits>,
+        ) -> DispatchResultWithPostInfo {
+            match T::UpdateOrigin::try_origin(origin) {
+                Ok(_) => (),
+                Err(origin) => {
+                    let who = ensure_signed(origin)?;
+                    if who != legal_officer_id {
+                        Err(BadOrigin)?
+                    }
+                },
+            }
+            let data = <LegalOfficerSet<T>>::get(&legal_officer_id);
+            if data.is_none() {
+                Err(Error::<T>::NotFound)?
+            } else if fee.map_or(false, |fee| !Self::is_legal_fee_in_bounds(&data.unwrap(), fee)) {
+                Err(Error::<T>::LegalFeeOutOfBounds)?
+            } else {
+                match fee {
+                    Some(fee) => <LegalFeeSchedules<T>>::insert(&legal_officer_id, loc_type, fee),
+                    None => <LegalFeeSchedules<T>>::remove(&legal_officer_id, loc_type),
+                }
+
+                Self::deposit_event(Event::LegalFeeSet(legal_officer_id, loc_type, fee));
+                Ok(().into())
+            }
+        }
+
+        /// Sets (or resets to default with `None`) the lowest and highest legal fees LOs may publish in given currency
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_legal_fee_bounds())]
+        pub fn set_legal_fee_bounds(
+            origin: OriginFor<T>,
+            currency: FiatCurrency,
+            bounds: Option<(MinorUnits, MinorUnits)>,
+        ) -> DispatchResultWithPostInfo {
+            T::UpdateOrigin::ensure_origin(origin)?;
+            if bounds.map_or(false, |(min, max)| min > max) {
+                Err(Error::<T>::InvalidLegalFeeBounds)?
+            } else {
+                match bounds {
+                    Some(bounds) => <LegalFeeBoundsMap<T>>::insert(currency, bounds),
+                    None => <LegalFeeBoundsMap<T>>::remove(currency),
+                }
+
+                Self::deposit_event(Event::LegalFeeBoundsSet(currency, bounds));
+                Ok(().into())
+            }
+        }
     }
 }
 
@@ -387,6 +485,17 @@ impl<T: Config> Pallet<T> {
             LegalOfficerData::Host(host_data) => host_data.region,
         }
     }
+
+    fn is_legal_fee_in_bounds(data: &LegalOfficerDataOf<T>, fee: MinorUnits) -> bool {
+        let (min, max) = Self::current_legal_fee_bounds(data);
+        fee >= min && fee <= max
+    }
+
+    fn current_legal_fee_bounds(data: &LegalOfficerDataOf<T>) -> (MinorUnits, MinorUnits) {
+        let currency = T::RegionalCurrency::convert(Self::get_region(data));
+        Self::legal_fee_bounds(currency)
+            .unwrap_or_else(|| T::LegalFeeBounds::convert(currency))
+    }
 }
 
 impl<T: Config> EnsureOrigin<T::RuntimeOrigin> for Pallet<T> {
@@ -425,4 +534,22 @@ impl<T: Config> LegalOfficerCreation<T::AccountId> for Pallet<T> {
 
         Pallet::<T>::do_add_legal_officer(guest_legal_officer_id, LegalOfficerData::Guest(host_legal_officer_id))
     }
-}
\ No newline at end of file
+}
+
+impl<T: Config> LegalFeeSchedule<T::AccountId, T::LocType> for Pallet<T> {
+
+    /// Fees set before the bounds were tightened are clamped to the current bounds.
+    fn get_legal_fee(legal_officer: &T::AccountId, loc_type: T::LocType) -> Option<FiatAmount> {
+        let data = LegalOfficerSet::<T>::get(legal_officer)?;
+        let amount = LegalFeeSchedules::<T>::get(legal_officer, loc_type)
+            .or_else(|| match &data {
+                LegalOfficerData::Guest(host) => LegalFeeSchedules::<T>::get(host, loc_type),
+                _ => None,
+            })?;
+        let (min, max) = Self::current_legal_fee_bounds(&data);
+        Some(FiatAmount {
+            currency: T::RegionalCurrency::convert(Self::get_region(&data)),
+            amount: amount.clamp(min, max),
+        })
+    }
+}
@@ -2,10 +2,11 @@ use crate::{self as pallet_lo_authority_list, HostData, HostDataOf};
 use sp_core::hash::H256;
 use frame_support::{parameter_types, codec::{Encode, Decode}};
 use sp_runtime::{
-    traits::{BlakeTwo256, IdentityLookup}, testing::Header,
+    traits::{BlakeTwo256, Convert, IdentityLookup}, testing::Header,
 };
 use frame_system::{self as system, EnsureRoot};
 use scale_info::TypeInfo;
+use logion_shared::{FiatCurrency, MinorUnits};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -71,6 +72,27 @@ impl core::str::FromStr for Region {
     }
 }
 
+pub struct RegionalCurrency;
+impl Convert<Region, FiatCurrency> for RegionalCurrency {
+    fn convert(region: Region) -> FiatCurrency {
+        match region {
+            Region::Europe => FiatCurrency::Eur,
+            Region::Other => FiatCurrency::Usd,
+        }
+    }
+}
+
+pub struct LegalFeeBounds;
+impl Convert<FiatCurrency, (MinorUnits, MinorUnits)> for LegalFeeBounds {
+    fn convert(currency: FiatCurrency) -> (MinorUnits, MinorUnits) {
+        match currency {
+            FiatCurrency::Eur => (5_00, 500_00),
+            FiatCurrency::Chf => (5_00, 500_00),
+            FiatCurrency::Usd => (6_00, 600_00),
+        }
+    }
+}
+
 impl Default for Region {
 
     fn default() -> Self {
@@ -78,6 +100,13 @@ impl Default for Region {
     }
 }
 
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, Copy)]
+pub enum LocType {
+    Transaction,
+    Identity,
+    Collection,
+}
+
 impl Default for HostDataOf<Test> {
 
     fn default() -> Self {
@@ -94,6 +123,9 @@ impl pallet_lo_authority_list::Config for Test {
     type RemoveOrigin = EnsureRoot<u64>;
     type UpdateOrigin = EnsureRoot<u64>;
     type Region = Region;
+    type LocType = LocType;
+    type RegionalCurrency = RegionalCurrency;
+    type LegalFeeBounds = LegalFeeBounds;
     type RuntimeEvent = RuntimeEvent;
     type WeightInfo = ();
 }
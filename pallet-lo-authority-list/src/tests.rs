@@ -1,6 +1,6 @@
 use crate::{mock::*, LegalOfficerData, Error, HostData, LegalOfficerDataOf, HostDataOf};
 use frame_support::{assert_err, assert_ok, error::BadOrigin};
-use logion_shared::IsLegalOfficer;
+use logion_shared::{FiatAmount, FiatCurrency, IsLegalOfficer, LegalFeeSchedule};
 use sp_core::OpaquePeerId;
 
 const LEGAL_OFFICER_ID: u64 = 1;
@@ -412,3 +412,171 @@ fn it_fails_changing_guest_guest_region() {
         assert_err!(LoAuthorityList::update_legal_officer(RuntimeOrigin::root(), LEGAL_OFFICER_ID3, LegalOfficerData::Guest(LEGAL_OFFICER_ID2)), Error::<Test>::CannotChangeRegion);
     });
 }
+
+#[test]
+fn it_lets_host_set_legal_fee() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(150_00)));
+        assert_eq!(LoAuthorityList::legal_fee_schedule(LEGAL_OFFICER_ID, LocType::Transaction), Some(150_00));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID, LocType::Transaction), Some(euros(150_00)));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID, LocType::Collection), None);
+    });
+}
+
+#[test]
+fn it_lets_superuser_set_legal_fee() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::root(), LEGAL_OFFICER_ID, LocType::Transaction, Some(150_00)));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID, LocType::Transaction), Some(euros(150_00)));
+    });
+}
+
+#[test]
+fn it_lets_host_withdraw_legal_fee() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(150_00)));
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, None));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID, LocType::Transaction), None);
+    });
+}
+
+#[test]
+fn it_lets_guest_inherit_host_legal_fee() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(150_00)));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID2, LocType::Transaction), Some(euros(150_00)));
+    });
+}
+
+#[test]
+fn it_lets_guest_override_host_legal_fee() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(150_00)));
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID2), LEGAL_OFFICER_ID2, LocType::Transaction, Some(80_00)));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID, LocType::Transaction), Some(euros(150_00)));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID2, LocType::Transaction), Some(euros(80_00)));
+    });
+}
+
+#[test]
+fn it_fails_setting_legal_fee_of_other_legal_officer() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_err!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID2), LEGAL_OFFICER_ID, LocType::Transaction, Some(150_00)), BadOrigin);
+    });
+}
+
+#[test]
+fn it_fails_setting_legal_fee_of_unknown_legal_officer() {
+    new_test_ext().execute_with(|| {
+        assert_err!(LoAuthorityList::set_legal_fee(RuntimeOrigin::root(), LEGAL_OFFICER_ID, LocType::Transaction, Some(150_00)), Error::<Test>::NotFound);
+    });
+}
+
+#[test]
+fn it_fails_setting_legal_fee_out_of_bounds() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_err!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(5_00 - 1)), Error::<Test>::LegalFeeOutOfBounds);
+        assert_err!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(500_00 + 1)), Error::<Test>::LegalFeeOutOfBounds);
+    });
+}
+
+#[test]
+fn it_bounds_legal_fee_in_regional_currency() {
+    new_test_ext().execute_with(|| {
+        setup_hosts();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID2), LEGAL_OFFICER_ID2, LocType::Transaction, Some(550_00)));
+        assert_err!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID2), LEGAL_OFFICER_ID2, LocType::Transaction, Some(5_50)), Error::<Test>::LegalFeeOutOfBounds);
+        assert_err!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(550_00)), Error::<Test>::LegalFeeOutOfBounds);
+    });
+}
+
+#[test]
+fn it_lets_superuser_set_legal_fee_bounds() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee_bounds(RuntimeOrigin::root(), FiatCurrency::Eur, Some((1_00, 1000_00))));
+        assert_eq!(LoAuthorityList::legal_fee_bounds(FiatCurrency::Eur), Some((1_00, 1000_00)));
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(1_00)));
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Collection, Some(1000_00)));
+        assert_err!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Identity, Some(1000_00 + 1)), Error::<Test>::LegalFeeOutOfBounds);
+    });
+}
+
+#[test]
+fn it_resets_legal_fee_bounds_to_default() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee_bounds(RuntimeOrigin::root(), FiatCurrency::Eur, Some((1_00, 1000_00))));
+        assert_ok!(LoAuthorityList::set_legal_fee_bounds(RuntimeOrigin::root(), FiatCurrency::Eur, None));
+        assert_eq!(LoAuthorityList::legal_fee_bounds(FiatCurrency::Eur), None);
+        assert_err!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(1_00)), Error::<Test>::LegalFeeOutOfBounds);
+    });
+}
+
+#[test]
+fn it_clamps_legal_fee_set_before_bounds_change() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Transaction, Some(5_00)));
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Collection, Some(500_00)));
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID), LEGAL_OFFICER_ID, LocType::Identity, Some(150_00)));
+
+        assert_ok!(LoAuthorityList::set_legal_fee_bounds(RuntimeOrigin::root(), FiatCurrency::Eur, Some((10_00, 200_00))));
+
+        assert_eq!(LoAuthorityList::legal_fee_schedule(LEGAL_OFFICER_ID, LocType::Transaction), Some(5_00));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID, LocType::Transaction), Some(euros(10_00)));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID, LocType::Collection), Some(euros(200_00)));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID, LocType::Identity), Some(euros(150_00)));
+    });
+}
+
+#[test]
+fn it_fails_setting_legal_fee_bounds_if_not_superuser() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_err!(LoAuthorityList::set_legal_fee_bounds(RuntimeOrigin::signed(LEGAL_OFFICER_ID), FiatCurrency::Eur, Some((1_00, 1000_00))), BadOrigin);
+    });
+}
+
+#[test]
+fn it_fails_setting_invalid_legal_fee_bounds() {
+    new_test_ext().execute_with(|| {
+        assert_err!(LoAuthorityList::set_legal_fee_bounds(RuntimeOrigin::root(), FiatCurrency::Eur, Some((1000_00, 1_00))), Error::<Test>::InvalidLegalFeeBounds);
+    });
+}
+
+#[test]
+fn it_removes_legal_fees_with_legal_officer() {
+    new_test_ext().execute_with(|| {
+        setup_host_and_guest();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID2), LEGAL_OFFICER_ID2, LocType::Transaction, Some(80_00)));
+        assert_ok!(LoAuthorityList::remove_legal_officer(RuntimeOrigin::root(), LEGAL_OFFICER_ID2));
+        assert_eq!(LoAuthorityList::legal_fee_schedule(LEGAL_OFFICER_ID2, LocType::Transaction), None);
+    });
+}
+
+fn euros(amount: u32) -> FiatAmount {
+    FiatAmount {
+        currency: FiatCurrency::Eur,
+        amount,
+    }
+}
+
+#[test]
+fn it_publishes_legal_fee_in_regional_currency() {
+    new_test_ext().execute_with(|| {
+        setup_hosts();
+        assert_ok!(LoAuthorityList::set_legal_fee(RuntimeOrigin::signed(LEGAL_OFFICER_ID2), LEGAL_OFFICER_ID2, LocType::Transaction, Some(120_00)));
+        assert_eq!(LoAuthorityList::get_legal_fee(&LEGAL_OFFICER_ID2, LocType::Transaction), Some(FiatAmount {
+            currency: FiatCurrency::Usd,
+            amount: 120_00,
+        }));
+    });
+}
@@ -10,6 +10,8 @@ pub trait WeightInfo {
     fn add_legal_officer() -> Weight;
     fn remove_legal_officer() -> Weight;
     fn update_legal_officer() -> Weight;
+    fn set_legal_fee() -> Weight;
+    fn set_legal_fee_bounds() -> Weight;
 }
 
 /// Weights for pallet_logion_loc using the Substrate node and recommended hardware.
@@ -24,13 +26,22 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn remove_legal_officer() -> Weight {
         Weight::from_parts(20_945_000, 0)
             .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(2))
     }
     fn update_legal_officer() -> Weight {
         Weight::from_parts(26_316_000, 0)
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
+    fn set_legal_fee() -> Weight {
+        Weight::from_parts(18_000_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn set_legal_fee_bounds() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
 }
 
 // For backwards compatibility and tests
@@ -43,11 +54,20 @@ impl WeightInfo for () {
     fn remove_legal_officer() -> Weight {
         Weight::from_parts(20_945_000, 0)
             .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
     }
     fn update_legal_officer() -> Weight {
         Weight::from_parts(26_316_000, 0)
             .saturating_add(RocksDbWeight::get().reads(3))
             .saturating_add(RocksDbWeight::get().writes(2))
     }
+    fn set_legal_fee() -> Weight {
+        Weight::from_parts(18_000_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn set_legal_fee_bounds() -> Weight {
+        Weight::from_parts(12_000_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
 }
@@ -1,103 +1,704 @@
 use super::*;
 
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
-use frame_support::{assert_ok, traits::EnsureOrigin};
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, BenchmarkError};
+use frame_support::{assert_ok, traits::Hooks};
 use frame_system::RawOrigin;
-use sp_std::{vec, vec::Vec, boxed::Box};
+use logion_shared::{FiatCurrency, IsLegalOfficer};
+use sp_runtime::{traits::Bounded, KeyTypeId};
+use sp_std::vec::Vec;
 
 use crate::Pallet as LogionLoc;
-use crate::Config;
+
+const SEED: u32 = 0;
+const ETHEREUM_KEY_TYPE: KeyTypeId = KeyTypeId(*b"ethr");
+const ETHEREUM_KEY_SEED: &[u8] = b"//Ethereum";
 
 benchmarks! {
     create_polkadot_identity_loc {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-    }: _(caller, Default::default(), Default::default())
+        let legal_officer = legal_officer::<T>();
+        let requester = requester::<T>();
+        let sponsorship_id = sponsor_requester::<T>(&legal_officer, &requester);
+        let loc_id = into_id::<T::LocId>(0);
+    }: _(RawOrigin::Signed(requester), loc_id, legal_officer, Some(sponsorship_id))
+    verify {
+        assert!(LocMap::<T>::contains_key(loc_id));
+    }
 
     create_logion_identity_loc {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-    }: _(caller, Default::default())
+        let legal_officer = legal_officer::<T>();
+        let loc_id = into_id::<T::LocId>(0);
+    }: _(RawOrigin::Signed(legal_officer), loc_id)
+    verify {
+        assert!(LocMap::<T>::contains_key(loc_id));
+    }
 
     create_polkadot_transaction_loc {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-    }: _(caller, Default::default(), Default::default())
+        let legal_officer = legal_officer::<T>();
+        let requester = requester::<T>();
+        let sponsorship_id = sponsor_requester::<T>(&legal_officer, &requester);
+        let loc_id = into_id::<T::LocId>(0);
+    }: _(RawOrigin::Signed(requester), loc_id, legal_officer, Some(sponsorship_id))
+    verify {
+        assert!(LocMap::<T>::contains_key(loc_id));
+    }
 
     create_logion_transaction_loc {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-        let identity_loc_id = into_loc_id::<T>(0);
-        assert_ok!(LogionLoc::<T>::create_logion_identity_loc(caller.clone().into(), identity_loc_id));
-        assert_ok!(LogionLoc::<T>::close(caller.clone().into(), identity_loc_id));
-        let loc_id = into_loc_id::<T>(1);
-    }: _(caller, loc_id, identity_loc_id)
+        let legal_officer = legal_officer::<T>();
+        let identity_loc_id = into_id::<T::LocId>(0);
+        assert_ok!(LogionLoc::<T>::create_logion_identity_loc(RawOrigin::Signed(legal_officer.clone()).into(), identity_loc_id));
+        assert_ok!(LogionLoc::<T>::close(RawOrigin::Signed(legal_officer.clone()).into(), identity_loc_id));
+        let loc_id = into_id::<T::LocId>(1);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, identity_loc_id)
+    verify {
+        assert!(LocMap::<T>::contains_key(loc_id));
+    }
+
+    create_collection_loc {
+        let legal_officer = legal_officer::<T>();
+        let requester = requester::<T>();
+        let sponsorship_id = sponsor_requester::<T>(&legal_officer, &requester);
+        let loc_id = into_id::<T::LocId>(0);
+    }: _(RawOrigin::Signed(requester), loc_id, legal_officer, None, Some(1), false, Some(sponsorship_id))
+    verify {
+        assert!(LocMap::<T>::contains_key(loc_id));
+    }
 
     add_metadata {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-        let loc_id = Default::default();
+        let m in 0 .. T::MaxLocMetadata::get() - 1;
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+        add_metadata_items::<T>(loc_id, &legal_officer, m);
         let item = MetadataItemParams {
-            name: vec![1u8, 2u8, 3u8],
-            value: vec![4u8, 5u8, 6u8],
-            submitter: Default::default(),
+            name: into_hash::<T>(m),
+            value: into_hash::<T>(m),
+            submitter: SupportedAccountId::Polkadot(legal_officer.clone()),
         };
-        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
-    }: _(caller, loc_id, item)
+    }: _(RawOrigin::Signed(legal_officer), loc_id, item)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().metadata.len(), (m + 1) as usize);
+    }
 
     add_file {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-        let loc_id = Default::default();
-        let file = FileParams {
-            hash: Default::default(),
-            nature: vec![1u8, 2u8, 3u8],
-            submitter: Default::default(),
-        };
-        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
-    }: _(caller, loc_id, file)
+        let f in 0 .. T::MaxLocFiles::get() - 1;
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+        add_files::<T>(loc_id, &legal_officer, f);
+        let file = file_params::<T>(f, &legal_officer);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, file)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().files.len(), (f + 1) as usize);
+    }
 
     add_link {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-        let linked_loc_id = into_loc_id::<T>(0);
+        let l in 0 .. T::MaxLocLinks::get() - 1;
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+        add_links::<T>(loc_id, &legal_officer, l);
+        let linked_loc_id = into_id::<T::LocId>(l + 1);
+        assert_ok!(LogionLoc::<T>::create_logion_identity_loc(RawOrigin::Signed(legal_officer.clone()).into(), linked_loc_id));
         let link = LocLink {
-            id: linked_loc_id.clone(),
-            nature: vec![1u8, 2u8, 3u8],
+            id: linked_loc_id,
+            nature: into_hash::<T>(l),
         };
-        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), linked_loc_id, Default::default()));
-        let loc_id = into_loc_id::<T>(1);
-        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
-    }: _(caller, loc_id, link)
+    }: _(RawOrigin::Signed(legal_officer), loc_id, link)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().links.len(), (l + 1) as usize);
+    }
 
     close {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-        let loc_id = Default::default();
-        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
-    }: _(caller, loc_id)
+        let m in 0 .. T::MaxLocMetadata::get();
+        let f in 0 .. T::MaxLocFiles::get();
+        let l in 0 .. T::MaxLocLinks::get();
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+        add_metadata_items::<T>(loc_id, &legal_officer, m);
+        add_files::<T>(loc_id, &legal_officer, f);
+        add_links::<T>(loc_id, &legal_officer, l);
+    }: _(RawOrigin::Signed(legal_officer), loc_id)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().status, LocStatus::Closed);
+    }
+
+    close_and_seal {
+        let m in 0 .. T::MaxLocMetadata::get();
+        let f in 0 .. T::MaxLocFiles::get();
+        let l in 0 .. T::MaxLocLinks::get();
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+        add_metadata_items::<T>(loc_id, &legal_officer, m);
+        add_files::<T>(loc_id, &legal_officer, f);
+        add_links::<T>(loc_id, &legal_officer, l);
+        let seal = Seal {
+            version: SealVersion::V1,
+            hash: into_hash::<T>(0),
+        };
+    }: _(RawOrigin::Signed(legal_officer), loc_id, seal)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().seal, Some(seal));
+    }
 
     make_void {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-        let loc_id = Default::default();
-        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
-    }: _(caller, loc_id)
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+    }: _(RawOrigin::Signed(legal_officer), loc_id)
+    verify {
+        assert!(LogionLoc::<T>::loc(loc_id).unwrap().void_info.is_some());
+    }
 
     make_void_and_replace {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-        let replacer_loc_id = into_loc_id::<T>(0);
-        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), replacer_loc_id, Default::default()));
-        let loc_id = into_loc_id::<T>(1);
-        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(caller.clone().into(), loc_id, Default::default()));
-    }: _(caller, loc_id, replacer_loc_id)
-
-    create_collection_loc {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-    }: _(caller, Default::default(), Default::default(), Option::None, Option::Some(1))
+        let (loc_id, legal_officer, requester) = open_transaction_loc::<T>(0);
+        let replacer_loc_id = into_id::<T::LocId>(1);
+        assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(RawOrigin::Signed(requester).into(), replacer_loc_id, legal_officer.clone(), None));
+    }: _(RawOrigin::Signed(legal_officer), loc_id, replacer_loc_id)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(replacer_loc_id).unwrap().replacer_of, Some(loc_id));
+    }
 
     add_collection_item {
-        let caller = <T as crate::Config>::CreateOrigin::successful_origin().into().ok().unwrap();
-        let loc_id = into_loc_id::<T>(0);
-        let requester: T::AccountId = whitelisted_caller();
-        assert_ok!(LogionLoc::<T>::create_collection_loc(caller.clone().into(), loc_id, requester.clone(), Option::None, Option::Some(1)));
-        assert_ok!(LogionLoc::<T>::close(caller.clone().into(), loc_id));
-    }: _(RawOrigin::Signed(requester), loc_id, Default::default(), Default::default())
+        let f in 0 .. T::MaxCollectionItemFiles::get();
+        let t in 0 .. T::MaxCollectionItemTCs::get();
+        let (collection_loc_id, legal_officer, requester) = closed_collection_loc::<T>(0);
+        CollectionAccumulatorsMap::<T>::insert(collection_loc_id, full_accumulator::<T>());
+        let item_files: Vec<CollectionItemFileOf<T>> = (0..f).map(|i| CollectionItemFile {
+            name: into_hash::<T>(i),
+            content_type: into_hash::<T>(i),
+            size: 1,
+            hash: into_hash::<T>(i),
+        }).collect();
+        let terms_and_conditions: Vec<TermsAndConditionsElementOf<T>> = (0..t).map(|i| {
+            let tc_loc = into_id::<T::LocId>(i + 1);
+            assert_ok!(LogionLoc::<T>::create_logion_identity_loc(RawOrigin::Signed(legal_officer.clone()).into(), tc_loc));
+            assert_ok!(LogionLoc::<T>::close(RawOrigin::Signed(legal_officer.clone()).into(), tc_loc));
+            TermsAndConditionsElement {
+                tc_type: into_hash::<T>(i),
+                tc_loc,
+                details: into_hash::<T>(i),
+            }
+        }).collect();
+        let item_id = into_id::<T::CollectionItemId>(0);
+    }: _(RawOrigin::Signed(requester), collection_loc_id, item_id, Default::default(), item_files, None, false, terms_and_conditions)
+    verify {
+        assert!(CollectionItemsMap::<T>::contains_key(collection_loc_id, item_id));
+        assert_eq!(LogionLoc::<T>::collection_item_leaf_index(collection_loc_id, item_id), Some(u32::MAX));
+    }
+
+    nominate_issuer {
+        let (issuer, legal_officer, identity_loc_id) = issuer_with_identity_loc::<T>(0);
+    }: _(RawOrigin::Signed(legal_officer.clone()), issuer.clone(), identity_loc_id)
+    verify {
+        assert!(LogionLoc::<T>::verified_issuers(legal_officer, issuer).is_some());
+    }
+
+    dismiss_issuer {
+        let l in 0 .. T::MaxIssuerLocs::get();
+        let (issuer, legal_officer) = issuer_selected_on_locs::<T>(l);
+    }: _(RawOrigin::Signed(legal_officer.clone()), issuer.clone())
+    verify {
+        assert!(LogionLoc::<T>::verified_issuers(legal_officer, issuer).is_none());
+    }
+
+    set_issuer_selection {
+        let l in 0 .. T::MaxIssuerLocs::get() - 1;
+        let (issuer, legal_officer) = issuer_selected_on_locs::<T>(l);
+        let loc_id = into_id::<T::LocId>(T::MaxIssuerLocs::get() + 1);
+        assert_ok!(LogionLoc::<T>::create_logion_identity_loc(RawOrigin::Signed(legal_officer.clone()).into(), loc_id));
+    }: _(RawOrigin::Signed(legal_officer), loc_id, issuer.clone(), true)
+    verify {
+        assert!(LogionLoc::<T>::verified_issuers_by_loc(loc_id, issuer).is_some());
+    }
+
+    add_tokens_record {
+        let f in 1 .. T::MaxTokensRecordFiles::get();
+        let (collection_loc_id, _, requester) = closed_collection_loc::<T>(0);
+        let files: Vec<TokensRecordFileOf<T>> = (0..f).map(|i| TokensRecordFile {
+            name: into_hash::<T>(i),
+            content_type: into_hash::<T>(i),
+            size: 1,
+            hash: into_hash::<T>(i),
+        }).collect();
+        let record_id = into_id::<T::TokensRecordId>(0);
+    }: _(RawOrigin::Signed(requester), collection_loc_id, record_id, Default::default(), files)
+    verify {
+        assert!(TokensRecordsMap::<T>::contains_key(collection_loc_id, record_id));
+    }
+
+    create_other_identity_loc {
+        let legal_officer = legal_officer::<T>();
+        let sponsor = requester::<T>();
+        let requester_account_id = OtherAccountId::Ethereum(Default::default());
+        let sponsorship_id = into_id::<T::SponsorshipId>(0);
+        assert_ok!(LogionLoc::<T>::sponsor(RawOrigin::Signed(sponsor).into(), sponsorship_id, SupportedAccountId::Other(requester_account_id.clone()), legal_officer.clone(), sponsorship_expiry::<T>(), BalanceOf::<T>::max_value(), None));
+        let loc_id = into_id::<T::LocId>(0);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, requester_account_id, sponsorship_id)
+    verify {
+        assert!(LocMap::<T>::contains_key(loc_id));
+    }
+
+    create_other_transaction_loc {
+        let legal_officer = legal_officer::<T>();
+        let requester_account_id = OtherAccountId::Ethereum(Default::default());
+        let sponsorship_id = sponsor_other_account::<T>(&legal_officer, &requester_account_id);
+        let loc_id = into_id::<T::LocId>(0);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, requester_account_id, Some(sponsorship_id))
+    verify {
+        assert!(LocMap::<T>::contains_key(loc_id));
+    }
+
+    create_other_collection_loc {
+        let legal_officer = legal_officer::<T>();
+        let requester_account_id = OtherAccountId::Ethereum(Default::default());
+        let sponsorship_id = sponsor_other_account::<T>(&legal_officer, &requester_account_id);
+        let loc_id = into_id::<T::LocId>(0);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, requester_account_id, None, Some(1), true, Some(sponsorship_id))
+    verify {
+        assert!(LocMap::<T>::contains_key(loc_id));
+    }
+
+    sponsor {
+        let legal_officer = legal_officer::<T>();
+        let sponsor = requester::<T>();
+        sponsorships_expiring::<T>(&sponsor, &legal_officer, 1, T::MaxExpiringSponsorships::get() - 1);
+        let sponsorship_id = into_id::<T::SponsorshipId>(0);
+    }: _(RawOrigin::Signed(sponsor), sponsorship_id, SupportedAccountId::Other(OtherAccountId::Ethereum(Default::default())), legal_officer, sponsorship_expiry::<T>(), BalanceOf::<T>::max_value(), None)
+    verify {
+        assert!(SponsorshipMap::<T>::contains_key(sponsorship_id));
+    }
+
+    withdraw_sponsorship {
+        let legal_officer = legal_officer::<T>();
+        let sponsor = requester::<T>();
+        let sponsorship_id = into_id::<T::SponsorshipId>(0);
+        assert_ok!(LogionLoc::<T>::sponsor(RawOrigin::Signed(sponsor.clone()).into(), sponsorship_id, SupportedAccountId::Other(OtherAccountId::Ethereum(Default::default())), legal_officer, sponsorship_expiry::<T>(), BalanceOf::<T>::max_value(), None));
+    }: _(RawOrigin::Signed(sponsor), sponsorship_id)
+    verify {
+        assert!(!SponsorshipMap::<T>::contains_key(sponsorship_id));
+    }
+
+    expire_sponsorships {
+        let s in 0 .. T::MaxExpiringSponsorships::get();
+        let legal_officer = legal_officer::<T>();
+        let sponsor = requester::<T>();
+        sponsorships_expiring::<T>(&sponsor, &legal_officer, 0, s);
+    }: {
+        LogionLoc::<T>::on_initialize(sponsorship_expiry::<T>());
+    }
+    verify {
+        assert_eq!(SponsorshipMap::<T>::iter().count(), 0);
+    }
+
+    acknowledge_metadata {
+        let m in 1 .. T::MaxLocMetadata::get();
+        let (loc_id, legal_officer, requester) = open_transaction_loc::<T>(0);
+        add_metadata_items::<T>(loc_id, &requester, m);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, into_hash::<T>(m - 1))
+    verify {
+        assert!(LogionLoc::<T>::loc(loc_id).unwrap().metadata[(m - 1) as usize].acknowledged);
+    }
+
+    acknowledge_file {
+        let f in 1 .. T::MaxLocFiles::get();
+        let (loc_id, legal_officer, requester) = open_transaction_loc::<T>(0);
+        add_files::<T>(loc_id, &requester, f);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, into_hash::<T>(f - 1))
+    verify {
+        assert!(LogionLoc::<T>::loc(loc_id).unwrap().files[(f - 1) as usize].acknowledged);
+    }
+
+    delete_metadata {
+        let m in 1 .. T::MaxLocMetadata::get();
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+        add_metadata_items::<T>(loc_id, &legal_officer, m);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, into_hash::<T>(m - 1))
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().metadata.len(), (m - 1) as usize);
+    }
+
+    delete_file {
+        let f in 1 .. T::MaxLocFiles::get();
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+        add_files::<T>(loc_id, &legal_officer, f);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, into_hash::<T>(f - 1))
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().files.len(), (f - 1) as usize);
+    }
+
+    delete_link {
+        let l in 1 .. T::MaxLocLinks::get();
+        let (loc_id, legal_officer, _) = open_transaction_loc::<T>(0);
+        add_links::<T>(loc_id, &legal_officer, l);
+    }: _(RawOrigin::Signed(legal_officer), loc_id, into_id::<T::LocId>(l))
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().links.len(), (l - 1) as usize);
+    }
+
+    add_metadata_with_ethereum_signature {
+        let m in 0 .. T::MaxLocMetadata::get() - 1;
+        let (loc_id, legal_officer, submitter) = ethereum_identity_loc::<T>(0);
+        add_metadata_items::<T>(loc_id, &legal_officer, m);
+        let item = MetadataItemParams {
+            name: into_hash::<T>(m),
+            value: into_hash::<T>(m),
+            submitter,
+        };
+        let signature = ethereum_sign(LogionLoc::<T>::ethereum_signable_hash(b"add_metadata", &(loc_id, &item), 0));
+    }: _(RawOrigin::Signed(legal_officer), loc_id, item, 0, signature)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().metadata.len(), (m + 1) as usize);
+    }
+
+    add_file_with_ethereum_signature {
+        let f in 0 .. T::MaxLocFiles::get() - 1;
+        let (loc_id, legal_officer, submitter) = ethereum_identity_loc::<T>(0);
+        add_files::<T>(loc_id, &legal_officer, f);
+        let file = FileParams {
+            submitter,
+            ..file_params::<T>(f, &legal_officer)
+        };
+        let signature = ethereum_sign(LogionLoc::<T>::ethereum_signable_hash(b"add_file", &(loc_id, &file), 0));
+    }: _(RawOrigin::Signed(legal_officer), loc_id, file, 0, signature)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().files.len(), (f + 1) as usize);
+    }
+
+    add_collection_item_with_ethereum_signature {
+        let f in 0 .. T::MaxCollectionItemFiles::get();
+        let t in 0 .. T::MaxCollectionItemTCs::get();
+        let (collection_loc_id, legal_officer, submitter) = closed_ethereum_collection_loc::<T>(0);
+        CollectionAccumulatorsMap::<T>::insert(collection_loc_id, full_accumulator::<T>());
+        let item_files: Vec<CollectionItemFileOf<T>> = (0..f).map(|i| CollectionItemFile {
+            name: into_hash::<T>(i),
+            content_type: into_hash::<T>(i),
+            size: 1,
+            hash: into_hash::<T>(i),
+        }).collect();
+        let terms_and_conditions: Vec<TermsAndConditionsElementOf<T>> = (0..t).map(|i| {
+            let tc_loc = into_id::<T::LocId>(i + 1);
+            assert_ok!(LogionLoc::<T>::create_logion_identity_loc(RawOrigin::Signed(legal_officer.clone()).into(), tc_loc));
+            assert_ok!(LogionLoc::<T>::close(RawOrigin::Signed(legal_officer.clone()).into(), tc_loc));
+            TermsAndConditionsElement {
+                tc_type: into_hash::<T>(i),
+                tc_loc,
+                details: into_hash::<T>(i),
+            }
+        }).collect();
+        let item_id = into_id::<T::CollectionItemId>(0);
+        let item_description: <T as pallet::Config>::Hash = Default::default();
+        let item_token: Option<CollectionItemToken<T::TokenIssuance, <T as pallet::Config>::Hash>> = None;
+        let item = (collection_loc_id, item_id, item_description, &item_files, &item_token, false, &terms_and_conditions);
+        let signature = ethereum_sign(LogionLoc::<T>::ethereum_signable_hash(b"add_collection_item", &item, 0));
+    }: _(RawOrigin::Signed(legal_officer), collection_loc_id, item_id, item_description, item_files, item_token, false, terms_and_conditions, submitter, 0, signature)
+    verify {
+        assert!(CollectionItemsMap::<T>::contains_key(collection_loc_id, item_id));
+        assert_eq!(LogionLoc::<T>::collection_item_leaf_index(collection_loc_id, item_id), Some(u32::MAX));
+    }
+
+    add_tokens_record_with_ethereum_signature {
+        let f in 1 .. T::MaxTokensRecordFiles::get();
+        let (collection_loc_id, legal_officer, submitter) = closed_ethereum_collection_loc::<T>(0);
+        let files: Vec<TokensRecordFileOf<T>> = (0..f).map(|i| TokensRecordFile {
+            name: into_hash::<T>(i),
+            content_type: into_hash::<T>(i),
+            size: 1,
+            hash: into_hash::<T>(i),
+        }).collect();
+        let record_id = into_id::<T::TokensRecordId>(0);
+        let description: <T as pallet::Config>::Hash = Default::default();
+        let signature = ethereum_sign(LogionLoc::<T>::ethereum_signable_hash(b"add_tokens_record", &(collection_loc_id, record_id, description, &files), 0));
+    }: _(RawOrigin::Signed(legal_officer), collection_loc_id, record_id, description, files, submitter, 0, signature)
+    verify {
+        assert!(TokensRecordsMap::<T>::contains_key(collection_loc_id, record_id));
+    }
+
+    request_review {
+        let (loc_id, _, requester) = sponsored_draft_loc::<T>(0);
+    }: _(RawOrigin::Signed(requester), loc_id)
+    verify {
+        assert!(LegalFeeHoldsMap::<T>::contains_key(loc_id));
+    }
+
+    accept_loc {
+        let legal_officer = legal_officer::<T>();
+        let requester = requester::<T>();
+        let loc_id = into_id::<T::LocId>(0);
+        let last_block = frame_system::Pallet::<T>::block_number() + 100u32.into();
+        assert_ok!(LogionLoc::<T>::create_collection_loc(RawOrigin::Signed(requester.clone()).into(), loc_id, legal_officer.clone(), Some(last_block), None, true, None));
+        assert_ok!(LogionLoc::<T>::request_review(RawOrigin::Signed(requester).into(), loc_id));
+        let hold_expiry = LogionLoc::<T>::legal_fee_hold(loc_id).unwrap().expires_on.unwrap();
+        LegalFeeHoldsByExpiryMap::<T>::mutate(hold_expiry, |loc_ids| {
+            for id in 1 .. T::MaxExpiringLegalFeeHolds::get() {
+                let _ = loc_ids.try_push(into_id::<T::LocId>(id));
+            }
+        });
+        CollectionsByLastBlockMap::<T>::mutate(last_block, |loc_ids| {
+            for id in 1 .. T::MaxCollectionsByLastBlock::get() {
+                let _ = loc_ids.try_push(into_id::<T::LocId>(id));
+            }
+        });
+    }: _(RawOrigin::Signed(legal_officer), loc_id)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().status, LocStatus::Open);
+        assert!(LogionLoc::<T>::collections_by_last_block(last_block).contains(&loc_id));
+        assert!(!DraftDepositsMap::<T>::contains_key(loc_id));
+    }
+
+    reject_loc {
+        let (loc_id, legal_officer, requester) = sponsored_draft_loc::<T>(0);
+        assert_ok!(LogionLoc::<T>::request_review(RawOrigin::Signed(requester).into(), loc_id));
+    }: _(RawOrigin::Signed(legal_officer), loc_id, Default::default())
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().status, LocStatus::Rejected);
+    }
+
+    cancel_loc {
+        let (loc_id, legal_officer, requester) = sponsored_draft_loc::<T>(0);
+        review_and_accept::<T>(&requester, loc_id, &legal_officer);
+    }: _(RawOrigin::Signed(requester), loc_id)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().status, LocStatus::Cancelled);
+    }
+
+    expire_legal_fee_holds {
+        let h in 0 .. T::MaxExpiringLegalFeeHolds::get();
+        let legal_officer = legal_officer::<T>();
+        let requester = requester::<T>();
+        for id in 0 .. h {
+            let loc_id = into_id::<T::LocId>(id);
+            assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(RawOrigin::Signed(requester.clone()).into(), loc_id, legal_officer.clone(), None));
+            assert_ok!(LogionLoc::<T>::request_review(RawOrigin::Signed(requester.clone()).into(), loc_id));
+        }
+        let expiry = frame_system::Pallet::<T>::block_number() + T::LegalFeeHoldTimeout::get();
+    }: {
+        LogionLoc::<T>::on_initialize(expiry);
+    }
+    verify {
+        assert_eq!(LegalFeeHoldsMap::<T>::iter().count(), 0);
+    }
+
+    set_exchange_rate {
+        let feeder = requester::<T>();
+        ExchangeRateFeeder::<T>::put(feeder.clone());
+        let rate = T::ExchangeRate::convert(FiatCurrency::Eur);
+        let valid_for: T::BlockNumber = 10u32.into();
+    }: _(RawOrigin::Signed(feeder), FiatCurrency::Eur, rate, valid_for)
+    verify {
+        assert!(ExchangeRatesMap::<T>::get(FiatCurrency::Eur).is_some());
+    }
+
+    set_exchange_rate_feeder {
+        let origin = T::ExchangeRateOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let feeder = requester::<T>();
+    }: _<T::RuntimeOrigin>(origin, Some(feeder.clone()))
+    verify {
+        assert_eq!(ExchangeRateFeeder::<T>::get(), Some(feeder));
+    }
+
+    snapshot_collection_roots {
+        let c in 0 .. T::MaxCollectionsByLastBlock::get();
+        let legal_officer = legal_officer::<T>();
+        let requester = requester::<T>();
+        let last_block = frame_system::Pallet::<T>::block_number() + 10u32.into();
+        for id in 0 .. c {
+            let loc_id = into_id::<T::LocId>(id);
+            assert_ok!(LogionLoc::<T>::create_collection_loc(RawOrigin::Signed(requester.clone()).into(), loc_id, legal_officer.clone(), Some(last_block), None, false, None));
+            review_and_accept::<T>(&requester, loc_id, &legal_officer);
+            CollectionAccumulatorsMap::<T>::insert(loc_id, full_accumulator::<T>());
+        }
+    }: {
+        LogionLoc::<T>::on_initialize(last_block);
+    }
+    verify {
+        assert_eq!(CollectionRootSnapshotsMap::<T>::iter().count(), c as usize);
+    }
+
+    cancel_loc_with_ethereum_signature {
+        let legal_officer = legal_officer::<T>();
+        let requester = OtherAccountId::Ethereum(ethereum_signer::<T>());
+        let sponsorship_id = sponsor_other_account::<T>(&legal_officer, &requester);
+        let loc_id = into_id::<T::LocId>(0);
+        assert_ok!(LogionLoc::<T>::create_other_transaction_loc(RawOrigin::Signed(legal_officer.clone()).into(), loc_id, requester.clone(), Some(sponsorship_id)));
+        let signature = ethereum_sign(LogionLoc::<T>::ethereum_signable_hash(b"cancel_loc", &loc_id, 0));
+    }: _(RawOrigin::Signed(legal_officer), loc_id, SupportedAccountId::Other(requester), 0, signature)
+    verify {
+        assert_eq!(LogionLoc::<T>::loc(loc_id).unwrap().status, LocStatus::Cancelled);
+    }
+}
+
+fn into_id<Id: Decode>(value: u32) -> Id {
+    let mut bytes = [0u8; 64];
+    bytes[..4].copy_from_slice(&value.to_le_bytes());
+    Id::decode(&mut &bytes[..]).unwrap()
+}
+
+fn into_hash<T: pallet::Config>(value: u32) -> <T as pallet::Config>::Hash {
+    T::Hasher::hash(&value.encode())
+}
+
+fn funded_account<T: pallet::Config>(name: &'static str, index: u32) -> T::AccountId {
+    let account: T::AccountId = account(name, index, SEED);
+    fund::<T>(&account);
+    account
+}
+
+fn fund<T: pallet::Config>(account: &T::AccountId) {
+    T::Currency::set_balance(account, BalanceOf::<T>::max_value() / 1_000_000u32.into());
+}
+
+fn legal_officer<T: pallet::Config>() -> T::AccountId {
+    let legal_officer = T::IsLegalOfficer::legal_officers()[0].clone();
+    fund::<T>(&legal_officer);
+    legal_officer
+}
+
+fn requester<T: pallet::Config>() -> T::AccountId {
+    funded_account::<T>("requester", 0)
+}
+
+fn sponsor_requester<T: pallet::Config>(legal_officer: &T::AccountId, requester: &T::AccountId) -> T::SponsorshipId {
+    let sponsor = funded_account::<T>("sponsor", 0);
+    let sponsorship_id = into_id::<T::SponsorshipId>(0);
+    assert_ok!(LogionLoc::<T>::sponsor(RawOrigin::Signed(sponsor).into(), sponsorship_id, SupportedAccountId::Polkadot(requester.clone()), legal_officer.clone(), sponsorship_expiry::<T>(), BalanceOf::<T>::max_value(), None));
+    sponsorship_id
+}
+
+fn sponsorship_expiry<T: pallet::Config>() -> T::BlockNumber {
+    frame_system::Pallet::<T>::block_number() + 100u32.into()
+}
+
+fn sponsorships_expiring<T: pallet::Config>(sponsor: &T::AccountId, legal_officer: &T::AccountId, first_id: u32, count: u32) {
+    for id in first_id .. first_id + count {
+        assert_ok!(LogionLoc::<T>::sponsor(RawOrigin::Signed(sponsor.clone()).into(), into_id::<T::SponsorshipId>(id), SupportedAccountId::Polkadot(sponsor.clone()), legal_officer.clone(), sponsorship_expiry::<T>(), BalanceOf::<T>::max_value(), None));
+    }
+}
+
+fn sponsored_draft_loc<T: pallet::Config>(id: u32) -> (T::LocId, T::AccountId, T::AccountId) {
+    let legal_officer = legal_officer::<T>();
+    let requester = requester::<T>();
+    let sponsorship_id = sponsor_requester::<T>(&legal_officer, &requester);
+    let loc_id = into_id::<T::LocId>(id);
+    assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(RawOrigin::Signed(requester.clone()).into(), loc_id, legal_officer.clone(), Some(sponsorship_id)));
+    (loc_id, legal_officer, requester)
+}
+
+fn review_and_accept<T: pallet::Config>(requester: &T::AccountId, loc_id: T::LocId, legal_officer: &T::AccountId) {
+    assert_ok!(LogionLoc::<T>::request_review(RawOrigin::Signed(requester.clone()).into(), loc_id));
+    assert_ok!(LogionLoc::<T>::accept_loc(RawOrigin::Signed(legal_officer.clone()).into(), loc_id));
+}
+
+fn open_transaction_loc<T: pallet::Config>(id: u32) -> (T::LocId, T::AccountId, T::AccountId) {
+    let legal_officer = legal_officer::<T>();
+    let requester = requester::<T>();
+    let loc_id = into_id::<T::LocId>(id);
+    assert_ok!(LogionLoc::<T>::create_polkadot_transaction_loc(RawOrigin::Signed(requester.clone()).into(), loc_id, legal_officer.clone(), None));
+    review_and_accept::<T>(&requester, loc_id, &legal_officer);
+    (loc_id, legal_officer, requester)
+}
+
+fn sponsor_other_account<T: pallet::Config>(legal_officer: &T::AccountId, sponsored_account: &OtherAccountIdOf<T>) -> T::SponsorshipId {
+    let sponsor = funded_account::<T>("sponsor", 0);
+    let sponsorship_id = into_id::<T::SponsorshipId>(0);
+    assert_ok!(LogionLoc::<T>::sponsor(RawOrigin::Signed(sponsor).into(), sponsorship_id, SupportedAccountId::Other(sponsored_account.clone()), legal_officer.clone(), sponsorship_expiry::<T>(), BalanceOf::<T>::max_value(), None));
+    sponsorship_id
+}
+
+fn ethereum_identity_loc<T: pallet::Config>(id: u32) -> (T::LocId, T::AccountId, SupportedAccountIdOf<T>) {
+    let legal_officer = legal_officer::<T>();
+    let requester = OtherAccountId::Ethereum(ethereum_signer::<T>());
+    let sponsorship_id = sponsor_other_account::<T>(&legal_officer, &requester);
+    let loc_id = into_id::<T::LocId>(id);
+    assert_ok!(LogionLoc::<T>::create_other_identity_loc(RawOrigin::Signed(legal_officer.clone()).into(), loc_id, requester.clone(), sponsorship_id));
+    (loc_id, legal_officer, SupportedAccountId::Other(requester))
+}
+
+fn ethereum_sign(hash: [u8; 32]) -> EthereumSignature {
+    let public = sp_io::crypto::ecdsa_generate(ETHEREUM_KEY_TYPE, Some(ETHEREUM_KEY_SEED.to_vec()));
+    EthereumSignature(sp_io::crypto::ecdsa_sign_prehashed(ETHEREUM_KEY_TYPE, &public, &hash).unwrap().0)
+}
+
+fn ethereum_signer<T: pallet::Config>() -> T::EthereumAddress {
+    let hash = [0u8; 32];
+    LogionLoc::<T>::recover_ethereum_address(&ethereum_sign(hash), &hash).unwrap()
+}
+
+fn closed_collection_loc<T: pallet::Config>(id: u32) -> (T::LocId, T::AccountId, T::AccountId) {
+    let legal_officer = legal_officer::<T>();
+    let requester = requester::<T>();
+    let loc_id = into_id::<T::LocId>(id);
+    assert_ok!(LogionLoc::<T>::create_collection_loc(RawOrigin::Signed(requester.clone()).into(), loc_id, legal_officer.clone(), None, Some(1), true, None));
+    review_and_accept::<T>(&requester, loc_id, &legal_officer);
+    assert_ok!(LogionLoc::<T>::close(RawOrigin::Signed(legal_officer.clone()).into(), loc_id));
+    (loc_id, legal_officer, requester)
+}
+
+fn closed_ethereum_collection_loc<T: pallet::Config>(id: u32) -> (T::LocId, T::AccountId, SupportedAccountIdOf<T>) {
+    let legal_officer = legal_officer::<T>();
+    let requester = OtherAccountId::Ethereum(ethereum_signer::<T>());
+    let sponsorship_id = sponsor_other_account::<T>(&legal_officer, &requester);
+    let loc_id = into_id::<T::LocId>(id);
+    assert_ok!(LogionLoc::<T>::create_other_collection_loc(RawOrigin::Signed(legal_officer.clone()).into(), loc_id, requester.clone(), None, Some(1), true, Some(sponsorship_id)));
+    assert_ok!(LogionLoc::<T>::close(RawOrigin::Signed(legal_officer.clone()).into(), loc_id));
+    (loc_id, legal_officer, SupportedAccountId::Other(requester))
+}
+
+fn add_metadata_items<T: pallet::Config>(loc_id: T::LocId, submitter: &T::AccountId, count: u32) {
+    for i in 0..count {
+        let item = MetadataItemParams {
+            name: into_hash::<T>(i),
+            value: into_hash::<T>(i),
+            submitter: SupportedAccountId::Polkadot(submitter.clone()),
+        };
+        assert_ok!(LogionLoc::<T>::add_metadata(RawOrigin::Signed(submitter.clone()).into(), loc_id, item));
+    }
+}
+
+fn file_params<T: pallet::Config>(index: u32, submitter: &T::AccountId) -> FileParams<<T as pallet::Config>::Hash, T::AccountId, OtherAccountIdOf<T>> {
+    FileParams {
+        hash: into_hash::<T>(index),
+        nature: into_hash::<T>(index),
+        submitter: SupportedAccountId::Polkadot(submitter.clone()),
+        size: 1,
+    }
+}
+
+fn add_files<T: pallet::Config>(loc_id: T::LocId, submitter: &T::AccountId, count: u32) {
+    for i in 0..count {
+        assert_ok!(LogionLoc::<T>::add_file(RawOrigin::Signed(submitter.clone()).into(), loc_id, file_params::<T>(i, submitter)));
+    }
+}
+
+fn add_links<T: pallet::Config>(loc_id: T::LocId, legal_officer: &T::AccountId, count: u32) {
+    for i in 1..=count {
+        let linked_loc_id = into_id::<T::LocId>(i);
+        assert_ok!(LogionLoc::<T>::create_logion_identity_loc(RawOrigin::Signed(legal_officer.clone()).into(), linked_loc_id));
+        let link = LocLink {
+            id: linked_loc_id,
+            nature: into_hash::<T>(i),
+        };
+        assert_ok!(LogionLoc::<T>::add_link(RawOrigin::Signed(legal_officer.clone()).into(), loc_id, link));
+    }
+}
+
+/// An accumulator with the maximum number of peaks, the worst case when computing its root.
+fn full_accumulator<T: pallet::Config>() -> merkle::MerkleAccumulator<<T as pallet::Config>::Hash> {
+    let peaks: Vec<<T as pallet::Config>::Hash> = (0..32).map(|i| into_hash::<T>(i)).collect();
+    merkle::MerkleAccumulator {
+        leaf_count: u32::MAX,
+        peaks: peaks.try_into().unwrap(),
+    }
+}
+
+fn issuer_with_identity_loc<T: pallet::Config>(id: u32) -> (T::AccountId, T::AccountId, T::LocId) {
+    let legal_officer = legal_officer::<T>();
+    let issuer = funded_account::<T>("issuer", 0);
+    let identity_loc_id = into_id::<T::LocId>(id);
+    assert_ok!(LogionLoc::<T>::create_polkadot_identity_loc(RawOrigin::Signed(issuer.clone()).into(), identity_loc_id, legal_officer.clone(), None));
+    review_and_accept::<T>(&issuer, identity_loc_id, &legal_officer);
+    assert_ok!(LogionLoc::<T>::close(RawOrigin::Signed(legal_officer.clone()).into(), identity_loc_id));
+    (issuer, legal_officer, identity_loc_id)
 }
 
-fn into_loc_id<T: pallet::Config>(value: u128) -> <T as crate::Config>::LocId {
-    <T as crate::Config>::LocId::decode(&mut &value.encode()[..]).unwrap()
+fn issuer_selected_on_locs<T: pallet::Config>(count: u32) -> (T::AccountId, T::AccountId) {
+    let (issuer, legal_officer, identity_loc_id) = issuer_with_identity_loc::<T>(0);
+    assert_ok!(LogionLoc::<T>::nominate_issuer(RawOrigin::Signed(legal_officer.clone()).into(), issuer.clone(), identity_loc_id));
+    for i in 1..=count {
+        let loc_id = into_id::<T::LocId>(i);
+        assert_ok!(LogionLoc::<T>::create_logion_identity_loc(RawOrigin::Signed(legal_officer.clone()).into(), loc_id));
+        assert_ok!(LogionLoc::<T>::set_issuer_selection(RawOrigin::Signed(legal_officer.clone()).into(), loc_id, issuer.clone(), true));
+    }
+    (issuer, legal_officer)
 }
 
 impl_benchmark_test_suite!(
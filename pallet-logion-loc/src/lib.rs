@@ -2,6 +2,7 @@
 
 pub use pallet::*;
 
+pub mod merkle;
 pub mod migrations;
 pub mod runtime_api;
 
@@ -19,16 +20,21 @@ mod benchmarking;
 
 use frame_support::{
     BoundedVec,
-    codec::{Decode, Encode},
+    codec::{Decode, Encode, MaxEncodedLen},
     dispatch::Vec,
 };
-use frame_support::traits::Currency;
+use frame_support::traits::Time;
+use frame_support::traits::fungible::{Balanced, BalancedHold, Credit, Inspect, Mutate, MutateHold};
+use frame_support::traits::tokens::{Fortitude, Precision, Preservation};
 use scale_info::TypeInfo;
 use logion_shared::LegalOfficerCaseSummary;
 use crate::Requester::Account;
 use frame_support::sp_runtime::Saturating;
+use frame_support::sp_runtime::Percent;
+use frame_support::sp_runtime::traits::{Convert, One, Zero};
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::{blake2_256, keccak_256}};
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, Copy)]
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
 pub enum LocType {
     Transaction,
     Identity,
@@ -41,103 +47,230 @@ impl Default for LocType {
     }
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct MetadataItem<AccountId, EthereumAddress, Hash> {
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub struct Timepoint<BlockNumber, Moment> {
+    block_number: BlockNumber,
+    moment: Option<Moment>,
+}
+
+pub type MomentOf<T> = <<T as Config>::Timestamp as Time>::Moment;
+
+pub type TimepointOf<T> = Timepoint<<T as frame_system::Config>::BlockNumber, MomentOf<T>>;
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct MetadataItem<AccountId, OtherAccount, Hash, BlockNumber, Moment> {
     name: Hash,
     value: Hash,
-    submitter: SupportedAccountId<AccountId, EthereumAddress>,
+    submitter: SupportedAccountId<AccountId, OtherAccount>,
     acknowledged: bool,
+    added_on: Option<Timepoint<BlockNumber, Moment>>,
+    acknowledged_on: Option<Timepoint<BlockNumber, Moment>>,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct MetadataItemParams<AccountId, EthereumAddress, Hash> {
+pub type MetadataItemOf<T> = MetadataItem<
+    <T as frame_system::Config>::AccountId,
+    OtherAccountIdOf<T>,
+    <T as pallet::Config>::Hash,
+    <T as frame_system::Config>::BlockNumber,
+    MomentOf<T>,
+>;
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct MetadataItemParams<AccountId, OtherAccount, Hash> {
     name: Hash,
     value: Hash,
-    submitter: SupportedAccountId<AccountId, EthereumAddress>,
+    submitter: SupportedAccountId<AccountId, OtherAccount>,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct LocLink<LocId, Hash> {
     id: LocId,
     nature: Hash,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct File<Hash, AccountId, EthereumAddress> {
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LocLinkItem<LocId, Hash, BlockNumber, Moment> {
+    id: LocId,
+    nature: Hash,
+    added_on: Option<Timepoint<BlockNumber, Moment>>,
+}
+
+pub type LocLinkItemOf<T> = LocLinkItem<
+    <T as pallet::Config>::LocId,
+    <T as pallet::Config>::Hash,
+    <T as frame_system::Config>::BlockNumber,
+    MomentOf<T>,
+>;
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct File<Hash, AccountId, OtherAccount, BlockNumber, Moment> {
     hash: Hash,
     nature: Hash,
-    submitter: SupportedAccountId<AccountId, EthereumAddress>,
+    submitter: SupportedAccountId<AccountId, OtherAccount>,
     size: u32,
     acknowledged: bool,
+    added_on: Option<Timepoint<BlockNumber, Moment>>,
+    acknowledged_on: Option<Timepoint<BlockNumber, Moment>>,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct FileParams<Hash, AccountId, EthereumAddress> {
+pub type FileOf<T> = File<
+    <T as pallet::Config>::Hash,
+    <T as frame_system::Config>::AccountId,
+    OtherAccountIdOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    MomentOf<T>,
+>;
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct FileParams<Hash, AccountId, OtherAccount> {
     hash: Hash,
     nature: Hash,
-    submitter: SupportedAccountId<AccountId, EthereumAddress>,
+    submitter: SupportedAccountId<AccountId, OtherAccount>,
     size: u32,
 }
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct LocVoidInfo<LocId> {
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LocVoidInfo<LocId, BlockNumber, Moment> {
     replacer: Option<LocId>,
+    voided_on: Option<Timepoint<BlockNumber, Moment>>,
 }
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, Copy)]
-pub enum OtherAccountId<EthereumAddress> {
-    Ethereum(EthereumAddress)
+/// The lifecycle of a LOC. A LOC requested by a Polkadot account starts as a draft which the requester submits for review.
+/// The owner then accepts it, making it open, or rejects it. LOCs created by their owner are open right away.
+/// The requester may cancel a LOC as long as its owner did not act on it.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum LocStatus {
+    Draft,
+    ReviewRequested,
+    Open,
+    Rejected,
+    Closed,
+    Void,
+    Cancelled,
 }
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub enum Requester<AccountId, LocId, EthereumAddress> {
+impl LocStatus {
+
+    /// Tells if items cannot be added, acknowledged or deleted in this status. Void LOCs are handled separately.
+    pub fn is_frozen(&self) -> bool {
+        match self {
+            LocStatus::ReviewRequested | LocStatus::Closed | LocStatus::Cancelled => true,
+            _ => false,
+        }
+    }
+}
+
+impl Default for LocStatus {
+
+    fn default() -> LocStatus {
+        LocStatus::Open
+    }
+}
+
+/// An account of another blockchain. Variant indexes are fixed so that existing encodings remain stable.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub enum OtherAccountId<EthereumAddress, BitcoinAddress, SolanaAddress, TezosAddress> {
+    #[codec(index = 0)]
+    Ethereum(EthereumAddress),
+    #[codec(index = 1)]
+    Bitcoin(BitcoinAddress),
+    #[codec(index = 2)]
+    Solana(SolanaAddress),
+    #[codec(index = 3)]
+    Tezos(TezosAddress),
+}
+
+pub type OtherAccountIdOf<T> = OtherAccountId<
+    <T as Config>::EthereumAddress,
+    <T as Config>::BitcoinAddress,
+    <T as Config>::SolanaAddress,
+    <T as Config>::TezosAddress,
+>;
+
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum Requester<AccountId, LocId, OtherAccount> {
     None,
     Account(AccountId),
     Loc(LocId),
-    OtherAccount(OtherAccountId<EthereumAddress>),
+    OtherAccount(OtherAccount),
 }
 
-pub type RequesterOf<T> = Requester<<T as frame_system::Config>::AccountId, <T as Config>::LocId, <T as Config>::EthereumAddress>;
+pub type RequesterOf<T> = Requester<<T as frame_system::Config>::AccountId, <T as Config>::LocId, OtherAccountIdOf<T>>;
 
-impl<AccountId, LocId, EthereumAddress> Default for Requester<AccountId, LocId, EthereumAddress> {
+impl<AccountId, LocId, OtherAccount> Default for Requester<AccountId, LocId, OtherAccount> {
 
-    fn default() -> Requester<AccountId, LocId, EthereumAddress> {
+    fn default() -> Requester<AccountId, LocId, OtherAccount> {
         Requester::None
     }
 }
 
-#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, Copy)]
-pub enum SupportedAccountId<AccountId, EthereumAddress> {
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Copy)]
+pub enum SupportedAccountId<AccountId, OtherAccount> {
     None, // Enables "null" account ID
     Polkadot(AccountId),
-    Other(OtherAccountId<EthereumAddress>),
+    Other(OtherAccount),
 }
 
-impl<AccountId, EthereumAddress> Default for SupportedAccountId<AccountId, EthereumAddress> {
+impl<AccountId, OtherAccount> Default for SupportedAccountId<AccountId, OtherAccount> {
 
-    fn default() -> SupportedAccountId<AccountId, EthereumAddress> {
+    fn default() -> SupportedAccountId<AccountId, OtherAccount> {
         SupportedAccountId::None
     }
 }
 
+pub type SupportedAccountIdOf<T> = SupportedAccountId<<T as frame_system::Config>::AccountId, OtherAccountIdOf<T>>;
+
+/// An ECDSA signature produced by an Ethereum account: r, s and recovery ID (v).
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct EthereumSignature(pub [u8; 65]);
+
 pub type CollectionSize = u32;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct LegalOfficerCase<AccountId, Hash, LocId, BlockNumber, EthereumAddress, SponsorshipId> {
+/// The format used to compute a seal, see `Pallet::compute_seal`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum SealVersion {
+    /// Seals recorded before seals were versioned. Their format is unknown to the chain, they cannot be verified on-chain.
+    V0,
+    /// The `Hasher` hash of the SCALE encoding of `(SealVersion::V1, salt, personal_data)`.
+    V1,
+}
+
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Seal<Hash> {
+    pub version: SealVersion,
+    pub hash: Hash,
+}
+
+pub type SealOf<T> = Seal<<T as pallet::Config>::Hash>;
+
+/// The kind of LOC item a content leaf commits to, see `Pallet::content_leaves`.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Debug, TypeInfo)]
+pub enum LocItemKind {
+    Metadata,
+    File,
+    Link,
+}
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LegalOfficerCase<AccountId, Hash, LocId, BlockNumber, OtherAccount, SponsorshipId, Moment, BoundedLocMetadataList, BoundedLocFilesList, BoundedLocLinksList> {
     owner: AccountId,
-    requester: Requester<AccountId, LocId, EthereumAddress>,
-    metadata: Vec<MetadataItem<AccountId, EthereumAddress, Hash>>,
-    files: Vec<File<Hash, AccountId, EthereumAddress>>,
+    requester: Requester<AccountId, LocId, OtherAccount>,
+    metadata: BoundedLocMetadataList,
+    files: BoundedLocFilesList,
+    status: LocStatus,
+    /// Kept when the LOC is voided, a closed LOC being `Void` afterwards
     closed: bool,
     loc_type: LocType,
-    links: Vec<LocLink<LocId, Hash>>,
-    void_info: Option<LocVoidInfo<LocId>>,
+    links: BoundedLocLinksList,
+    void_info: Option<LocVoidInfo<LocId, BlockNumber, Moment>>,
     replacer_of: Option<LocId>,
     collection_last_block_submission: Option<BlockNumber>,
     collection_max_size: Option<CollectionSize>,
     collection_can_upload: bool,
-    seal: Option<Hash>,
+    seal: Option<Seal<Hash>>,
     sponsorship_id: Option<SponsorshipId>,
+    closed_on: Option<Timepoint<BlockNumber, Moment>>,
+    rejection_reason: Option<Hash>,
 }
 
 pub type LegalOfficerCaseOf<T> = LegalOfficerCase<
@@ -145,33 +278,46 @@ pub type LegalOfficerCaseOf<T> = LegalOfficerCase<
     <T as pallet::Config>::Hash,
     <T as pallet::Config>::LocId,
     <T as frame_system::Config>::BlockNumber,
-    <T as pallet::Config>::EthereumAddress,
+    OtherAccountIdOf<T>,
     <T as pallet::Config>::SponsorshipId,
+    MomentOf<T>,
+    BoundedVec<MetadataItemOf<T>, <T as pallet::Config>::MaxLocMetadata>,
+    BoundedVec<FileOf<T>, <T as pallet::Config>::MaxLocFiles>,
+    BoundedVec<LocLinkItemOf<T>, <T as pallet::Config>::MaxLocLinks>,
 >;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct TermsAndConditionsElement<LocId, Hash> {
     tc_type: Hash,
     tc_loc: LocId,
     details: Hash,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct CollectionItem<Hash, LocId, TokenIssuance> {
+pub type TermsAndConditionsElementOf<T> = TermsAndConditionsElement<
+    <T as pallet::Config>::LocId,
+    <T as pallet::Config>::Hash,
+>;
+
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionItem<Hash, TokenIssuance, BlockNumber, Moment, BoundedCollectionItemFilesList, BoundedTermsAndConditionsList> {
     description: Hash,
-    files: Vec<CollectionItemFile<Hash>>,
+    files: BoundedCollectionItemFilesList,
     token: Option<CollectionItemToken<TokenIssuance, Hash>>,
     restricted_delivery: bool,
-    terms_and_conditions: Vec<TermsAndConditionsElement<LocId, Hash>>,
+    terms_and_conditions: BoundedTermsAndConditionsList,
+    added_on: Option<Timepoint<BlockNumber, Moment>>,
 }
 
 pub type CollectionItemOf<T> = CollectionItem<
     <T as pallet::Config>::Hash,
-    <T as pallet::Config>::LocId,
     <T as pallet::Config>::TokenIssuance,
+    <T as frame_system::Config>::BlockNumber,
+    MomentOf<T>,
+    BoundedVec<CollectionItemFileOf<T>, <T as pallet::Config>::MaxCollectionItemFiles>,
+    BoundedVec<TermsAndConditionsElementOf<T>, <T as pallet::Config>::MaxCollectionItemTCs>,
 >;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct CollectionItemFile<Hash> {
     name: Hash,
     content_type: Hash,
@@ -181,14 +327,14 @@ pub struct CollectionItemFile<Hash> {
 
 pub type CollectionItemFileOf<T> = CollectionItemFile<<T as pallet::Config>::Hash>;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct CollectionItemToken<TokenIssuance, Hash> {
     token_type: Hash,
     token_id: Hash,
     token_issuance: TokenIssuance,
 }
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct VerifiedIssuer<LocId> {
     identity_loc: LocId,
 }
@@ -197,11 +343,12 @@ pub type VerifiedIssuerOf<T> = VerifiedIssuer<
     <T as pallet::Config>::LocId,
 >;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct TokensRecord<Hash, BoundedTokensRecordFilesList, AccountId> {
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct TokensRecord<Hash, BoundedTokensRecordFilesList, AccountId, OtherAccount, BlockNumber, Moment> {
     description: Hash,
     files: BoundedTokensRecordFilesList,
-    submitter: AccountId,
+    submitter: SupportedAccountId<AccountId, OtherAccount>,
+    added_on: Option<Timepoint<BlockNumber, Moment>>,
 }
 
 pub type TokensRecordOf<T> = TokensRecord<
@@ -211,9 +358,12 @@ pub type TokensRecordOf<T> = TokensRecord<
         <T as pallet::Config>::MaxTokensRecordFiles
     >,
     <T as frame_system::Config>::AccountId,
+    OtherAccountIdOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    MomentOf<T>,
 >;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
 pub struct TokensRecordFile<Hash> {
     name: Hash,
     content_type: Hash,
@@ -225,22 +375,121 @@ pub type TokensRecordFileOf<T> = TokensRecordFile<
     <T as pallet::Config>::Hash,
 >;
 
-pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+/// Where a file is registered in a LOC.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum FileLocation<CollectionItemId, TokensRecordId> {
+    /// The LOC's own files
+    Loc,
+    /// The files of a collection item
+    CollectionItem(CollectionItemId),
+    /// The files of a tokens record
+    TokensRecord(TokensRecordId),
+}
+
+pub type FileLocationOf<T> = FileLocation<
+    <T as pallet::Config>::CollectionItemId,
+    <T as pallet::Config>::TokensRecordId,
+>;
+
+/// An occurrence of a file along with the status of the LOC holding it, see `Pallet::get_file_occurrences`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct FileOccurrence<LocId, CollectionItemId, TokensRecordId> {
+    pub loc_id: LocId,
+    pub location: FileLocation<CollectionItemId, TokensRecordId>,
+    pub status: LocStatus,
+    pub closed: bool,
+    pub void: bool,
+}
+
+pub type FileOccurrenceOf<T> = FileOccurrence<
+    <T as pallet::Config>::LocId,
+    <T as pallet::Config>::CollectionItemId,
+    <T as pallet::Config>::TokensRecordId,
+>;
+
+pub type BalanceOf<T> = <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
-pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId, >>::NegativeImbalance;
+pub type CreditOf<T> = Credit<<T as frame_system::Config>::AccountId, <T as Config>::Currency>;
 
-#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-pub struct Sponsorship<AccountId, EthereumAddress, LocId> {
+#[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct Sponsorship<AccountId, OtherAccount, BlockNumber, Balance> {
     sponsor: AccountId,
-    sponsored_account: SupportedAccountId<AccountId, EthereumAddress>,
+    sponsored_account: SupportedAccountId<AccountId, OtherAccount>,
     legal_officer: AccountId,
-    loc_id: Option<LocId>,
+    expires_on: BlockNumber,
+    max_balance: Balance,
+    max_locs: Option<u32>,
+    used_locs: u32,
+    used_balance: Balance,
 }
 
 pub type SponsorshipOf<T> = Sponsorship<
     <T as frame_system::Config>::AccountId,
-    <T as Config>::EthereumAddress,
-    <T as Config>::LocId,
+    OtherAccountIdOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+    BalanceOf<T>,
+>;
+
+/// Reasons for this pallet placing funds on hold.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug, TypeInfo, MaxEncodedLen)]
+pub enum HoldReason {
+    /// The legal fee of a LOC, held until the LOC is closed.
+    LegalFee,
+    /// The deposit of a requester for a LOC, held until the LOC is accepted, cancelled or voided.
+    DraftDeposit,
+}
+
+/// A legal fee on hold. The fee of a LOC under review is returned to its payer if the LOC is not accepted
+/// before `expires_on`. Once the LOC is open, the fee stays on hold until the LOC is closed.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct LegalFeeHold<AccountId, Balance, BlockNumber> {
+    pub payer: AccountId,
+    pub amount: Balance,
+    pub expires_on: Option<BlockNumber>,
+}
+
+pub type LegalFeeHoldOf<T> = LegalFeeHold<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// A deposit held on the account of the requester of a LOC while its owner did not accept it.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct DraftDeposit<AccountId, Balance> {
+    pub depositor: AccountId,
+    pub amount: Balance,
+}
+
+pub type DraftDepositOf<T> = DraftDeposit<
+    <T as frame_system::Config>::AccountId,
+    BalanceOf<T>,
+>;
+
+/// The Merkle root of a collection's items taken when the collection reached its limits.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct CollectionRootSnapshot<Hash, BlockNumber> {
+    pub root: Hash,
+    pub item_count: u32,
+    pub taken_on: BlockNumber,
+}
+
+pub type CollectionRootSnapshotOf<T> = CollectionRootSnapshot<
+    <T as pallet::Config>::Hash,
+    <T as frame_system::Config>::BlockNumber,
+>;
+
+/// An exchange rate set on-chain, applying from `valid_from` to `valid_until` (both included).
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ExchangeRateRecord<Balance, BlockNumber> {
+    pub rate: Balance,
+    pub valid_from: BlockNumber,
+    pub valid_until: BlockNumber,
+}
+
+pub type ExchangeRateRecordOf<T> = ExchangeRateRecord<
+    BalanceOf<T>,
+    <T as frame_system::Config>::BlockNumber,
 >;
 
 pub mod weights;
@@ -259,10 +508,9 @@ pub mod pallet {
         pallet_prelude::*, traits::tokens::Balance,
     };
     use codec::HasCompact;
-    use frame_support::traits::Currency;
     use logion_shared::{
         LocQuery, LocValidity, IsLegalOfficer, RewardDistributor,
-        DistributionKey, LegalFee, EuroCent, Beneficiary,
+        DistributionKey, LegalFee, LegalFeeSchedule, FiatAmount, FiatCurrency, Beneficiary,
     };
     use super::*;
     pub use crate::weights::WeightInfo;
@@ -270,10 +518,10 @@ pub mod pallet {
     #[pallet::config]
     pub trait Config: frame_system::Config {
         /// LOC identifier
-        type LocId: Member + Parameter + Default + Copy + HasCompact;
+        type LocId: Member + Parameter + Default + Copy + HasCompact + MaxEncodedLen;
 
         /// Type for hashes stored in LOCs
-        type Hash: Member + Parameter + Default + Copy + Ord;
+        type Hash: Member + Parameter + Default + Copy + Ord + MaxEncodedLen;
 
         /// Type for hasher
         type Hasher: Hasher<<Self as pallet::Config>::Hash>;
@@ -285,7 +533,7 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
         /// Collection item identifier
-        type CollectionItemId: Member + Parameter + Default + Copy;
+        type CollectionItemId: Member + Parameter + Default + Copy + MaxEncodedLen;
 
         /// The maximum size of a Collection Item description
         type MaxCollectionItemDescriptionSize: Get<usize>;
@@ -300,7 +548,7 @@ pub mod pallet {
         type IsLegalOfficer: IsLegalOfficer<Self::AccountId, Self::RuntimeOrigin>;
 
         /// Token Record identifier
-        type TokensRecordId: Member + Parameter + Default + Copy;
+        type TokensRecordId: Member + Parameter + Default + Copy + MaxEncodedLen;
 
         /// The maximum size of a Token Record description
         type MaxTokensRecordDescriptionSize: Get<u32>;
@@ -315,7 +563,13 @@ pub mod pallet {
         type MaxTokensRecordFiles: Get<u32>;
 
         /// The currency trait.
-        type Currency: Currency<Self::AccountId>;
+        type Currency: Mutate<Self::AccountId>
+            + Balanced<Self::AccountId>
+            + MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+            + BalancedHold<Self::AccountId>;
+
+        /// The overarching hold reason.
+        type RuntimeHoldReason: From<HoldReason>;
 
         /// The variable part of the Fee to pay to store a file (per byte)
         type FileStorageByteFee: Get<BalanceOf<Self>>;
@@ -324,22 +578,47 @@ pub mod pallet {
         type FileStorageEntryFee: Get<BalanceOf<Self>>;
 
         /// Used to payout file storage fees
-        type RewardDistributor: RewardDistributor<NegativeImbalanceOf<Self>, BalanceOf<Self>>;
+        type RewardDistributor: RewardDistributor<Self::Currency, Self::AccountId>;
 
         /// Used to payout rewards
         type FileStorageFeeDistributionKey: Get<DistributionKey>;
 
         /// Ethereum Address type
-        type EthereumAddress: Member + Parameter + Default + Copy;
+        type EthereumAddress: Member + Parameter + Default + Copy + MaxEncodedLen;
+
+        /// Bitcoin Address type
+        type BitcoinAddress: Member + Parameter + MaxEncodedLen;
+
+        /// Solana Address type
+        type SolanaAddress: Member + Parameter + MaxEncodedLen;
+
+        /// Tezos Address type
+        type TezosAddress: Member + Parameter + MaxEncodedLen;
 
         /// The identifier of a sponsorship
-        type SponsorshipId: Member + Parameter + Default + Copy + HasCompact;
+        type SponsorshipId: Member + Parameter + Default + Copy + HasCompact + MaxEncodedLen;
 
         /// Used to payout legal fees
-        type LegalFee: LegalFee<NegativeImbalanceOf<Self>, BalanceOf<Self>, LocType, Self::AccountId>;
+        type LegalFee: LegalFee<Self::Currency, LocType, Self::AccountId>;
+
+        /// The legal fees published by legal officers, overriding the default ones
+        type LegalFeeSchedule: LegalFeeSchedule<Self::AccountId, LocType>;
 
-        /// Exchange Rate LGNT/EURO cents, i.e. the amount of balance equivalent to 1 euro cent.
-        type ExchangeRate: Get<BalanceOf<Self>>;
+        /// Exchange Rate LGNT/minor unit of each fiat currency, i.e. the amount of balance equivalent to
+        /// e.g. 1 euro cent. Applies when no valid exchange rate was set on-chain for the currency.
+        type ExchangeRate: Convert<FiatCurrency, BalanceOf<Self>>;
+
+        /// The origin which can set the exchange rate without bounds and choose its feeder
+        type ExchangeRateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// The maximum relative change of the exchange rate allowed per update by the feeder
+        #[pallet::constant]
+        type MaxExchangeRateChange: Get<Percent>;
+
+        /// The minimum number of blocks between the last update of the exchange rate of a currency
+        /// and the next one by the feeder
+        #[pallet::constant]
+        type MinExchangeRateFeedInterval: Get<Self::BlockNumber>;
 
         /// The certificate fee per issued token
         type CertificateFee: Get<BalanceOf<Self>>;
@@ -349,10 +628,50 @@ pub mod pallet {
 
         /// The collection item's token issuance type
         type TokenIssuance: Balance + Into<BalanceOf<Self>>;
+
+        /// Used to timestamp items and LOC state transitions
+        type Timestamp: Time;
+
+        /// The maximum number of metadata items per LOC
+        type MaxLocMetadata: Get<u32>;
+
+        /// The maximum number of files per LOC
+        type MaxLocFiles: Get<u32>;
+
+        /// The maximum number of links per LOC
+        type MaxLocLinks: Get<u32>;
+
+        /// The maximum number of files per collection item
+        type MaxCollectionItemFiles: Get<u32>;
+
+        /// The maximum number of terms and conditions elements per collection item
+        type MaxCollectionItemTCs: Get<u32>;
+
+        /// The maximum number of LOCs of a given legal officer an issuer may be selected on
+        type MaxIssuerLocs: Get<u32>;
+
+        /// The maximum number of sponsorships expiring at a given block
+        #[pallet::constant]
+        type MaxExpiringSponsorships: Get<u32>;
+
+        /// The number of blocks after which the legal fee of a LOC under review is returned to its payer if the LOC was not accepted
+        #[pallet::constant]
+        type LegalFeeHoldTimeout: Get<Self::BlockNumber>;
+
+        /// The maximum number of legal fee holds expiring at a given block
+        #[pallet::constant]
+        type MaxExpiringLegalFeeHolds: Get<u32>;
+
+        /// The maximum number of collections whose last block of submission is a given block
+        #[pallet::constant]
+        type MaxCollectionsByLastBlock: Get<u32>;
+
+        /// The deposit held on the requester's account when creating a LOC, until the LOC is accepted
+        #[pallet::constant]
+        type DraftLocDeposit: Get<BalanceOf<Self>>;
     }
 
     #[pallet::pallet]
-    #[pallet::without_storage_info]
     pub struct Pallet<T>(_);
 
     /// All LOCs indexed by ID.
@@ -360,26 +679,83 @@ pub mod pallet {
     #[pallet::getter(fn loc)]
     pub type LocMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, LegalOfficerCaseOf<T>>;
 
-    /// Requested LOCs by account ID.
+    /// Requested LOCs by account ID and LOC type.
+    #[pallet::storage]
+    pub type AccountLocsMap<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, <T as frame_system::Config>::AccountId>, // requester
+            NMapKey<Blake2_128Concat, LocType>,
+            NMapKey<Blake2_128Concat, <T as Config>::LocId>,
+        ),
+        ()
+    >;
+
+    /// LOCs holding a file indexed by file hash, along with where the file is registered in the LOC.
+    #[pallet::storage]
+    pub type FileHashIndexMap<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, <T as Config>::Hash>, // file hash
+            NMapKey<Blake2_128Concat, <T as Config>::LocId>,
+            NMapKey<Blake2_128Concat, FileLocationOf<T>>,
+        ),
+        ()
+    >;
+
+    /// Requested LOCs by logion Identity LOC and LOC type.
     #[pallet::storage]
-    #[pallet::getter(fn account_locs)]
-    pub type AccountLocsMap<T> = StorageMap<_, Blake2_128Concat, <T as frame_system::Config>::AccountId, Vec<<T as Config>::LocId>>;
+    pub type IdentityLocLocsMap<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, <T as Config>::LocId>, // requester
+            NMapKey<Blake2_128Concat, LocType>,
+            NMapKey<Blake2_128Concat, <T as Config>::LocId>,
+        ),
+        ()
+    >;
 
-    /// Requested LOCs by logion Identity LOC.
+    /// Requested LOCs by other requester and LOC type.
     #[pallet::storage]
-    #[pallet::getter(fn identity_loc_locs)]
-    pub type IdentityLocLocsMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, Vec<<T as Config>::LocId>>;
+    pub type OtherAccountLocsMap<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, OtherAccountIdOf<T>>, // requester
+            NMapKey<Blake2_128Concat, LocType>,
+            NMapKey<Blake2_128Concat, <T as Config>::LocId>,
+        ),
+        ()
+    >;
 
-    /// Requested LOCs by other requester.
+    /// LOCs by owner (legal officer).
     #[pallet::storage]
-    #[pallet::getter(fn other_account_locs)]
-    pub type OtherAccountLocsMap<T> = StorageMap<_, Blake2_128Concat, OtherAccountId<<T as Config>::EthereumAddress>, Vec<<T as Config>::LocId>>;
+    #[pallet::getter(fn locs_by_owner)]
+    pub type LocsByOwnerMap<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId, // owner
+        Blake2_128Concat,
+        <T as Config>::LocId,
+        ()
+    >;
 
     /// Collection items by LOC ID.
     #[pallet::storage]
     #[pallet::getter(fn collection_items)]
     pub type CollectionItemsMap<T> = StorageDoubleMap<_, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as Config>::CollectionItemId, CollectionItemOf<T>>;
 
+    /// Collection LOC ID and item ID by token type and token ID. A token may be registered by a single item.
+    #[pallet::storage]
+    #[pallet::getter(fn collection_item_by_token)]
+    pub type CollectionItemsByTokenMap<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        <T as Config>::Hash, // token type
+        Blake2_128Concat,
+        <T as Config>::Hash, // token ID
+        (<T as Config>::LocId, <T as Config>::CollectionItemId),
+    >;
+
     /// Collection size by LOC ID.
     #[pallet::storage]
     #[pallet::getter(fn collection_size)]
@@ -438,6 +814,124 @@ pub mod pallet {
     #[pallet::getter(fn sponsorship)]
     pub type SponsorshipMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::SponsorshipId, SponsorshipOf<T>>;
 
+    /// Sponsorships indexed by expiry block
+    #[pallet::storage]
+    #[pallet::getter(fn sponsorships_by_expiry)]
+    pub type SponsorshipsByExpiryMap<T> = StorageMap<
+        _,
+        Blake2_128Concat,
+        <T as frame_system::Config>::BlockNumber,
+        BoundedVec<<T as Config>::SponsorshipId, <T as Config>::MaxExpiringSponsorships>,
+        ValueQuery,
+    >;
+
+    /// The nonce expected in the next signature of an Ethereum address
+    #[pallet::storage]
+    #[pallet::getter(fn ethereum_nonce)]
+    pub type EthereumNonceMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::EthereumAddress, u64, ValueQuery>;
+
+    /// Legal fees on hold until their LOC is closed
+    #[pallet::storage]
+    #[pallet::getter(fn legal_fee_hold)]
+    pub type LegalFeeHoldsMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, LegalFeeHoldOf<T>, OptionQuery>;
+
+    /// Deposits held for LOCs not accepted yet
+    #[pallet::storage]
+    #[pallet::getter(fn draft_deposit)]
+    pub type DraftDepositsMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, DraftDepositOf<T>, OptionQuery>;
+
+    /// LOCs under review with a legal fee on hold indexed by hold expiry block
+    #[pallet::storage]
+    #[pallet::getter(fn legal_fee_holds_by_expiry)]
+    pub type LegalFeeHoldsByExpiryMap<T> = StorageMap<
+        _,
+        Blake2_128Concat,
+        <T as frame_system::Config>::BlockNumber,
+        BoundedVec<<T as Config>::LocId, <T as Config>::MaxExpiringLegalFeeHolds>,
+        ValueQuery,
+    >;
+
+    /// The Merkle root of the metadata, files and links of closed LOCs. LOCs closed before roots were introduced have none.
+    #[pallet::storage]
+    #[pallet::getter(fn content_root)]
+    pub type ContentRootsMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, <T as Config>::Hash>;
+
+    /// The Merkle accumulator of the items of collection LOCs, in order of addition.
+    /// Items added before accumulators were introduced are not part of it.
+    #[pallet::storage]
+    #[pallet::getter(fn collection_accumulator)]
+    pub type CollectionAccumulatorsMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, merkle::MerkleAccumulator<<T as Config>::Hash>, ValueQuery>;
+
+    /// The roots of the perfect subtrees of collection accumulators indexed by collection and (level, index),
+    /// leaves being at level 0. Proofs of inclusion are built from them in a logarithmic number of reads.
+    #[pallet::storage]
+    #[pallet::getter(fn collection_accumulator_node)]
+    pub type CollectionAccumulatorNodesMap<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        <T as Config>::LocId,
+        Twox64Concat,
+        (u32, u32),
+        <T as Config>::Hash,
+    >;
+
+    /// The index of the leaf of collection items in their collection's accumulator
+    #[pallet::storage]
+    #[pallet::getter(fn collection_item_leaf_index)]
+    pub type CollectionItemLeafIndexMap<T> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        <T as Config>::LocId,
+        Blake2_128Concat,
+        <T as Config>::CollectionItemId,
+        u32,
+    >;
+
+    /// The root of collections' items taken when they reached their limits
+    #[pallet::storage]
+    #[pallet::getter(fn collection_root_snapshot)]
+    pub type CollectionRootSnapshotsMap<T> = StorageMap<_, Blake2_128Concat, <T as Config>::LocId, CollectionRootSnapshotOf<T>>;
+
+    /// Collection LOCs indexed by their last block of submission
+    #[pallet::storage]
+    #[pallet::getter(fn collections_by_last_block)]
+    pub type CollectionsByLastBlockMap<T> = StorageMap<
+        _,
+        Blake2_128Concat,
+        <T as frame_system::Config>::BlockNumber,
+        BoundedVec<<T as Config>::LocId, <T as Config>::MaxCollectionsByLastBlock>,
+        ValueQuery,
+    >;
+
+    /// The exchange rates last set on-chain indexed by fiat currency
+    #[pallet::storage]
+    #[pallet::getter(fn exchange_rate)]
+    pub type ExchangeRatesMap<T> = StorageMap<_, Twox64Concat, FiatCurrency, ExchangeRateRecordOf<T>>;
+
+    /// All exchange rates set on-chain indexed by fiat currency, the block they became valid and
+    /// their index among the updates of the same block
+    #[pallet::storage]
+    #[pallet::getter(fn exchange_rate_history)]
+    pub type ExchangeRateHistoryMap<T> = StorageNMap<
+        _,
+        (
+            NMapKey<Twox64Concat, FiatCurrency>,
+            NMapKey<Twox64Concat, <T as frame_system::Config>::BlockNumber>,
+            NMapKey<Twox64Concat, u32>,
+        ),
+        ExchangeRateRecordOf<T>,
+    >;
+
+    /// The block of the last exchange rate update of each fiat currency and the number of updates in that block,
+    /// giving the index of the next update in `ExchangeRateHistoryMap`
+    #[pallet::storage]
+    pub type ExchangeRateUpdatesInBlockMap<T> = StorageMap<_, Twox64Concat, FiatCurrency, (<T as frame_system::Config>::BlockNumber, u32)>;
+
+    /// The account allowed to update the exchange rate within bounds
+    #[pallet::storage]
+    #[pallet::getter(fn exchange_rate_feeder)]
+    pub type ExchangeRateFeeder<T> = StorageValue<_, <T as frame_system::Config>::AccountId, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -452,13 +946,63 @@ pub mod pallet {
         /// Issued when File Storage Fee is withdrawn. [payerAccountId, storageFee]
         StorageFeeWithdrawn(T::AccountId, BalanceOf<T>),
         /// Issued when a sponsorship was successfully created [sponsorship_id, sponsor, sponsored_account]
-        SponsorshipCreated(T::SponsorshipId, T::AccountId, SupportedAccountId<T::AccountId, T::EthereumAddress>),
+        SponsorshipCreated(T::SponsorshipId, T::AccountId, SupportedAccountIdOf<T>),
         /// Issued when a sponsorship was successfully withdrawn [sponsorship_id, sponsor, sponsored_account]
-        SponsorshipWithdrawn(T::SponsorshipId, T::AccountId, SupportedAccountId<T::AccountId, T::EthereumAddress>),
+        SponsorshipWithdrawn(T::SponsorshipId, T::AccountId, SupportedAccountIdOf<T>),
+        /// Issued when an unused sponsorship expired and was removed [sponsorship_id, sponsor, sponsored_account]
+        SponsorshipExpired(T::SponsorshipId, T::AccountId, SupportedAccountIdOf<T>),
         /// Issued when Legal Fee is withdrawn. [payerAccountId, beneficiary, legalFee]
         LegalFeeWithdrawn(T::AccountId, Beneficiary<T::AccountId>, BalanceOf<T>),
+        /// Issued when Legal Fee is placed on hold until the LOC is closed. [locId, payerAccountId, legalFee]
+        LegalFeeHeld(T::LocId, T::AccountId, BalanceOf<T>),
+        /// Issued when a Legal Fee on hold is returned to its payer. [locId, payerAccountId, legalFee]
+        LegalFeeReturned(T::LocId, T::AccountId, BalanceOf<T>),
+        /// Issued when the deposit of a new LOC is placed on hold. [locId, depositor, deposit]
+        DraftDepositHeld(T::LocId, T::AccountId, BalanceOf<T>),
+        /// Issued when the deposit of a LOC is returned to its depositor. [locId, depositor, deposit]
+        DraftDepositReturned(T::LocId, T::AccountId, BalanceOf<T>),
         /// Issued when Certificate Fee is withdrawn. [payerAccountId, fee]
         CertificateFeeWithdrawn(T::AccountId, BalanceOf<T>),
+        /// Issued when a metadata item was added to a LOC. [locId, name, submitter]
+        MetadataItemAdded(T::LocId, <T as Config>::Hash, SupportedAccountIdOf<T>),
+        /// Issued when a file was added to a LOC. [locId, hash, submitter]
+        FileAdded(T::LocId, <T as Config>::Hash, SupportedAccountIdOf<T>),
+        /// Issued when a link was added to a LOC. [locId, linkedLocId, submitter]
+        LinkAdded(T::LocId, T::LocId, T::AccountId),
+        /// Issued when a metadata item was acknowledged. [locId, name, submitter]
+        MetadataItemAcknowledged(T::LocId, <T as Config>::Hash, SupportedAccountIdOf<T>),
+        /// Issued when a file was acknowledged. [locId, hash, submitter]
+        FileAcknowledged(T::LocId, <T as Config>::Hash, SupportedAccountIdOf<T>),
+        /// Issued when an issuer was nominated. [legalOfficer, issuer, identityLocId]
+        IssuerNominated(T::AccountId, T::AccountId, T::LocId),
+        /// Issued when an issuer was dismissed. [legalOfficer, issuer]
+        IssuerDismissed(T::AccountId, T::AccountId),
+        /// Issued when an issuer was selected or unselected on a LOC. [locId, issuer, selected]
+        IssuerSelectionChanged(T::LocId, T::AccountId, bool),
+        /// Issued when a tokens record was added to a collection. [locId, recordId, submitter]
+        TokensRecordAdded(T::LocId, T::TokensRecordId, SupportedAccountIdOf<T>),
+        /// Issued when a metadata item was deleted. [locId, name]
+        MetadataItemDeleted(T::LocId, <T as Config>::Hash),
+        /// Issued when a file was deleted. The storage fee is not refunded. [locId, hash]
+        FileDeleted(T::LocId, <T as Config>::Hash),
+        /// Issued when a link was deleted. [locId, linkedLocId]
+        LinkDeleted(T::LocId, T::LocId),
+        /// Issued when the requester submitted a draft LOC for review. [locId]
+        LocReviewRequested(T::LocId),
+        /// Issued when the owner accepted a LOC, which is now open. [locId]
+        LocAccepted(T::LocId),
+        /// Issued when the owner rejected a LOC. [locId, reason]
+        LocRejected(T::LocId, <T as Config>::Hash),
+        /// Issued when the requester cancelled a LOC. [locId]
+        LocCancelled(T::LocId),
+        /// Issued when a LOC under review went back to draft because its legal fee hold timed out. [locId]
+        LocReviewExpired(T::LocId),
+        /// Issued when the exchange rate of a fiat currency was updated. [currency, rate, validFrom, validUntil]
+        ExchangeRateUpdated(FiatCurrency, BalanceOf<T>, T::BlockNumber, T::BlockNumber),
+        /// Issued when the exchange rate feeder was set or removed. [feeder]
+        ExchangeRateFeederSet(Option<T::AccountId>),
+        /// Issued when the root of a collection's items was snapshot because the collection reached its limits. [locId, root]
+        CollectionRootSnapshotTaken(T::LocId, <T as Config>::Hash),
     }
 
     #[pallet::error]
@@ -558,10 +1102,88 @@ pub mod pallet {
         CannotCloseUnacknowledged,
         /// Invalid token issuance
         BadTokenIssuance,
+        /// Metadata item cannot be added because the LOC already has the maximum number of metadata items
+        LocMetadataTooMuchData,
+        /// File cannot be added because the LOC already has the maximum number of files
+        LocFilesTooMuchData,
+        /// Link cannot be added because the LOC already has the maximum number of links
+        LocLinksTooMuchData,
+        /// The sponsorship expiry block is not in the future
+        InvalidSponsorshipExpiry,
+        /// No more sponsorships can expire at the requested block
+        SponsorshipExpiryLimitReached,
+        /// Paying the fee would exceed the maximum balance of the sponsorship
+        SponsorshipBudgetExceeded,
+        /// The signature is invalid or was not produced by the submitter
+        InvalidEthereumSignature,
+        /// The nonce does not match the one expected in the next signature of the submitter
+        InvalidEthereumNonce,
+        /// The status of the LOC does not allow the requested transition
+        InvalidLocStatus,
+        /// Identity LOCs and LOCs already acknowledged or modified by their owner cannot be cancelled
+        CannotCancel,
+        /// No more legal fee holds can expire at the current timeout block
+        LegalFeeHoldExpiryLimitReached,
+        /// The exchange rate and its validity period must not be zero
+        InvalidExchangeRate,
+        /// The exchange rate change exceeds the maximum allowed per update
+        ExchangeRateChangeTooLarge,
+        /// No more collections can have the requested last block of submission
+        CollectionLastBlockLimitReached,
+        /// The token is already registered by a collection item
+        DuplicateToken,
+        /// Storage fees of items relayed on behalf of their submitter must be paid by a sponsor
+        SponsorshipRequired,
+        /// The sponsorship of the LOC does not exist anymore
+        SponsorshipNotFound,
+        /// The feeder cannot update the exchange rate before the end of the minimum interval since the last update
+        ExchangeRateUpdateTooEarly,
+        /// Legacy seals cannot be set anymore
+        InvalidSealVersion,
+        /// Issuer cannot be selected because it is already selected on the maximum number of LOCs of the legal officer
+        IssuerLocsLimitReached,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+            let expired = <SponsorshipsByExpiryMap<T>>::take(now);
+            for sponsorship_id in expired.iter() {
+                match <SponsorshipMap<T>>::get(sponsorship_id) {
+                    Some(sponsorship) if sponsorship.used_locs == 0 => {
+                        <SponsorshipMap<T>>::remove(sponsorship_id);
+                        Self::deposit_event(Event::SponsorshipExpired(*sponsorship_id, sponsorship.sponsor, sponsorship.sponsored_account));
+                    },
+                    _ => (),
+                }
+            }
+            let expired_holds = <LegalFeeHoldsByExpiryMap<T>>::take(now);
+            for loc_id in expired_holds.iter() {
+                match (<LegalFeeHoldsMap<T>>::get(loc_id), <LocMap<T>>::get(loc_id)) {
+                    (Some(hold), Some(loc)) if loc.status == LocStatus::ReviewRequested => {
+                        <LegalFeeHoldsMap<T>>::remove(loc_id);
+                        Self::return_legal_fee(loc_id, &loc, hold);
+                        <LocMap<T>>::mutate(loc_id, |loc| {
+                            let mutable_loc = loc.as_mut().unwrap();
+                            mutable_loc.status = LocStatus::Draft;
+                        });
+                        Self::deposit_event(Event::LocReviewExpired(*loc_id));
+                    },
+                    _ => (),
+                }
+            }
+            let ending_collections = <CollectionsByLastBlockMap<T>>::take(now);
+            for loc_id in ending_collections.iter() {
+                let ending = matches!(<LocMap<T>>::get(loc_id), Some(loc) if loc.collection_last_block_submission == Some(now));
+                if ending && !<CollectionRootSnapshotsMap<T>>::contains_key(loc_id) {
+                    Self::snapshot_collection_root(loc_id);
+                }
+            }
+            T::WeightInfo::expire_sponsorships(expired.len() as u32)
+                .saturating_add(T::WeightInfo::expire_legal_fee_holds(expired_holds.len() as u32))
+                .saturating_add(T::WeightInfo::snapshot_collection_roots(ending_collections.len() as u32))
+        }
+
         fn integrity_test() {
             assert!(T::FileStorageFeeDistributionKey::get().is_valid());
         }
@@ -603,7 +1225,7 @@ pub mod pallet {
         }
     }
 
-    #[derive(Encode, Decode, Eq, PartialEq, Debug, TypeInfo)]
+    #[derive(Encode, Decode, Eq, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
     pub enum StorageVersion {
         V1,
         V2MakeLocVoid,
@@ -622,11 +1244,21 @@ pub mod pallet {
         V15AddTokenIssuance,
         V16MoveTokenIssuance,
         V17HashItemRecordPublicData,
+        V18AddOwnerIndex,
+        V19AddTimepoints,
+        V20BoundedLocStorage,
+        V21IndexRequesterLocsByType,
+        V22SponsorshipLimits,
+        V24TokensRecordOtherSubmitter,
+        V25LocStatus,
+        V27VersionedSeals,
+        V28IndexFileHashes,
+        V29IndexTokens,
     }
 
     impl Default for StorageVersion {
         fn default() -> StorageVersion {
-            return StorageVersion::V17HashItemRecordPublicData;
+            return StorageVersion::V29IndexTokens;
         }
     }
 
@@ -639,12 +1271,15 @@ pub mod pallet {
     impl<T:Config> Pallet<T> {
 
         /// Creates a new Polkadot Identity LOC i.e. a LOC linking a real identity to an AccountId.
+        /// The LOC is a draft until the owner accepts it, see `request_review`.
+        /// If a sponsorship is provided, the sponsor pays the legal fee and storage fees.
         #[pallet::call_index(0)]
         #[pallet::weight(T::WeightInfo::create_polkadot_identity_loc())]
         pub fn create_polkadot_identity_loc(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
             legal_officer: T::AccountId,
+            sponsorship_id: Option<T::SponsorshipId>,
         ) -> DispatchResultWithPostInfo {
             let requester_account_id = ensure_signed(origin)?;
 
@@ -652,13 +1287,21 @@ pub mod pallet {
                 Err(Error::<T>::Unauthorized)?
             } else if <LocMap<T>>::contains_key(&loc_id) {
                 Err(Error::<T>::AlreadyExists)?
+            } else if !Self::can_link_to_optional_sponsorship(&sponsorship_id, &legal_officer, &SupportedAccountId::Polkadot(requester_account_id.clone())) {
+                Err(Error::<T>::CannotLinkToSponsorship)?
             } else {
                 let requester = RequesterOf::<T>::Account(requester_account_id.clone());
-                let loc = Self::build_open_loc(&legal_officer, &requester, LocType::Identity, None);
-
-                Self::apply_legal_fee(&loc)?;
+                let loc = LegalOfficerCaseOf::<T> {
+                    status: LocStatus::Draft,
+                    ..Self::build_open_loc(&legal_officer, &requester, LocType::Identity, sponsorship_id)
+                };
+                Self::hold_draft_deposit(&loc_id, &requester_account_id)?;
                 <LocMap<T>>::insert(loc_id, loc);
-                Self::link_with_account(&requester_account_id, &loc_id);
+                Self::link_with_owner(&legal_officer, &loc_id);
+                Self::link_with_account(&requester_account_id, LocType::Identity, &loc_id);
+                if sponsorship_id.is_some() {
+                    Self::link_sponsorship_to_loc(&sponsorship_id.unwrap());
+                }
 
                 Self::deposit_event(Event::LocCreated(loc_id));
                 Ok(().into())
@@ -681,6 +1324,7 @@ pub mod pallet {
                 let requester = RequesterOf::<T>::None;
                 let loc = Self::build_open_loc(&who, &requester, LocType::Identity, None);
                 <LocMap<T>>::insert(loc_id, loc);
+                Self::link_with_owner(&who, &loc_id);
 
                 Self::deposit_event(Event::LocCreated(loc_id));
                 Ok(().into())
@@ -688,12 +1332,15 @@ pub mod pallet {
         }
 
         /// Creates a new Polkadot Transaction LOC i.e. a LOC requested with an AccountId
+        /// The LOC is a draft until the owner accepts it, see `request_review`.
+        /// If a sponsorship is provided, the sponsor pays the legal fee and storage fees.
         #[pallet::call_index(2)]
         #[pallet::weight(T::WeightInfo::create_polkadot_transaction_loc())]
         pub fn create_polkadot_transaction_loc(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
             legal_officer: T::AccountId,
+            sponsorship_id: Option<T::SponsorshipId>,
         ) -> DispatchResultWithPostInfo {
             let requester_account_id = ensure_signed(origin)?;
 
@@ -701,13 +1348,21 @@ pub mod pallet {
                 Err(Error::<T>::Unauthorized)?
             } else if <LocMap<T>>::contains_key(&loc_id) {
                 Err(Error::<T>::AlreadyExists)?
+            } else if !Self::can_link_to_optional_sponsorship(&sponsorship_id, &legal_officer, &SupportedAccountId::Polkadot(requester_account_id.clone())) {
+                Err(Error::<T>::CannotLinkToSponsorship)?
             } else {
                 let requester = RequesterOf::<T>::Account(requester_account_id.clone());
-                let loc = Self::build_open_loc(&legal_officer, &requester, LocType::Transaction, None);
-
-                Self::apply_legal_fee(&loc)?;
+                let loc = LegalOfficerCaseOf::<T> {
+                    status: LocStatus::Draft,
+                    ..Self::build_open_loc(&legal_officer, &requester, LocType::Transaction, sponsorship_id)
+                };
+                Self::hold_draft_deposit(&loc_id, &requester_account_id)?;
                 <LocMap<T>>::insert(loc_id, loc);
-                Self::link_with_account(&requester_account_id, &loc_id);
+                Self::link_with_owner(&legal_officer, &loc_id);
+                Self::link_with_account(&requester_account_id, LocType::Transaction, &loc_id);
+                if sponsorship_id.is_some() {
+                    Self::link_sponsorship_to_loc(&sponsorship_id.unwrap());
+                }
 
                 Self::deposit_event(Event::LocCreated(loc_id));
                 Ok(().into())
@@ -738,7 +1393,8 @@ pub mod pallet {
                             let requester = RequesterOf::<T>::Loc(requester_loc_id.clone());
                             let new_loc = Self::build_open_loc(&who, &requester, LocType::Transaction, None);
                             <LocMap<T>>::insert(loc_id, new_loc);
-                            Self::link_with_identity_loc(&requester_loc_id, &loc_id);
+                            Self::link_with_owner(&who, &loc_id);
+                            Self::link_with_identity_loc(&requester_loc_id, LocType::Transaction, &loc_id);
                         },
                 }
 
@@ -748,6 +1404,8 @@ pub mod pallet {
         }
 
         /// Creates a new Collection LOC
+        /// The LOC is a draft until the owner accepts it, see `request_review`.
+        /// If a sponsorship is provided, the sponsor pays the legal fee and storage fees.
         #[pallet::call_index(4)]
         #[pallet::weight(T::WeightInfo::create_collection_loc())]
         pub fn create_collection_loc(
@@ -757,6 +1415,7 @@ pub mod pallet {
             collection_last_block_submission: Option<T::BlockNumber>,
             collection_max_size: Option<u32>,
             collection_can_upload: bool,
+            sponsorship_id: Option<T::SponsorshipId>,
         ) -> DispatchResultWithPostInfo {
             let requester_account_id = ensure_signed(origin)?;
 
@@ -768,19 +1427,28 @@ pub mod pallet {
 
             if <LocMap<T>>::contains_key(&loc_id) {
                 Err(Error::<T>::AlreadyExists)?
+            } else if !Self::can_link_to_optional_sponsorship(&sponsorship_id, &legal_officer, &SupportedAccountId::Polkadot(requester_account_id.clone())) {
+                Err(Error::<T>::CannotLinkToSponsorship)?
             } else {
                 let requester = RequesterOf::<T>::Account(requester_account_id.clone());
-                let loc = Self::build_open_collection_loc(
-                    &legal_officer,
-                    &requester,
-                    collection_last_block_submission,
-                    collection_max_size,
-                    collection_can_upload,
-                );
-
-                Self::apply_legal_fee(&loc)?;
+                let loc = LegalOfficerCaseOf::<T> {
+                    status: LocStatus::Draft,
+                    ..Self::build_open_collection_loc(
+                        &legal_officer,
+                        &requester,
+                        collection_last_block_submission,
+                        collection_max_size,
+                        collection_can_upload,
+                        sponsorship_id,
+                    )
+                };
+                Self::hold_draft_deposit(&loc_id, &requester_account_id)?;
                 <LocMap<T>>::insert(loc_id, loc);
-                Self::link_with_account(&requester_account_id, &loc_id);
+                Self::link_with_owner(&legal_officer, &loc_id);
+                Self::link_with_account(&requester_account_id, LocType::Collection, &loc_id);
+                if sponsorship_id.is_some() {
+                    Self::link_sponsorship_to_loc(&sponsorship_id.unwrap());
+                }
 
                 Self::deposit_event(Event::LocCreated(loc_id));
                 Ok(().into())
@@ -789,11 +1457,11 @@ pub mod pallet {
 
         /// Add LOC metadata
         #[pallet::call_index(5)]
-        #[pallet::weight(T::WeightInfo::add_metadata())]
+        #[pallet::weight(T::WeightInfo::add_metadata(T::MaxLocMetadata::get()))]
         pub fn add_metadata(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
-            item: MetadataItemParams<T::AccountId, T::EthereumAddress, <T as pallet::Config>::Hash>
+            item: MetadataItemParams<T::AccountId, OtherAccountIdOf<T>, <T as pallet::Config>::Hash>
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
@@ -807,37 +1475,26 @@ pub mod pallet {
                     !Self::can_submit(&loc_id, &loc, &item.submitter)
                 ) {
                     Err(Error::<T>::Unauthorized)?
-                } else if loc.closed {
+                } else if loc.status.is_frozen() {
                     Err(Error::<T>::CannotMutate)?
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
                 } else if submitted_by_owner && !Self::can_submit(&loc_id, &loc, &item.submitter) {
                     Err(Error::<T>::CannotSubmit)?
                 } else {
-                    if loc.metadata.iter().find(|metadata_item| metadata_item.name == item.name).is_some() {
-                        Err(Error::<T>::DuplicateLocMetadata)?
-                    }
-                    <LocMap<T>>::mutate(loc_id, |loc| {
-                        let mutable_loc = loc.as_mut().unwrap();
-                        mutable_loc.metadata.push(MetadataItem {
-                            name: item.name,
-                            value: item.value,
-                            submitter: item.submitter,
-                            acknowledged: submitted_by_owner,
-                        });
-                    });
-                    Ok(().into())
+                    Self::do_add_metadata(loc_id, &loc, item, submitted_by_owner)?;
+                    Ok(Some(T::WeightInfo::add_metadata(loc.metadata.len() as u32)).into())
                 }
             }
         }
 
         /// Add file to LOC
         #[pallet::call_index(6)]
-        #[pallet::weight(T::WeightInfo::add_file())]
+        #[pallet::weight(T::WeightInfo::add_file(T::MaxLocFiles::get()))]
         pub fn add_file(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
-            file: FileParams<<T as pallet::Config>::Hash, T::AccountId, T::EthereumAddress>
+            file: FileParams<<T as pallet::Config>::Hash, T::AccountId, OtherAccountIdOf<T>>
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
@@ -847,49 +1504,26 @@ pub mod pallet {
                 let loc = <LocMap<T>>::get(&loc_id).unwrap();
                 let submitted_by_owner: bool = loc.owner == who;
                 if !submitted_by_owner && (
-                    file.submitter != SupportedAccountId::Polkadot(who) ||
+                    file.submitter != SupportedAccountId::Polkadot(who.clone()) ||
                         !Self::can_submit(&loc_id, &loc, &file.submitter)
                 ) {
                     Err(Error::<T>::Unauthorized)?
-                } else if loc.closed {
+                } else if loc.status.is_frozen() {
                     Err(Error::<T>::CannotMutate)?
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
                 } else if !Self::can_submit(&loc_id, &loc, &file.submitter) {
                     Err(Error::<T>::CannotSubmit)?
                 } else {
-                    if loc.files.iter().find(|item| item.hash == file.hash).is_some() {
-                        Err(Error::<T>::DuplicateLocFile)?
-                    }
-                    let fee_payer;
-                    if loc.sponsorship_id.is_some() {
-                        let sponsorship = <SponsorshipMap<T>>::get(loc.sponsorship_id.unwrap()).unwrap();
-                        fee_payer = sponsorship.sponsor;
-                    } else {
-                        fee_payer = match loc.requester {
-                            Account(requester_account) => requester_account,
-                            _ => loc.owner
-                        };
-                    }
-                    Self::apply_file_storage_fee(&fee_payer, 1, file.size)?;
-                    <LocMap<T>>::mutate(loc_id, |loc| {
-                        let mutable_loc = loc.as_mut().unwrap();
-                        mutable_loc.files.push(File {
-                            hash: file.hash,
-                            nature: file.nature,
-                            submitter: file.submitter,
-                            size: file.size,
-                            acknowledged: submitted_by_owner,
-                        });
-                    });
-                    Ok(().into())
+                    Self::do_add_file(who, loc_id, &loc, file, submitted_by_owner)?;
+                    Ok(Some(T::WeightInfo::add_file(loc.files.len() as u32)).into())
                 }
             }
         }
 
         /// Add a link to LOC
         #[pallet::call_index(7)]
-        #[pallet::weight(T::WeightInfo::add_link())]
+        #[pallet::weight(T::WeightInfo::add_link(T::MaxLocLinks::get()))]
         pub fn add_link(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
@@ -903,7 +1537,7 @@ pub mod pallet {
                 let loc = <LocMap<T>>::get(&loc_id).unwrap();
                 if loc.owner != who {
                     Err(Error::<T>::Unauthorized)?
-                } else if loc.closed {
+                } else if loc.status.is_frozen() {
                     Err(Error::<T>::CannotMutate)?
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
@@ -913,18 +1547,25 @@ pub mod pallet {
                     if loc.links.iter().find(|item| item.id == link.id).is_some() {
                         Err(Error::<T>::DuplicateLocLink)?
                     }
-                    <LocMap<T>>::mutate(loc_id, |loc| {
+                    let linked_loc_id = link.id;
+                    <LocMap<T>>::try_mutate(loc_id, |loc| {
                         let mutable_loc = loc.as_mut().unwrap();
-                        mutable_loc.links.push(link);
-                    });
-                    Ok(().into())
+                        mutable_loc.links.try_push(LocLinkItem {
+                            id: link.id,
+                            nature: link.nature,
+                            added_on: Some(Self::current_timepoint()),
+                        })
+                    }).map_err(|_| Error::<T>::LocLinksTooMuchData)?;
+
+                    Self::deposit_event(Event::LinkAdded(loc_id, linked_loc_id, who));
+                    Ok(Some(T::WeightInfo::add_link(loc.links.len() as u32)).into())
                 }
             }
         }
 
         /// Close LOC.
         #[pallet::call_index(8)]
-        #[pallet::weight(T::WeightInfo::close())]
+        #[pallet::weight(T::WeightInfo::close(T::MaxLocMetadata::get(), T::MaxLocFiles::get(), T::MaxLocLinks::get()))]
         pub fn close(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
@@ -932,14 +1573,17 @@ pub mod pallet {
             Self::do_close(origin, loc_id, None)
         }
 
-        /// Close and seal LOC.
+        /// Close and seal LOC. Legacy `V0` seals are not accepted.
         #[pallet::call_index(9)]
-        #[pallet::weight(T::WeightInfo::close())]
+        #[pallet::weight(T::WeightInfo::close_and_seal(T::MaxLocMetadata::get(), T::MaxLocFiles::get(), T::MaxLocLinks::get()))]
         pub fn close_and_seal(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
-            seal: <T as Config>::Hash,
+            seal: SealOf<T>,
         ) -> DispatchResultWithPostInfo {
+            if seal.version == SealVersion::V0 {
+                Err(Error::<T>::InvalidSealVersion)?
+            }
             Self::do_close(origin, loc_id, Some(seal))
         }
 
@@ -966,7 +1610,7 @@ pub mod pallet {
 
         /// Adds an item to a collection
         #[pallet::call_index(12)]
-        #[pallet::weight(T::WeightInfo::add_collection_item())]
+        #[pallet::weight(T::WeightInfo::add_collection_item(item_files.len() as u32, terms_and_conditions.len() as u32))]
         pub fn add_collection_item(
             origin: OriginFor<T>,
             #[pallet::compact] collection_loc_id: T::LocId,
@@ -976,13 +1620,16 @@ pub mod pallet {
             item_token: Option<CollectionItemToken<T::TokenIssuance, <T as Config>::Hash>>,
             restricted_delivery: bool,
             terms_and_conditions: Vec<TermsAndConditionsElement<T::LocId, <T as Config>::Hash>>,
-        ) -> DispatchResultWithPostInfo { Self::do_add_collection_item(origin, collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions) }
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_add_collection_item(who.clone(), SupportedAccountId::Polkadot(who), collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions)
+        }
 
         /// Adds an item with terms and conditions to a collection
         /// 
         /// DEPRECATED - this extrinsic will be removed in a future release, use add_collection_item instead
         #[pallet::call_index(13)]
-        #[pallet::weight(T::WeightInfo::add_collection_item())]
+        #[pallet::weight(T::WeightInfo::add_collection_item(item_files.len() as u32, terms_and_conditions.len() as u32))]
         pub fn add_collection_item_with_terms_and_conditions(
             origin: OriginFor<T>,
             #[pallet::compact] collection_loc_id: T::LocId,
@@ -992,7 +1639,10 @@ pub mod pallet {
             item_token: Option<CollectionItemToken<T::TokenIssuance, <T as Config>::Hash>>,
             restricted_delivery: bool,
             terms_and_conditions: Vec<TermsAndConditionsElement<<T as pallet::Config>::LocId, <T as Config>::Hash>>,
-        ) -> DispatchResultWithPostInfo { Self::do_add_collection_item(origin, collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions) }
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            Self::do_add_collection_item(who.clone(), SupportedAccountId::Polkadot(who), collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions)
+        }
 
         /// Nominate an issuer
         #[pallet::call_index(14)]
@@ -1009,7 +1659,7 @@ pub mod pallet {
                 Err(Error::<T>::InvalidIdentityLoc)?
             }
             let identity_loc = maybe_identity_loc.unwrap();
-            if !identity_loc.closed
+            if identity_loc.status != LocStatus::Closed
                 || identity_loc.void_info.is_some()
                 || match identity_loc.requester { Account(requester_account) => requester_account != issuer, _ => true } {
                 Err(Error::<T>::InvalidIdentityLoc)?
@@ -1021,13 +1671,15 @@ pub mod pallet {
                 <VerifiedIssuersMap<T>>::insert(&who, &issuer, VerifiedIssuer {
                     identity_loc: identity_loc_id
                 });
+
+                Self::deposit_event(Event::IssuerNominated(who, issuer, identity_loc_id));
                 Ok(().into())
             }
         }
 
         /// Dismiss an issuer
         #[pallet::call_index(15)]
-        #[pallet::weight(T::WeightInfo::dismiss_issuer())]
+        #[pallet::weight(T::WeightInfo::dismiss_issuer(T::MaxIssuerLocs::get()))]
         pub fn dismiss_issuer(
             origin: OriginFor<T>,
             issuer: T::AccountId,
@@ -1045,14 +1697,16 @@ pub mod pallet {
                 .collect();
             issuer_locs.iter().for_each(|loc_id| {
                 <VerifiedIssuersByLocMap<T>>::remove(loc_id, &issuer);
+                Self::deposit_event(Event::IssuerSelectionChanged(*loc_id, issuer.clone(), false));
             });
 
-            Ok(().into())
+            Self::deposit_event(Event::IssuerDismissed(who, issuer));
+            Ok(Some(T::WeightInfo::dismiss_issuer(issuer_locs.len() as u32)).into())
         }
 
         /// Select/unselect an issuer on a given LOC
         #[pallet::call_index(16)]
-        #[pallet::weight(T::WeightInfo::set_issuer_selection())]
+        #[pallet::weight(T::WeightInfo::set_issuer_selection(T::MaxIssuerLocs::get()))]
         pub fn set_issuer_selection(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
@@ -1073,21 +1727,30 @@ pub mod pallet {
                     Err(Error::<T>::NotNominated)?
                 } else {
                     let already_issuer = Self::verified_issuers_by_loc(loc_id, &issuer);
+                    let mut issuer_locs = 0;
                     if already_issuer.is_some() && !selected {
                         <VerifiedIssuersByLocMap<T>>::remove(loc_id, &issuer);
                         <LocsByVerifiedIssuerMap<T>>::remove((&issuer, loc.owner, loc_id));
+                        Self::deposit_event(Event::IssuerSelectionChanged(loc_id, issuer, selected));
                     } else if already_issuer.is_none() && selected {
+                        issuer_locs = <LocsByVerifiedIssuerMap<T>>::iter_key_prefix((&issuer, &who))
+                            .take(T::MaxIssuerLocs::get() as usize)
+                            .count();
+                        if issuer_locs >= T::MaxIssuerLocs::get() as usize {
+                            Err(Error::<T>::IssuerLocsLimitReached)?
+                        }
                         <VerifiedIssuersByLocMap<T>>::insert(loc_id, &issuer, ());
                         <LocsByVerifiedIssuerMap<T>>::insert((&issuer, loc.owner, loc_id), ());
+                        Self::deposit_event(Event::IssuerSelectionChanged(loc_id, issuer, selected));
                     }
-                    Ok(().into())
+                    Ok(Some(T::WeightInfo::set_issuer_selection(issuer_locs as u32)).into())
                 }
             }
         }
 
         /// Add token record
         #[pallet::call_index(17)]
-        #[pallet::weight(T::WeightInfo::add_tokens_record())]
+        #[pallet::weight(T::WeightInfo::add_tokens_record(files.len() as u32))]
         pub fn add_tokens_record(
             origin: OriginFor<T>,
             #[pallet::compact] collection_loc_id: T::LocId,
@@ -1096,76 +1759,34 @@ pub mod pallet {
             files: Vec<TokensRecordFileOf<T>>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
-
-            let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
-            match collection_loc_option {
-                None => Err(Error::<T>::WrongCollectionLoc)?,
-                Some(collection_loc) => {
-                    if <TokensRecordsMap<T>>::contains_key(&collection_loc_id, &record_id) {
-                        Err(Error::<T>::TokensRecordAlreadyExists)?
-                    }
-                    if ! Self::can_add_record(&who, &collection_loc_id, &collection_loc) {
-                        Err(Error::<T>::CannotAddRecord)?
-                    }
-                    if files.len() == 0 {
-                        Err(Error::<T>::MustUpload)?
-                    } else {
-                        let files_hashes: Vec<<T as Config>::Hash> = files.iter()
-                            .map(|file| file.hash)
-                            .collect();
-                        if !Self::has_unique_elements(&files_hashes) {
-                            Err(Error::<T>::DuplicateFile)?
-                        }
-                    }
-
-                    let mut bounded_files: BoundedVec<TokensRecordFileOf<T>, T::MaxTokensRecordFiles> = BoundedVec::with_bounded_capacity(files.len());
-                    for file in files.iter() {
-                        bounded_files.try_push(file.clone()).map_err(|_| Error::<T>::TokensRecordTooMuchData)?;
-                    }
-                    let fee_payer = match collection_loc.requester {
-                        Account(requester_account) => requester_account,
-                        _ => collection_loc.owner
-                    };
-
-                    let tot_size = files.iter()
-                        .map(|file| file.size)
-                        .fold(0, |tot, current| tot + current);
-                    Self::apply_file_storage_fee(&fee_payer, files.len(), tot_size)?;
-                    let record = TokensRecord {
-                        description,
-                        files: bounded_files,
-                        submitter: who,
-                    };
-                    <TokensRecordsMap<T>>::insert(collection_loc_id, record_id, record);
-                },
-            }
-
-            Ok(().into())
+            Self::do_add_tokens_record(who.clone(), SupportedAccountId::Polkadot(who), collection_loc_id, record_id, description, files)
         }
 
-        /// Creates a new Identity LOC whose requester is another address (Currently only Ethereum address is supported).
+        /// Creates a new Identity LOC whose requester is an account of another blockchain (Ethereum, Bitcoin, Solana or Tezos).
+        /// The sponsor's legal fee is placed on hold until the LOC is closed.
         #[pallet::call_index(18)]
         #[pallet::weight(T::WeightInfo::create_other_identity_loc())]
         pub fn create_other_identity_loc(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
-            requester_account_id: OtherAccountId<T::EthereumAddress>,
+            requester_account_id: OtherAccountIdOf<T>,
             #[pallet::compact] sponsorship_id: T::SponsorshipId,
         ) -> DispatchResultWithPostInfo {
             let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
 
             if <LocMap<T>>::contains_key(&loc_id) {
                 Err(Error::<T>::AlreadyExists)?
-            } else if !Self::can_link_to_sponsorship(&sponsorship_id, &who, &SupportedAccountId::Other(requester_account_id)) {
+            } else if !Self::can_link_to_sponsorship(&sponsorship_id, &who, &SupportedAccountId::Other(requester_account_id.clone())) {
                 Err(Error::<T>::CannotLinkToSponsorship)?
             } else {
                 let requester = RequesterOf::<T>::OtherAccount(requester_account_id.clone());
                 let loc = Self::build_open_loc(&who, &requester, LocType::Identity, Some(sponsorship_id));
 
-                Self::apply_legal_fee(&loc)?;
+                Self::hold_legal_fee(&loc_id, &loc, None)?;
                 <LocMap<T>>::insert(loc_id, loc);
-                Self::link_with_other_account(&requester_account_id, &loc_id);
-                Self::link_sponsorship_to_loc(&sponsorship_id, &loc_id);
+                Self::link_with_owner(&who, &loc_id);
+                Self::link_with_other_account(&requester_account_id, LocType::Identity, &loc_id);
+                Self::link_sponsorship_to_loc(&sponsorship_id);
 
                 Self::deposit_event(Event::LocCreated(loc_id));
                 Ok(().into())
@@ -1173,13 +1794,19 @@ pub mod pallet {
         }
 
         /// Creates a sponsorship.
+        /// The sponsorship expires at block `expires_on`. Until then, it may be used for at most `max_locs` LOCs
+        /// (no limit if `None`), and the sponsor pays at most `max_balance` in legal and storage fees.
+        /// An unused sponsorship is removed when it expires, a used one is kept to pay the fees of its LOCs.
         #[pallet::call_index(19)]
         #[pallet::weight(T::WeightInfo::sponsor())]
         pub fn sponsor(
             origin: OriginFor<T>,
             #[pallet::compact] sponsorship_id: T::SponsorshipId,
-            sponsored_account: SupportedAccountId<T::AccountId, T::EthereumAddress>,
+            sponsored_account: SupportedAccountIdOf<T>,
             legal_officer: T::AccountId,
+            expires_on: T::BlockNumber,
+            max_balance: BalanceOf<T>,
+            max_locs: Option<u32>,
         ) -> DispatchResultWithPostInfo {
             let sponsor = ensure_signed(origin)?;
 
@@ -1187,12 +1814,20 @@ pub mod pallet {
                 Err(Error::<T>::Unauthorized)?
             } else if <SponsorshipMap<T>>::contains_key(&sponsorship_id) {
                 Err(Error::<T>::AlreadyExists)?
+            } else if expires_on <= <frame_system::Pallet<T>>::block_number() {
+                Err(Error::<T>::InvalidSponsorshipExpiry)?
             } else {
+                <SponsorshipsByExpiryMap<T>>::try_mutate(expires_on, |sponsorship_ids| sponsorship_ids.try_push(sponsorship_id))
+                    .map_err(|_| Error::<T>::SponsorshipExpiryLimitReached)?;
                 let sponsorship = Sponsorship {
                     sponsor: sponsor.clone(),
                     sponsored_account: sponsored_account.clone(),
                     legal_officer,
-                    loc_id: None,
+                    expires_on,
+                    max_balance,
+                    max_locs,
+                    used_locs: 0,
+                    used_balance: Zero::zero(),
                 };
                 <SponsorshipMap<T>>::insert(sponsorship_id, sponsorship);
 
@@ -1201,9 +1836,9 @@ pub mod pallet {
             }
         }
 
-        /// Withdraws an unused sponsorship.
+        /// Withdraws a sponsorship that was not used for any LOC yet.
         #[pallet::call_index(20)]
-        #[pallet::weight(T::WeightInfo::sponsor())]
+        #[pallet::weight(T::WeightInfo::withdraw_sponsorship())]
         pub fn withdraw_sponsorship(
             origin: OriginFor<T>,
             #[pallet::compact] sponsorship_id: T::SponsorshipId,
@@ -1215,11 +1850,12 @@ pub mod pallet {
                 Err(Error::<T>::NotFound)?
             } else {
                 let sponsorship = maybe_sponsorship.unwrap();
-                if sponsorship.loc_id.is_some() {
+                if sponsorship.used_locs > 0 {
                     Err(Error::<T>::AlreadyUsed)?
                 } else {
                     let sponsored_account = sponsorship.sponsored_account;
                     <SponsorshipMap<T>>::remove(&sponsorship_id);
+                    <SponsorshipsByExpiryMap<T>>::mutate(sponsorship.expires_on, |sponsorship_ids| sponsorship_ids.retain(|id| *id != sponsorship_id));
 
                     Self::deposit_event(Event::SponsorshipWithdrawn(sponsorship_id, sponsor, sponsored_account));
                     Ok(().into())
@@ -1229,7 +1865,7 @@ pub mod pallet {
 
         /// Acknowledge a metadata item.
         #[pallet::call_index(21)]
-        #[pallet::weight(T::WeightInfo::acknowledge_metadata())]
+        #[pallet::weight(T::WeightInfo::acknowledge_metadata(T::MaxLocMetadata::get()))]
         pub fn acknowledge_metadata(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
@@ -1243,7 +1879,7 @@ pub mod pallet {
                 let loc = <LocMap<T>>::get(&loc_id).unwrap();
                 if loc.owner != who {
                     Err(Error::<T>::Unauthorized)?
-                } else if loc.closed {
+                } else if loc.status.is_frozen() {
                     Err(Error::<T>::CannotMutate)?
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
@@ -1259,15 +1895,19 @@ pub mod pallet {
                     <LocMap<T>>::mutate(loc_id, |loc| {
                         let mutable_loc = loc.as_mut().unwrap();
                         mutable_loc.metadata[item_index].acknowledged = true;
+                        mutable_loc.metadata[item_index].acknowledged_on = Some(Self::current_timepoint());
                     });
-                    Ok(().into())
+
+                    let submitter = loc.metadata[item_index].submitter.clone();
+                    Self::deposit_event(Event::MetadataItemAcknowledged(loc_id, name, submitter));
+                    Ok(Some(T::WeightInfo::acknowledge_metadata(loc.metadata.len() as u32)).into())
                 }
             }
         }
 
         /// Acknowledge a file.
         #[pallet::call_index(22)]
-        #[pallet::weight(T::WeightInfo::acknowledge_file())]
+        #[pallet::weight(T::WeightInfo::acknowledge_file(T::MaxLocFiles::get()))]
         pub fn acknowledge_file(
             origin: OriginFor<T>,
             #[pallet::compact] loc_id: T::LocId,
@@ -1281,7 +1921,7 @@ pub mod pallet {
                 let loc = <LocMap<T>>::get(&loc_id).unwrap();
                 if loc.owner != who {
                     Err(Error::<T>::Unauthorized)?
-                } else if loc.closed {
+                } else if loc.status.is_frozen() {
                     Err(Error::<T>::CannotMutate)?
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
@@ -1297,11 +1937,542 @@ pub mod pallet {
                     <LocMap<T>>::mutate(loc_id, |loc| {
                         let mutable_loc = loc.as_mut().unwrap();
                         mutable_loc.files[item_index].acknowledged = true;
+                        mutable_loc.files[item_index].acknowledged_on = Some(Self::current_timepoint());
+                    });
+
+                    let submitter = loc.files[item_index].submitter.clone();
+                    Self::deposit_event(Event::FileAcknowledged(loc_id, hash, submitter));
+                    Ok(Some(T::WeightInfo::acknowledge_file(loc.files.len() as u32)).into())
+                }
+            }
+        }
+
+        /// Delete LOC metadata. The owner may delete any item, a submitter only its own unacknowledged items.
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::delete_metadata(T::MaxLocMetadata::get()))]
+        pub fn delete_metadata(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            name: <T as pallet::Config>::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                let option_item_index = loc.metadata.iter().position(|item| item.name == name);
+                if option_item_index.is_none() {
+                    Err(Error::<T>::ItemNotFound)?
+                }
+                let item_index = option_item_index.unwrap();
+                let item = &loc.metadata[item_index];
+                let deleted_by_owner = loc.owner == who;
+                if !deleted_by_owner && item.submitter != SupportedAccountId::Polkadot(who) {
+                    Err(Error::<T>::Unauthorized)?
+                } else if loc.status.is_frozen() {
+                    Err(Error::<T>::CannotMutate)?
+                } else if loc.void_info.is_some() {
+                    Err(Error::<T>::CannotMutateVoid)?
+                } else if !deleted_by_owner && item.acknowledged {
+                    Err(Error::<T>::ItemAlreadyAcknowledged)?
+                } else {
+                    <LocMap<T>>::mutate(loc_id, |loc| {
+                        let mutable_loc = loc.as_mut().unwrap();
+                        mutable_loc.metadata.remove(item_index);
+                    });
+
+                    Self::deposit_event(Event::MetadataItemDeleted(loc_id, name));
+                    Ok(Some(T::WeightInfo::delete_metadata(loc.metadata.len() as u32)).into())
+                }
+            }
+        }
+
+        /// Delete a file from LOC. The owner may delete any file, a submitter only its own unacknowledged files.
+        ///
+        /// The storage fee paid when the file was added is not refunded.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::delete_file(T::MaxLocFiles::get()))]
+        pub fn delete_file(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            hash: <T as pallet::Config>::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                let option_item_index = loc.files.iter().position(|item| item.hash == hash);
+                if option_item_index.is_none() {
+                    Err(Error::<T>::ItemNotFound)?
+                }
+                let item_index = option_item_index.unwrap();
+                let item = &loc.files[item_index];
+                let deleted_by_owner = loc.owner == who;
+                if !deleted_by_owner && item.submitter != SupportedAccountId::Polkadot(who) {
+                    Err(Error::<T>::Unauthorized)?
+                } else if loc.status.is_frozen() {
+                    Err(Error::<T>::CannotMutate)?
+                } else if loc.void_info.is_some() {
+                    Err(Error::<T>::CannotMutateVoid)?
+                } else if !deleted_by_owner && item.acknowledged {
+                    Err(Error::<T>::ItemAlreadyAcknowledged)?
+                } else {
+                    <LocMap<T>>::mutate(loc_id, |loc| {
+                        let mutable_loc = loc.as_mut().unwrap();
+                        mutable_loc.files.remove(item_index);
                     });
+                    <FileHashIndexMap<T>>::remove((hash, loc_id, FileLocation::Loc));
+
+                    Self::deposit_event(Event::FileDeleted(loc_id, hash));
+                    Ok(Some(T::WeightInfo::delete_file(loc.files.len() as u32)).into())
+                }
+            }
+        }
+
+        /// Delete a link from LOC. Only the owner may delete a link.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::delete_link(T::MaxLocLinks::get()))]
+        pub fn delete_link(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            #[pallet::compact] linked_loc_id: T::LocId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                if loc.owner != who {
+                    Err(Error::<T>::Unauthorized)?
+                } else if loc.status.is_frozen() {
+                    Err(Error::<T>::CannotMutate)?
+                } else if loc.void_info.is_some() {
+                    Err(Error::<T>::CannotMutateVoid)?
+                }
+                let option_item_index = loc.links.iter().position(|item| item.id == linked_loc_id);
+                if option_item_index.is_none() {
+                    Err(Error::<T>::ItemNotFound)?
+                } else {
+                    let item_index = option_item_index.unwrap();
+                    <LocMap<T>>::mutate(loc_id, |loc| {
+                        let mutable_loc = loc.as_mut().unwrap();
+                        mutable_loc.links.remove(item_index);
+                    });
+
+                    Self::deposit_event(Event::LinkDeleted(loc_id, linked_loc_id));
+                    Ok(Some(T::WeightInfo::delete_link(loc.links.len() as u32)).into())
+                }
+            }
+        }
+
+        /// Add LOC metadata on behalf of the Ethereum requester of the LOC.
+        /// The submitter authorizes the item by signing it along with its next nonce, see `ethereum_signable_hash`.
+        /// Any account may dispatch the call.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::add_metadata_with_ethereum_signature(T::MaxLocMetadata::get()))]
+        pub fn add_metadata_with_ethereum_signature(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            item: MetadataItemParams<T::AccountId, OtherAccountIdOf<T>, <T as pallet::Config>::Hash>,
+            nonce: u64,
+            signature: EthereumSignature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                Self::check_ethereum_signature(&item.submitter, b"add_metadata", &(loc_id, &item), nonce, &signature)?;
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                if !Self::can_submit(&loc_id, &loc, &item.submitter) {
+                    Err(Error::<T>::Unauthorized)?
+                } else {
+                    Self::do_add_metadata(loc_id, &loc, item, false)?;
+                    Ok(Some(T::WeightInfo::add_metadata_with_ethereum_signature(loc.metadata.len() as u32)).into())
+                }
+            }
+        }
+
+        /// Add a file to a LOC on behalf of the Ethereum requester of the LOC.
+        /// The submitter authorizes the file by signing it along with its next nonce, see `ethereum_signable_hash`.
+        /// Any account may dispatch the call. Storage fees are paid by the sponsor of the LOC, which must be sponsored.
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::add_file_with_ethereum_signature(T::MaxLocFiles::get()))]
+        pub fn add_file_with_ethereum_signature(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            file: FileParams<<T as pallet::Config>::Hash, T::AccountId, OtherAccountIdOf<T>>,
+            nonce: u64,
+            signature: EthereumSignature,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                Self::check_ethereum_signature(&file.submitter, b"add_file", &(loc_id, &file), nonce, &signature)?;
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                if !Self::can_submit(&loc_id, &loc, &file.submitter) {
+                    Err(Error::<T>::Unauthorized)?
+                } else {
+                    Self::do_add_file(who, loc_id, &loc, file, false)?;
+                    Ok(Some(T::WeightInfo::add_file_with_ethereum_signature(loc.files.len() as u32)).into())
+                }
+            }
+        }
+
+        /// Creates a new Transaction LOC whose requester is an account of another blockchain.
+        /// If a sponsorship is provided, the sponsor pays the legal fee and storage fees, otherwise the owner pays storage fees.
+        /// The legal fee is placed on hold until the LOC is closed.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::create_other_transaction_loc())]
+        pub fn create_other_transaction_loc(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            requester_account_id: OtherAccountIdOf<T>,
+            sponsorship_id: Option<T::SponsorshipId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
+
+            if <LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::AlreadyExists)?
+            } else if !Self::can_link_to_optional_sponsorship(&sponsorship_id, &who, &SupportedAccountId::Other(requester_account_id.clone())) {
+                Err(Error::<T>::CannotLinkToSponsorship)?
+            } else {
+                let requester = RequesterOf::<T>::OtherAccount(requester_account_id.clone());
+                let loc = Self::build_open_loc(&who, &requester, LocType::Transaction, sponsorship_id);
+
+                Self::hold_legal_fee(&loc_id, &loc, None)?;
+                <LocMap<T>>::insert(loc_id, loc);
+                Self::link_with_owner(&who, &loc_id);
+                Self::link_with_other_account(&requester_account_id, LocType::Transaction, &loc_id);
+                if sponsorship_id.is_some() {
+                    Self::link_sponsorship_to_loc(&sponsorship_id.unwrap());
+                }
+
+                Self::deposit_event(Event::LocCreated(loc_id));
+                Ok(().into())
+            }
+        }
+
+        /// Creates a new Collection LOC whose requester is an account of another blockchain.
+        /// If a sponsorship is provided, the sponsor pays the legal fee and storage fees, otherwise the owner pays storage fees.
+        /// The legal fee is placed on hold until the LOC is closed.
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::create_other_collection_loc())]
+        pub fn create_other_collection_loc(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            requester_account_id: OtherAccountIdOf<T>,
+            collection_last_block_submission: Option<T::BlockNumber>,
+            collection_max_size: Option<u32>,
+            collection_can_upload: bool,
+            sponsorship_id: Option<T::SponsorshipId>,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
+
+            if collection_last_block_submission.is_none() && collection_max_size.is_none() {
+                Err(Error::<T>::CollectionHasNoLimit)?
+            }
+
+            if <LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::AlreadyExists)?
+            } else if !Self::can_link_to_optional_sponsorship(&sponsorship_id, &who, &SupportedAccountId::Other(requester_account_id.clone())) {
+                Err(Error::<T>::CannotLinkToSponsorship)?
+            } else {
+                let requester = RequesterOf::<T>::OtherAccount(requester_account_id.clone());
+                let loc = Self::build_open_collection_loc(
+                    &who,
+                    &requester,
+                    collection_last_block_submission,
+                    collection_max_size,
+                    collection_can_upload,
+                    sponsorship_id,
+                );
+
+                Self::hold_legal_fee(&loc_id, &loc, None)?;
+                Self::schedule_collection_root_snapshot(&loc_id, &collection_last_block_submission)?;
+                <LocMap<T>>::insert(loc_id, loc);
+                Self::link_with_owner(&who, &loc_id);
+                Self::link_with_other_account(&requester_account_id, LocType::Collection, &loc_id);
+                if sponsorship_id.is_some() {
+                    Self::link_sponsorship_to_loc(&sponsorship_id.unwrap());
+                }
+
+                Self::deposit_event(Event::LocCreated(loc_id));
+                Ok(().into())
+            }
+        }
+
+        /// Adds an item to a collection on behalf of its Ethereum requester.
+        /// The submitter authorizes the item by signing it along with its next nonce, see `ethereum_signable_hash`.
+        /// Any account may dispatch the call. Storage fees are paid by the sponsor of the collection, which must be sponsored.
+        /// The certificate fee of the token, if any, is paid by the dispatcher.
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::add_collection_item_with_ethereum_signature(item_files.len() as u32, terms_and_conditions.len() as u32))]
+        pub fn add_collection_item_with_ethereum_signature(
+            origin: OriginFor<T>,
+            #[pallet::compact] collection_loc_id: T::LocId,
+            item_id: T::CollectionItemId,
+            item_description: <T as Config>::Hash,
+            item_files: Vec<CollectionItemFileOf<T>>,
+            item_token: Option<CollectionItemToken<T::TokenIssuance, <T as Config>::Hash>>,
+            restricted_delivery: bool,
+            terms_and_conditions: Vec<TermsAndConditionsElement<T::LocId, <T as Config>::Hash>>,
+            submitter: SupportedAccountIdOf<T>,
+            nonce: u64,
+            signature: EthereumSignature,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let item = (collection_loc_id, item_id, item_description, &item_files, &item_token, restricted_delivery, &terms_and_conditions);
+            Self::check_ethereum_signature(&submitter, b"add_collection_item", &item, nonce, &signature)?;
+            Self::do_add_collection_item(who, submitter, collection_loc_id, item_id, item_description, item_files, item_token, restricted_delivery, terms_and_conditions)
+        }
+
+        /// Adds a tokens record to a collection on behalf of its Ethereum requester.
+        /// The submitter authorizes the record by signing it along with its next nonce, see `ethereum_signable_hash`.
+        /// Any account may dispatch the call. Storage fees are paid by the sponsor of the collection, which must be sponsored.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::add_tokens_record_with_ethereum_signature(files.len() as u32))]
+        pub fn add_tokens_record_with_ethereum_signature(
+            origin: OriginFor<T>,
+            #[pallet::compact] collection_loc_id: T::LocId,
+            record_id: T::TokensRecordId,
+            description: <T as Config>::Hash,
+            files: Vec<TokensRecordFileOf<T>>,
+            submitter: SupportedAccountIdOf<T>,
+            nonce: u64,
+            signature: EthereumSignature,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            Self::check_ethereum_signature(&submitter, b"add_tokens_record", &(collection_loc_id, record_id, description, &files), nonce, &signature)?;
+            Self::do_add_tokens_record(who, submitter, collection_loc_id, record_id, description, files)
+        }
+
+        /// Submits a draft or rejected LOC for review by its owner.
+        /// The legal fee is placed on hold on the sponsor's or the requester's account. It is withdrawn when the LOC is closed
+        /// and returned if the LOC is rejected, cancelled or voided before. If the LOC is not accepted before the hold
+        /// times out, the fee is returned and the LOC goes back to draft.
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::request_review())]
+        pub fn request_review(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                if !Self::is_requester(&SupportedAccountId::Polkadot(who), &loc) {
+                    Err(Error::<T>::Unauthorized)?
+                } else if loc.status != LocStatus::Draft && loc.status != LocStatus::Rejected {
+                    Err(Error::<T>::InvalidLocStatus)?
+                } else {
+                    let expires_on = <frame_system::Pallet<T>>::block_number().saturating_add(T::LegalFeeHoldTimeout::get());
+                    Self::hold_legal_fee(&loc_id, &loc, Some(expires_on))?;
+                    <LocMap<T>>::mutate(loc_id, |loc| {
+                        let mutable_loc = loc.as_mut().unwrap();
+                        mutable_loc.status = LocStatus::ReviewRequested;
+                    });
+
+                    Self::deposit_event(Event::LocReviewRequested(loc_id));
                     Ok(().into())
                 }
             }
         }
+
+        /// Accepts a LOC under review, which becomes open. The legal fee stays on hold until the LOC is closed.
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::accept_loc())]
+        pub fn accept_loc(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                if loc.owner != who {
+                    Err(Error::<T>::Unauthorized)?
+                } else if loc.status != LocStatus::ReviewRequested {
+                    Err(Error::<T>::InvalidLocStatus)?
+                } else {
+                    if loc.loc_type == LocType::Collection {
+                        Self::schedule_collection_root_snapshot(&loc_id, &loc.collection_last_block_submission)?;
+                    }
+                    Self::keep_legal_fee_on_hold(&loc_id);
+                    Self::release_draft_deposit(&loc_id);
+                    <LocMap<T>>::mutate(loc_id, |loc| {
+                        let mutable_loc = loc.as_mut().unwrap();
+                        mutable_loc.status = LocStatus::Open;
+                        mutable_loc.rejection_reason = None;
+                    });
+
+                    Self::deposit_event(Event::LocAccepted(loc_id));
+                    Ok(().into())
+                }
+            }
+        }
+
+        /// Rejects a LOC under review. The legal fee on hold is returned and the requester may submit the LOC again.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::reject_loc())]
+        pub fn reject_loc(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            reason: <T as Config>::Hash,
+        ) -> DispatchResultWithPostInfo {
+            let who = T::IsLegalOfficer::ensure_origin(origin.clone())?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                if loc.owner != who {
+                    Err(Error::<T>::Unauthorized)?
+                } else if loc.status != LocStatus::ReviewRequested {
+                    Err(Error::<T>::InvalidLocStatus)?
+                } else {
+                    Self::release_legal_fee(&loc_id, &loc);
+                    <LocMap<T>>::mutate(loc_id, |loc| {
+                        let mutable_loc = loc.as_mut().unwrap();
+                        mutable_loc.status = LocStatus::Rejected;
+                        mutable_loc.rejection_reason = Some(reason);
+                    });
+
+                    Self::deposit_event(Event::LocRejected(loc_id, reason));
+                    Ok(().into())
+                }
+            }
+        }
+
+        /// Cancels a Transaction or Collection LOC which was not acknowledged or modified by its owner yet.
+        /// The legal fee on hold is returned and the LOC is removed from the requester's and owner's LOCs.
+        /// The owner cancels on behalf of a requester identified by a LOC, see `cancel_loc_with_ethereum_signature`
+        /// for Ethereum requesters.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::cancel_loc())]
+        pub fn cancel_loc(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                let authorized = match loc.requester {
+                    Requester::Loc(_) => who == loc.owner,
+                    _ => Self::is_requester(&SupportedAccountId::Polkadot(who.clone()), &loc),
+                };
+                if !authorized {
+                    Err(Error::<T>::Unauthorized)?
+                } else {
+                    Self::do_cancel_loc(loc_id, &loc)
+                }
+            }
+        }
+        /// Sets the exchange rate of given fiat currency for the next `valid_for` blocks. The feeder may only
+        /// change the rate by `MaxExchangeRateChange` at most, and not before `MinExchangeRateFeedInterval`
+        /// blocks since the last update. `ExchangeRateOrigin` is not bounded.
+        #[pallet::call_index(36)]
+        #[pallet::weight(T::WeightInfo::set_exchange_rate())]
+        pub fn set_exchange_rate(
+            origin: OriginFor<T>,
+            currency: FiatCurrency,
+            rate: BalanceOf<T>,
+            valid_for: T::BlockNumber,
+        ) -> DispatchResultWithPostInfo {
+            let bounded = match T::ExchangeRateOrigin::try_origin(origin) {
+                Ok(_) => false,
+                Err(origin) => {
+                    let who = ensure_signed(origin)?;
+                    if Self::exchange_rate_feeder() != Some(who) {
+                        Err(Error::<T>::Unauthorized)?
+                    }
+                    true
+                },
+            };
+
+            if rate.is_zero() || valid_for.is_zero() {
+                Err(Error::<T>::InvalidExchangeRate)?
+            } else if bounded && !Self::is_acceptable_exchange_rate_change(currency, rate) {
+                Err(Error::<T>::ExchangeRateChangeTooLarge)?
+            } else if bounded && !Self::is_exchange_rate_feed_interval_elapsed(currency) {
+                Err(Error::<T>::ExchangeRateUpdateTooEarly)?
+            } else {
+                let valid_from = <frame_system::Pallet<T>>::block_number();
+                let valid_until = valid_from.saturating_add(valid_for).saturating_sub(One::one());
+                let record = ExchangeRateRecord {
+                    rate,
+                    valid_from,
+                    valid_until,
+                };
+                <ExchangeRatesMap<T>>::insert(currency, &record);
+                let index = match <ExchangeRateUpdatesInBlockMap<T>>::get(currency) {
+                    Some((block, count)) if block == valid_from => count,
+                    _ => 0,
+                };
+                <ExchangeRateUpdatesInBlockMap<T>>::insert(currency, (valid_from, index.saturating_add(1)));
+                <ExchangeRateHistoryMap<T>>::insert((currency, valid_from, index), record);
+
+                Self::deposit_event(Event::ExchangeRateUpdated(currency, rate, valid_from, valid_until));
+                Ok(().into())
+            }
+        }
+
+        /// Sets (or removes with `None`) the account allowed to update the exchange rate within bounds.
+        #[pallet::call_index(37)]
+        #[pallet::weight(T::WeightInfo::set_exchange_rate_feeder())]
+        pub fn set_exchange_rate_feeder(
+            origin: OriginFor<T>,
+            feeder: Option<T::AccountId>,
+        ) -> DispatchResultWithPostInfo {
+            T::ExchangeRateOrigin::ensure_origin(origin)?;
+
+            <ExchangeRateFeeder<T>>::set(feeder.clone());
+
+            Self::deposit_event(Event::ExchangeRateFeederSet(feeder));
+            Ok(().into())
+        }
+
+        /// Cancels a LOC on behalf of its Ethereum requester, see `cancel_loc`.
+        /// The requester authorizes the cancellation by signing the LOC ID along with its next nonce, see `ethereum_signable_hash`.
+        /// Any account may dispatch the call.
+        #[pallet::call_index(38)]
+        #[pallet::weight(T::WeightInfo::cancel_loc_with_ethereum_signature())]
+        pub fn cancel_loc_with_ethereum_signature(
+            origin: OriginFor<T>,
+            #[pallet::compact] loc_id: T::LocId,
+            requester: SupportedAccountIdOf<T>,
+            nonce: u64,
+            signature: EthereumSignature,
+        ) -> DispatchResultWithPostInfo {
+            ensure_signed(origin)?;
+
+            if !<LocMap<T>>::contains_key(&loc_id) {
+                Err(Error::<T>::NotFound)?
+            } else {
+                Self::check_ethereum_signature(&requester, b"cancel_loc", &loc_id, nonce, &signature)?;
+                let loc = <LocMap<T>>::get(&loc_id).unwrap();
+                if !Self::is_requester(&requester, &loc) {
+                    Err(Error::<T>::Unauthorized)?
+                } else {
+                    Self::do_cancel_loc(loc_id, &loc)
+                }
+            }
+        }
     }
 
     impl<T: Config> LocQuery<T::LocId, <T as frame_system::Config>::AccountId> for Pallet<T> {
@@ -1377,12 +2548,17 @@ pub mod pallet {
             }
 
             let loc_void_info = LocVoidInfo {
-                replacer:replacer_loc_id
+                replacer: replacer_loc_id,
+                voided_on: Some(Self::current_timepoint()),
             };
-            <LocMap<T>>::mutate(loc_id, |loc| {
+            let voided_loc = <LocMap<T>>::mutate(loc_id, |loc| {
                 let mutable_loc = loc.as_mut().unwrap();
                 mutable_loc.void_info = Some(loc_void_info);
+                mutable_loc.status = LocStatus::Void;
+                mutable_loc.clone()
             });
+            Self::release_legal_fee(&loc_id, &voided_loc);
+            Self::release_draft_deposit(&loc_id);
             if replacer_loc_id.is_some() {
                 <LocMap<T>>::mutate(replacer_loc_id.unwrap(), |replacer_loc| {
                     let mutable_replacer_loc = replacer_loc.as_mut().unwrap();
@@ -1397,17 +2573,9 @@ pub mod pallet {
             account: &<T as frame_system::Config>::AccountId,
             legal_officer: &<T as frame_system::Config>::AccountId
         ) -> bool {
-            let value = <AccountLocsMap<T>>::get(account);
-            match value {
-                Some(loc_ids) => {
-                    return loc_ids.iter().map(|id| <LocMap<T>>::get(id))
-                        .filter(|option| option.is_some())
-                        .map(|some| some.unwrap())
-                        .find(|loc| loc.owner == *legal_officer && loc.loc_type == LocType::Identity && loc.closed)
-                        .is_some();
-                }
-                None => false
-            }
+            <AccountLocsMap<T>>::iter_key_prefix((account.clone(), LocType::Identity))
+                .filter_map(|loc_id| <LocMap<T>>::get(loc_id))
+                .any(|loc| loc.owner == *legal_officer && loc.closed)
         }
 
         fn loc_valid_with_owner(
@@ -1417,58 +2585,47 @@ pub mod pallet {
             let loc = <LocMap<T>>::get(loc_id);
             match loc {
                 Some(loc) => {
-                    return loc.closed && loc.void_info.is_none() && loc.owner == *legal_officer;
+                    return loc.status == LocStatus::Closed && loc.void_info.is_none() && loc.owner == *legal_officer;
                 }
                 None => false
             }
         }
 
+        fn link_with_owner(
+            owner: &<T as frame_system::Config>::AccountId,
+            loc_id: &<T as Config>::LocId,
+        ) {
+            <LocsByOwnerMap<T>>::insert(owner, loc_id, ());
+        }
+
         fn link_with_account(
             account_id: &<T as frame_system::Config>::AccountId,
+            loc_type: LocType,
             loc_id: &<T as Config>::LocId,
         ) {
-            if <AccountLocsMap<T>>::contains_key(account_id) {
-                <AccountLocsMap<T>>::mutate(account_id, |locs| {
-                    let list = locs.as_mut().unwrap();
-                    list.push(loc_id.clone());
-                });
-            } else {
-                <AccountLocsMap<T>>::insert(account_id, Vec::from([loc_id.clone()]));
-            }
+            <AccountLocsMap<T>>::insert((account_id, loc_type, loc_id), ());
         }
 
         fn link_with_identity_loc(
             requester_loc_id: &<T as Config>::LocId,
+            loc_type: LocType,
             loc_id: &<T as Config>::LocId,
         ) {
-            if <IdentityLocLocsMap<T>>::contains_key(requester_loc_id) {
-                <IdentityLocLocsMap<T>>::mutate(requester_loc_id, |locs| {
-                    let list = locs.as_mut().unwrap();
-                    list.push(loc_id.clone());
-                });
-            } else {
-                <IdentityLocLocsMap<T>>::insert(requester_loc_id, Vec::from([loc_id.clone()]));
-            }
+            <IdentityLocLocsMap<T>>::insert((requester_loc_id, loc_type, loc_id), ());
         }
 
         fn link_with_other_account(
-            account_id: &OtherAccountId<T::EthereumAddress>,
+            account_id: &OtherAccountIdOf<T>,
+            loc_type: LocType,
             loc_id: &<T as Config>::LocId,
         ) {
-            if <OtherAccountLocsMap<T>>::contains_key(account_id) {
-                <OtherAccountLocsMap<T>>::mutate(account_id, |locs| {
-                    let list = locs.as_mut().unwrap();
-                    list.push(loc_id.clone());
-                });
-            } else {
-                <OtherAccountLocsMap<T>>::insert(account_id, Vec::from([loc_id.clone()]));
-            }
+            <OtherAccountLocsMap<T>>::insert((account_id, loc_type, loc_id), ());
         }
 
         fn is_valid_logion_id(loc: &LegalOfficerCaseOf<T>) -> bool {
             loc.loc_type != LocType::Identity
                 || match loc.requester { RequesterOf::<T>::None => false, _ => true }
-                || !loc.closed
+                || loc.status != LocStatus::Closed
                 || loc.void_info.is_some()
         }
 
@@ -1481,11 +2638,12 @@ pub mod pallet {
             LegalOfficerCaseOf::<T> {
                 owner: legal_officer.clone(),
                 requester: requester.clone(),
-                metadata: Vec::new(),
-                files: Vec::new(),
+                metadata: BoundedVec::default(),
+                files: BoundedVec::default(),
+                status: LocStatus::Open,
                 closed: false,
                 loc_type: loc_type.clone(),
-                links: Vec::new(),
+                links: BoundedVec::default(),
                 void_info: None,
                 replacer_of: None,
                 collection_last_block_submission: None,
@@ -1493,6 +2651,8 @@ pub mod pallet {
                 collection_can_upload: false,
                 seal: None,
                 sponsorship_id: sponsorship_id.clone(),
+                closed_on: None,
+                rejection_reason: None,
             }
         }
 
@@ -1502,29 +2662,79 @@ pub mod pallet {
             collection_last_block_submission: Option<T::BlockNumber>,
             collection_max_size: Option<CollectionSize>,
             collection_can_upload: bool,
+            sponsorship_id: Option<T::SponsorshipId>,
         ) -> LegalOfficerCaseOf<T> {
             LegalOfficerCaseOf::<T> {
                 owner: who.clone(),
                 requester: requester.clone(),
-                metadata: Vec::new(),
-                files: Vec::new(),
+                metadata: BoundedVec::default(),
+                files: BoundedVec::default(),
+                status: LocStatus::Open,
                 closed: false,
                 loc_type: LocType::Collection,
-                links: Vec::new(),
+                links: BoundedVec::default(),
                 void_info: None,
                 replacer_of: None,
                 collection_last_block_submission: collection_last_block_submission.clone(),
                 collection_max_size: collection_max_size.clone(),
                 collection_can_upload,
                 seal: None,
-                sponsorship_id: None,
+                sponsorship_id,
+                closed_on: None,
+                rejection_reason: None,
+            }
+        }
+
+        fn do_cancel_loc(loc_id: T::LocId, loc: &LegalOfficerCaseOf<T>) -> DispatchResultWithPostInfo {
+            if loc.void_info.is_some() {
+                Err(Error::<T>::CannotMutateVoid)?
+            } else if loc.status.is_frozen() {
+                Err(Error::<T>::InvalidLocStatus)?
+            } else if loc.loc_type == LocType::Identity || !Self::is_untouched_by_owner(&loc_id, loc) {
+                Err(Error::<T>::CannotCancel)?
+            } else {
+                Self::release_legal_fee(&loc_id, loc);
+                Self::release_draft_deposit(&loc_id);
+                <LocMap<T>>::mutate(loc_id, |loc| {
+                    let mutable_loc = loc.as_mut().unwrap();
+                    mutable_loc.status = LocStatus::Cancelled;
+                });
+                match &loc.requester {
+                    Requester::Account(account) => <AccountLocsMap<T>>::remove((account, loc.loc_type, &loc_id)),
+                    Requester::Loc(requester_loc_id) => <IdentityLocLocsMap<T>>::remove((requester_loc_id, loc.loc_type, &loc_id)),
+                    Requester::OtherAccount(account) => <OtherAccountLocsMap<T>>::remove((account, loc.loc_type, &loc_id)),
+                    Requester::None => (),
+                }
+                <LocsByOwnerMap<T>>::remove(&loc.owner, &loc_id);
+                if let Some(sponsorship_id) = loc.sponsorship_id {
+                    let now = <frame_system::Pallet<T>>::block_number();
+                    <SponsorshipMap<T>>::mutate(sponsorship_id, |maybe_sponsorship| {
+                        match maybe_sponsorship.as_mut() {
+                            Some(sponsorship) if sponsorship.expires_on > now => {
+                                sponsorship.used_locs = sponsorship.used_locs.saturating_sub(1);
+                            },
+                            _ => (),
+                        }
+                    });
+                }
+
+                Self::deposit_event(Event::LocCancelled(loc_id));
+                Ok(().into())
+            }
+        }
+
+        fn is_requester(account: &SupportedAccountIdOf<T>, loc: &LegalOfficerCaseOf<T>) -> bool {
+            match (account, &loc.requester) {
+                (SupportedAccountId::Polkadot(polkadot_account), Requester::Account(requester)) => polkadot_account == requester,
+                (SupportedAccountId::Other(other_account), Requester::OtherAccount(requester)) => other_account == requester,
+                _ => false,
             }
         }
 
-        fn can_add_item(who: &T::AccountId, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
+        fn can_add_item(submitter: &SupportedAccountIdOf<T>, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
             collection_loc.loc_type == LocType::Collection
-                && match &collection_loc.requester { Requester::Account(requester) => requester == who, _ => false }
-                && collection_loc.closed
+                && Self::is_requester(submitter, collection_loc)
+                && collection_loc.status == LocStatus::Closed
                 && collection_loc.void_info.is_none()
         }
 
@@ -1535,6 +2745,38 @@ pub mod pallet {
                 || match collection_loc.collection_last_block_submission { None => false, Some(last_block) => current_block_number >= last_block };
         }
 
+        fn schedule_collection_root_snapshot(loc_id: &T::LocId, collection_last_block_submission: &Option<T::BlockNumber>) -> DispatchResult {
+            match collection_last_block_submission {
+                Some(last_block) if *last_block > <frame_system::Pallet<T>>::block_number() => {
+                    <CollectionsByLastBlockMap<T>>::try_mutate(last_block, |loc_ids| loc_ids.try_push(*loc_id))
+                        .map_err(|_| Error::<T>::CollectionLastBlockLimitReached)?;
+                    Ok(())
+                },
+                _ => Ok(()),
+            }
+        }
+
+        pub(crate) fn accumulate_collection_item(collection_loc_id: &T::LocId, item_id: &T::CollectionItemId, leaf: <T as Config>::Hash) {
+            <CollectionAccumulatorsMap<T>>::mutate(collection_loc_id, |accumulator| {
+                let leaf_index = accumulator.leaf_count;
+                <CollectionItemLeafIndexMap<T>>::insert(collection_loc_id, item_id, leaf_index);
+                for (level, node) in accumulator.append::<T::Hasher>(leaf) {
+                    <CollectionAccumulatorNodesMap<T>>::insert(collection_loc_id, (level, leaf_index.checked_shr(level).unwrap_or(0)), node);
+                }
+            });
+        }
+
+        fn snapshot_collection_root(collection_loc_id: &T::LocId) {
+            let accumulator = <CollectionAccumulatorsMap<T>>::get(collection_loc_id);
+            let root = accumulator.root::<T::Hasher>();
+            <CollectionRootSnapshotsMap<T>>::insert(collection_loc_id, CollectionRootSnapshot {
+                root,
+                item_count: accumulator.leaf_count,
+                taken_on: <frame_system::Pallet<T>>::block_number(),
+            });
+            Self::deposit_event(Event::CollectionRootSnapshotTaken(*collection_loc_id, root));
+        }
+
         fn has_unique_elements<I>(iter: I) -> bool
             where
                 I: IntoIterator,
@@ -1547,7 +2789,7 @@ pub mod pallet {
         fn do_close(
             origin: OriginFor<T>,
             loc_id: T::LocId,
-            seal: Option<<T as Config>::Hash>,
+            seal: Option<SealOf<T>>,
         ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
@@ -1559,19 +2801,31 @@ pub mod pallet {
                     Err(Error::<T>::Unauthorized)?
                 } else if loc.void_info.is_some() {
                     Err(Error::<T>::CannotMutateVoid)?
-                } else if loc.closed {
+                } else if loc.status == LocStatus::Closed {
                     Err(Error::<T>::AlreadyClosed)?
+                } else if loc.status != LocStatus::Open {
+                    Err(Error::<T>::InvalidLocStatus)?
                 } else if Self::has_unacknowledged_items(&loc) {
                     Err(Error::<T>::CannotCloseUnacknowledged)?
                 } else {
+                    Self::withdraw_legal_fee_on_hold(&loc_id, &loc);
+                    let sealed = seal.is_some();
                     <LocMap<T>>::mutate(loc_id, |loc| {
                         let mutable_loc = loc.as_mut().unwrap();
+                        mutable_loc.status = LocStatus::Closed;
                         mutable_loc.closed = true;
                         mutable_loc.seal = seal;
+                        mutable_loc.closed_on = Some(Self::current_timepoint());
                     });
+                    <ContentRootsMap<T>>::insert(loc_id, merkle::merkle_root::<_, T::Hasher>(&Self::content_leaves(&loc)));
 
                     Self::deposit_event(Event::LocClosed(loc_id));
-                    Ok(().into())
+                    let weight = if sealed {
+                        T::WeightInfo::close_and_seal(loc.metadata.len() as u32, loc.files.len() as u32, loc.links.len() as u32)
+                    } else {
+                        T::WeightInfo::close(loc.metadata.len() as u32, loc.files.len() as u32, loc.links.len() as u32)
+                    };
+                    Ok(Some(weight).into())
                 }
             }
         }
@@ -1588,7 +2842,8 @@ pub mod pallet {
         }
 
         fn do_add_collection_item(
-            origin: OriginFor<T>,
+            dispatcher: T::AccountId,
+            submitter: SupportedAccountIdOf<T>,
             collection_loc_id: T::LocId,
             item_id: T::CollectionItemId,
             item_description: <T as Config>::Hash,
@@ -1597,8 +2852,6 @@ pub mod pallet {
             restricted_delivery: bool,
             terms_and_conditions: Vec<TermsAndConditionsElement<T::LocId, <T as Config>::Hash>>,
         ) -> DispatchResultWithPostInfo {
-            let who = ensure_signed(origin)?;
-
             if item_token.is_some() && item_token.as_ref().unwrap().token_issuance < 1_u32.into() {
                 Err(Error::<T>::BadTokenIssuance)?
             }
@@ -1618,12 +2871,17 @@ pub mod pallet {
                     if <CollectionItemsMap<T>>::contains_key(&collection_loc_id, &item_id) {
                         Err(Error::<T>::CollectionItemAlreadyExists)?
                     }
-                    if ! Self::can_add_item(&who, &collection_loc) {
+                    if ! Self::can_add_item(&submitter, &collection_loc) {
                         Err(Error::<T>::WrongCollectionLoc)?
                     }
                     if Self::collection_limits_reached(&collection_loc_id, &collection_loc) {
                         Err(Error::<T>::CollectionLimitsReached)?
                     }
+                    if let Some(token) = &item_token {
+                        if <CollectionItemsByTokenMap<T>>::contains_key(&token.token_type, &token.token_id) {
+                            Err(Error::<T>::DuplicateToken)?
+                        }
+                    }
                     if !collection_loc.collection_can_upload && item_files.len() > 0 {
                         Err(Error::<T>::CannotUpload)?
                     }
@@ -1643,34 +2901,48 @@ pub mod pallet {
                             let tc_loc = <LocMap<T>>::get(terms_and_conditions_element.tc_loc).unwrap();
                             if tc_loc.void_info.is_some() {
                                 Err(Error::<T>::TermsAndConditionsLocVoid)?
-                            } else if !tc_loc.closed {
+                            } else if tc_loc.status != LocStatus::Closed {
                                 Err(Error::<T>::TermsAndConditionsLocNotClosed)?
                             }
                         }
                     }
-                    let tot_size = item_files.iter()
+                    let bounded_files: BoundedVec<CollectionItemFileOf<T>, T::MaxCollectionItemFiles> = item_files.try_into()
+                        .map_err(|_| Error::<T>::CollectionItemTooMuchData)?;
+                    let bounded_terms_and_conditions: BoundedVec<TermsAndConditionsElementOf<T>, T::MaxCollectionItemTCs> = terms_and_conditions.try_into()
+                        .map_err(|_| Error::<T>::CollectionItemTooMuchData)?;
+                    let tot_size = bounded_files.iter()
                         .map(|file| file.size)
                         .fold(0, |tot, current| tot + current);
-                    Self::apply_file_storage_fee(&who, item_files.len(), tot_size)?;
+                    Self::apply_file_storage_fee(&collection_loc, &dispatcher, &submitter, bounded_files.len(), tot_size)?;
                     let item = CollectionItem {
                         description: item_description,
-                        files: item_files,
+                        files: bounded_files,
                         token: item_token.clone(),
                         restricted_delivery,
-                        terms_and_conditions,
+                        terms_and_conditions: bounded_terms_and_conditions,
+                        added_on: Some(Self::current_timepoint()),
                     };
+                    let leaf = merkle::hash_leaf::<_, T::Hasher, _>(&(&item_id, &item));
+                    for file in item.files.iter() {
+                        <FileHashIndexMap<T>>::insert((file.hash, collection_loc_id, FileLocation::CollectionItem(item_id)), ());
+                    }
                     <CollectionItemsMap<T>>::insert(collection_loc_id, item_id, item);
                     let collection_size = <CollectionSizeMap<T>>::get(&collection_loc_id).unwrap_or(0);
                     <CollectionSizeMap<T>>::insert(&collection_loc_id, collection_size + 1);
+                    Self::accumulate_collection_item(&collection_loc_id, &item_id, leaf);
+                    if let Some(token) = &item_token {
+                        <CollectionItemsByTokenMap<T>>::insert(token.token_type, token.token_id, (collection_loc_id, item_id));
+                    }
+                    if collection_loc.collection_max_size == Some(collection_size + 1) {
+                        Self::snapshot_collection_root(&collection_loc_id);
+                    }
 
                     match item_token {
                         Some(token) => {
                             let fee = Self::calculate_certificate_fee(token.token_issuance);
-                            ensure!(T::Currency::can_slash(&who, fee), Error::<T>::InsufficientFunds);
-
-                            let (credit, _) = T::Currency::slash(&who, fee);
+                            let credit = Self::withdraw_fee(&dispatcher, fee)?;
                             T::RewardDistributor::distribute(credit, T::CertificateFeeDistributionKey::get());
-                            Self::deposit_event(Event::CertificateFeeWithdrawn(who, fee));
+                            Self::deposit_event(Event::CertificateFeeWithdrawn(dispatcher, fee));
                         }
                         _ => {}
                     }
@@ -1681,22 +2953,196 @@ pub mod pallet {
             Ok(().into())
         }
 
+        fn do_add_tokens_record(
+            dispatcher: T::AccountId,
+            submitter: SupportedAccountIdOf<T>,
+            collection_loc_id: T::LocId,
+            record_id: T::TokensRecordId,
+            description: <T as Config>::Hash,
+            files: Vec<TokensRecordFileOf<T>>,
+        ) -> DispatchResultWithPostInfo {
+            let collection_loc_option = <LocMap<T>>::get(&collection_loc_id);
+            match collection_loc_option {
+                None => Err(Error::<T>::WrongCollectionLoc)?,
+                Some(collection_loc) => {
+                    if <TokensRecordsMap<T>>::contains_key(&collection_loc_id, &record_id) {
+                        Err(Error::<T>::TokensRecordAlreadyExists)?
+                    }
+                    if ! Self::can_add_record(&submitter, &collection_loc_id, &collection_loc) {
+                        Err(Error::<T>::CannotAddRecord)?
+                    }
+                    if files.len() == 0 {
+                        Err(Error::<T>::MustUpload)?
+                    } else {
+                        let files_hashes: Vec<<T as Config>::Hash> = files.iter()
+                            .map(|file| file.hash)
+                            .collect();
+                        if !Self::has_unique_elements(&files_hashes) {
+                            Err(Error::<T>::DuplicateFile)?
+                        }
+                    }
+
+                    let mut bounded_files: BoundedVec<TokensRecordFileOf<T>, T::MaxTokensRecordFiles> = BoundedVec::with_bounded_capacity(files.len());
+                    for file in files.iter() {
+                        bounded_files.try_push(file.clone()).map_err(|_| Error::<T>::TokensRecordTooMuchData)?;
+                    }
+                    let tot_size = files.iter()
+                        .map(|file| file.size)
+                        .fold(0, |tot, current| tot + current);
+                    Self::apply_file_storage_fee(&collection_loc, &dispatcher, &submitter, files.len(), tot_size)?;
+                    for file in bounded_files.iter() {
+                        <FileHashIndexMap<T>>::insert((file.hash, collection_loc_id, FileLocation::TokensRecord(record_id)), ());
+                    }
+                    let record = TokensRecord {
+                        description,
+                        files: bounded_files,
+                        submitter: submitter.clone(),
+                        added_on: Some(Self::current_timepoint()),
+                    };
+                    <TokensRecordsMap<T>>::insert(collection_loc_id, record_id, record);
+
+                    Self::deposit_event(Event::TokensRecordAdded(collection_loc_id, record_id, submitter));
+                },
+            }
+
+            Ok(().into())
+        }
+
+        fn current_timepoint() -> TimepointOf<T> {
+            let moment = T::Timestamp::now();
+            Timepoint {
+                block_number: <frame_system::Pallet<T>>::block_number(),
+                moment: if moment.is_zero() { None } else { Some(moment) },
+            }
+        }
+
         pub fn calculate_certificate_fee(token_issuance: T::TokenIssuance) -> BalanceOf<T> {
             T::CertificateFee::get().saturating_mul(token_issuance.into())
         }
 
-        fn can_add_record(adder: &T::AccountId, loc_id: &T::LocId, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
+        fn can_add_record(adder: &SupportedAccountIdOf<T>, loc_id: &T::LocId, collection_loc: &LegalOfficerCaseOf<T>) -> bool {
             collection_loc.loc_type == LocType::Collection
                 && (
-                    match &collection_loc.requester { Account(requester) => requester == adder, _ => false }
-                    || *adder == collection_loc.owner
-                    || Self::verified_issuers_by_loc(loc_id, adder).is_some()
+                    Self::is_requester(adder, collection_loc)
+                    || match adder {
+                        SupportedAccountId::Polkadot(polkadot_adder) => *polkadot_adder == collection_loc.owner
+                            || Self::verified_issuers_by_loc(loc_id, polkadot_adder).is_some(),
+                        _ => false,
+                    }
                 )
-                && collection_loc.closed
+                && collection_loc.status == LocStatus::Closed
                 && collection_loc.void_info.is_none()
         }
 
-        fn can_submit(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, submitter: &SupportedAccountId<T::AccountId, T::EthereumAddress>) -> bool {
+        fn do_add_metadata(
+            loc_id: T::LocId,
+            loc: &LegalOfficerCaseOf<T>,
+            item: MetadataItemParams<T::AccountId, OtherAccountIdOf<T>, <T as pallet::Config>::Hash>,
+            acknowledged: bool,
+        ) -> DispatchResult {
+            if loc.status.is_frozen() {
+                Err(Error::<T>::CannotMutate)?
+            } else if loc.void_info.is_some() {
+                Err(Error::<T>::CannotMutateVoid)?
+            } else if loc.metadata.iter().find(|metadata_item| metadata_item.name == item.name).is_some() {
+                Err(Error::<T>::DuplicateLocMetadata)?
+            }
+            <LocMap<T>>::try_mutate(loc_id, |loc| {
+                let mutable_loc = loc.as_mut().unwrap();
+                let now = Self::current_timepoint();
+                mutable_loc.metadata.try_push(MetadataItem {
+                    name: item.name,
+                    value: item.value,
+                    submitter: item.submitter.clone(),
+                    acknowledged,
+                    added_on: Some(now),
+                    acknowledged_on: if acknowledged { Some(now) } else { None },
+                })
+            }).map_err(|_| Error::<T>::LocMetadataTooMuchData)?;
+
+            Self::deposit_event(Event::MetadataItemAdded(loc_id, item.name, item.submitter));
+            Ok(())
+        }
+
+        fn do_add_file(
+            dispatcher: T::AccountId,
+            loc_id: T::LocId,
+            loc: &LegalOfficerCaseOf<T>,
+            file: FileParams<<T as pallet::Config>::Hash, T::AccountId, OtherAccountIdOf<T>>,
+            acknowledged: bool,
+        ) -> DispatchResult {
+            if loc.status.is_frozen() {
+                Err(Error::<T>::CannotMutate)?
+            } else if loc.void_info.is_some() {
+                Err(Error::<T>::CannotMutateVoid)?
+            } else if loc.files.iter().find(|item| item.hash == file.hash).is_some() {
+                Err(Error::<T>::DuplicateLocFile)?
+            } else if loc.files.len() >= T::MaxLocFiles::get() as usize {
+                Err(Error::<T>::LocFilesTooMuchData)?
+            }
+            Self::apply_file_storage_fee(loc, &dispatcher, &file.submitter, 1, file.size)?;
+            <LocMap<T>>::try_mutate(loc_id, |loc| {
+                let mutable_loc = loc.as_mut().unwrap();
+                let now = Self::current_timepoint();
+                mutable_loc.files.try_push(File {
+                    hash: file.hash,
+                    nature: file.nature,
+                    submitter: file.submitter.clone(),
+                    size: file.size,
+                    acknowledged,
+                    added_on: Some(now),
+                    acknowledged_on: if acknowledged { Some(now) } else { None },
+                })
+            }).map_err(|_| Error::<T>::LocFilesTooMuchData)?;
+            <FileHashIndexMap<T>>::insert((file.hash, loc_id, FileLocation::Loc), ());
+
+            Self::deposit_event(Event::FileAdded(loc_id, file.hash, file.submitter));
+            Ok(())
+        }
+
+        /// The hash an Ethereum account signs with `personal_sign` (EIP-191) in order to authorize `call` with `data`.
+        /// The signed message is the `0x`-prefixed hex encoding of the Blake2 hash of the SCALE-encoded
+        /// genesis hash, call name, data and nonce, which prevents replays across chains, calls and submissions.
+        pub fn ethereum_signable_hash(call: &[u8], data: &impl Encode, nonce: u64) -> [u8; 32] {
+            let genesis_hash = <frame_system::Pallet<T>>::block_hash(T::BlockNumber::zero());
+            let payload_hash = (genesis_hash, call, data, nonce).using_encoded(blake2_256);
+
+            const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+            let mut message = b"\x19Ethereum Signed Message:\n66".to_vec();
+            message.extend_from_slice(b"0x");
+            for byte in payload_hash.iter() {
+                message.push(HEX_DIGITS[(byte >> 4) as usize]);
+                message.push(HEX_DIGITS[(byte & 0x0f) as usize]);
+            }
+            keccak_256(&message)
+        }
+
+        pub fn recover_ethereum_address(signature: &EthereumSignature, hash: &[u8; 32]) -> Option<T::EthereumAddress> {
+            let public_key = secp256k1_ecdsa_recover(&signature.0, hash).ok()?;
+            let address = &keccak_256(&public_key)[12..];
+            T::EthereumAddress::decode(&mut &address[..]).ok()
+        }
+
+        fn check_ethereum_signature(
+            submitter: &SupportedAccountIdOf<T>,
+            call: &[u8],
+            data: &impl Encode,
+            nonce: u64,
+            signature: &EthereumSignature,
+        ) -> DispatchResult {
+            let ethereum_submitter = match submitter {
+                SupportedAccountId::Other(OtherAccountId::Ethereum(ethereum_submitter)) => ethereum_submitter,
+                _ => Err(Error::<T>::Unauthorized)?,
+            };
+            ensure!(nonce == Self::ethereum_nonce(ethereum_submitter), Error::<T>::InvalidEthereumNonce);
+            let hash = Self::ethereum_signable_hash(call, data, nonce);
+            let signer = Self::recover_ethereum_address(signature, &hash);
+            ensure!(signer.as_ref() == Some(ethereum_submitter), Error::<T>::InvalidEthereumSignature);
+            <EthereumNonceMap<T>>::insert(ethereum_submitter, nonce.saturating_add(1));
+            Ok(())
+        }
+
+        fn can_submit(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, submitter: &SupportedAccountIdOf<T>) -> bool {
             match &submitter {
                 SupportedAccountId::Polkadot(pokadot_submitter) => *pokadot_submitter == loc.owner
                     || match &loc.requester {
@@ -1704,27 +3150,48 @@ pub mod pallet {
                         _ => false
                     }
                     || Self::verified_issuers_by_loc(loc_id, pokadot_submitter).is_some(),
-                SupportedAccountId::Other(other_submitter) => match &other_submitter {
-                    OtherAccountId::Ethereum(ethereum_submitter) => match &loc.requester {
-                        Requester::OtherAccount(other_requester) => match &other_requester {
-                            OtherAccountId::Ethereum(ethereum_requester) => *ethereum_submitter == *ethereum_requester,
-                        },
-                        _ => false,
-                    },
-                }
+                SupportedAccountId::Other(other_submitter) => match &loc.requester {
+                    Requester::OtherAccount(other_requester) => *other_submitter == *other_requester,
+                    _ => false,
+                },
                 _ => false,
             }
         }
 
-        fn apply_file_storage_fee(fee_payer: &T::AccountId, num_of_entries: usize, tot_size: u32) -> DispatchResult {
+        /// Records `fee` in the usage of the LOC's sponsorship, if any, and returns the account paying it:
+        /// the sponsor, otherwise the Polkadot requester, or the owner if there is none.
+        /// Items relayed on behalf of their submitter by an account other than the owner are only paid by a sponsor.
+        fn storage_fee_payer(loc: &LegalOfficerCaseOf<T>, dispatcher: &T::AccountId, submitter: &SupportedAccountIdOf<T>, fee: BalanceOf<T>) -> Result<T::AccountId, DispatchError> {
+            let relayed = *submitter != SupportedAccountId::Polkadot(dispatcher.clone()) && *dispatcher != loc.owner;
+            let fee_payer = match Self::charge_sponsorship(loc, fee)? {
+                Some(sponsor) => sponsor,
+                None => if relayed && !fee.is_zero() {
+                    Err(Error::<T>::SponsorshipRequired)?
+                } else {
+                    match &loc.requester {
+                        Account(requester_account) => requester_account.clone(),
+                        _ => loc.owner.clone(),
+                    }
+                }
+            };
+            Ok(fee_payer)
+        }
+
+        fn apply_file_storage_fee(loc: &LegalOfficerCaseOf<T>, dispatcher: &T::AccountId, submitter: &SupportedAccountIdOf<T>, num_of_entries: usize, tot_size: u32) -> DispatchResult {
             let fee = Self::calculate_fee(num_of_entries as u32, tot_size);
-            ensure!(T::Currency::can_slash(&fee_payer, fee), Error::<T>::InsufficientFunds);
-            let (credit, _) = T::Currency::slash(&fee_payer, fee);
+            let fee_payer = Self::storage_fee_payer(loc, dispatcher, submitter, fee)?;
+            let credit = Self::withdraw_fee(&fee_payer, fee)?;
             T::RewardDistributor::distribute(credit, T::FileStorageFeeDistributionKey::get());
             Self::deposit_event(Event::StorageFeeWithdrawn(fee_payer.clone(), fee));
             Ok(())
         }
 
+        /// Withdraws `fee` from the free balance of `fee_payer`, which must stay alive.
+        fn withdraw_fee(fee_payer: &T::AccountId, fee: BalanceOf<T>) -> Result<CreditOf<T>, DispatchError> {
+            T::Currency::withdraw(fee_payer, fee, Precision::Exact, Preservation::Preserve, Fortitude::Polite)
+                .map_err(|_| Error::<T>::InsufficientFunds.into())
+        }
+
         pub fn calculate_fee(num_of_entries: u32, tot_size: u32) -> BalanceOf<T> {
             let byte_fee: BalanceOf<T> = T::FileStorageByteFee::get();
             let entry_fee: BalanceOf<T> = T::FileStorageEntryFee::get();
@@ -1732,56 +3199,424 @@ pub mod pallet {
                 .saturating_add(entry_fee.saturating_mul(num_of_entries.into()))
         }
 
-        fn apply_legal_fee(loc: &LegalOfficerCaseOf<T>) -> DispatchResult {
-            let fee_payer: Option<T::AccountId> = match loc.sponsorship_id {
-                Some(sponsorship_id) => {
-                    let sponsorship = <SponsorshipMap<T>>::get(sponsorship_id).unwrap();
-                    Some(sponsorship.sponsor)
+        fn legal_fee_payer(loc: &LegalOfficerCaseOf<T>, fee: BalanceOf<T>) -> Result<Option<T::AccountId>, DispatchError> {
+            match Self::charge_sponsorship(loc, fee)? {
+                Some(sponsor) => Ok(Some(sponsor)),
+                None => match loc.requester.clone() {
+                    Account(requester_account) => Ok(Some(requester_account)),
+                    _ => Ok(None),
+                },
+            }
+        }
+
+        /// Places the legal fee of a LOC on hold until the LOC is closed or, if given, the hold times out.
+        fn hold_legal_fee(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, expires_on: Option<T::BlockNumber>) -> DispatchResult {
+            let fee = Self::calculate_legal_fee(loc.loc_type, &loc.owner);
+            let fee_payer = Self::legal_fee_payer(loc, fee)?;
+            if fee_payer.is_some() {
+                let payer = fee_payer.unwrap();
+                T::Currency::hold(&HoldReason::LegalFee.into(), &payer, fee).map_err(|_| Error::<T>::InsufficientFunds)?;
+                if let Some(expiry) = expires_on {
+                    <LegalFeeHoldsByExpiryMap<T>>::try_mutate(expiry, |loc_ids| loc_ids.try_push(*loc_id))
+                        .map_err(|_| Error::<T>::LegalFeeHoldExpiryLimitReached)?;
                 }
-                _ => {
-                    match loc.requester.clone() {
-                        Account(requester_account) => Some(requester_account),
-                        _ => None
-                    }
+                <LegalFeeHoldsMap<T>>::insert(loc_id, LegalFeeHold {
+                    payer: payer.clone(),
+                    amount: fee,
+                    expires_on,
+                });
+                Self::deposit_event(Event::LegalFeeHeld(*loc_id, payer, fee));
+            }
+            Ok(())
+        }
+
+        /// Places the deposit of a new LOC on hold until the LOC is accepted, cancelled or voided.
+        fn hold_draft_deposit(loc_id: &T::LocId, depositor: &T::AccountId) -> DispatchResult {
+            let amount = T::DraftLocDeposit::get();
+            if !amount.is_zero() {
+                T::Currency::hold(&HoldReason::DraftDeposit.into(), depositor, amount).map_err(|_| Error::<T>::InsufficientFunds)?;
+                <DraftDepositsMap<T>>::insert(loc_id, DraftDeposit {
+                    depositor: depositor.clone(),
+                    amount,
+                });
+                Self::deposit_event(Event::DraftDepositHeld(*loc_id, depositor.clone(), amount));
+            }
+            Ok(())
+        }
+
+        fn release_draft_deposit(loc_id: &T::LocId) {
+            if let Some(deposit) = <DraftDepositsMap<T>>::take(loc_id) {
+                let _ = T::Currency::release(&HoldReason::DraftDeposit.into(), &deposit.depositor, deposit.amount, Precision::BestEffort);
+                Self::deposit_event(Event::DraftDepositReturned(*loc_id, deposit.depositor, deposit.amount));
+            }
+        }
+
+        fn take_legal_fee_hold(loc_id: &T::LocId) -> Option<LegalFeeHoldOf<T>> {
+            let hold = <LegalFeeHoldsMap<T>>::take(loc_id)?;
+            Self::unschedule_legal_fee_hold_expiry(loc_id, &hold);
+            Some(hold)
+        }
+
+        /// Keeps the legal fee of an accepted LOC on hold until the LOC is closed.
+        fn keep_legal_fee_on_hold(loc_id: &T::LocId) {
+            <LegalFeeHoldsMap<T>>::mutate(loc_id, |maybe_hold| {
+                if let Some(hold) = maybe_hold.as_mut() {
+                    Self::unschedule_legal_fee_hold_expiry(loc_id, hold);
+                    hold.expires_on = None;
                 }
-            };
-            if fee_payer.is_some() {
-                let fee = Self::calculate_legal_fee(loc.loc_type);
-                ensure!(T::Currency::can_slash(&fee_payer.as_ref().unwrap(), fee), Error::<T>::InsufficientFunds);
-                let (credit, _) = T::Currency::slash(&fee_payer.as_ref().unwrap(), fee);
+            });
+        }
+
+        fn unschedule_legal_fee_hold_expiry(loc_id: &T::LocId, hold: &LegalFeeHoldOf<T>) {
+            if let Some(expires_on) = hold.expires_on {
+                <LegalFeeHoldsByExpiryMap<T>>::mutate(expires_on, |loc_ids| loc_ids.retain(|id| id != loc_id));
+            }
+        }
+
+        /// Takes the legal fee on hold, if any, and pays it out to its beneficiary.
+        fn withdraw_legal_fee_on_hold(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) {
+            if let Some(hold) = Self::take_legal_fee_hold(loc_id) {
+                let (credit, _) = T::Currency::slash(&HoldReason::LegalFee.into(), &hold.payer, hold.amount);
                 let beneficiary = T::LegalFee::distribute(credit, loc.loc_type, loc.owner.clone());
-                Self::deposit_event(Event::LegalFeeWithdrawn(fee_payer.unwrap(), beneficiary, fee));
+                Self::deposit_event(Event::LegalFeeWithdrawn(hold.payer, beneficiary, hold.amount));
             }
-            Ok(())
         }
 
-        pub fn calculate_legal_fee(loc_type: LocType) -> BalanceOf<T> {
-            let fee_in_euro_cent: EuroCent = T::LegalFee::get_legal_fee(loc_type);
-            let exchange_rate: BalanceOf<T> = T::ExchangeRate::get();
-            exchange_rate.saturating_mul(fee_in_euro_cent.into())
+        /// Returns the legal fee on hold, if any, to its payer.
+        fn release_legal_fee(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) {
+            if let Some(hold) = Self::take_legal_fee_hold(loc_id) {
+                Self::return_legal_fee(loc_id, loc, hold);
+            }
+        }
+
+        fn return_legal_fee(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>, hold: LegalFeeHoldOf<T>) {
+            let _ = T::Currency::release(&HoldReason::LegalFee.into(), &hold.payer, hold.amount, Precision::BestEffort);
+            if let Some(sponsorship_id) = loc.sponsorship_id {
+                <SponsorshipMap<T>>::mutate(sponsorship_id, |maybe_sponsorship| {
+                    if let Some(sponsorship) = maybe_sponsorship.as_mut().filter(|sponsorship| sponsorship.sponsor == hold.payer) {
+                        sponsorship.used_balance = sponsorship.used_balance.saturating_sub(hold.amount);
+                    }
+                });
+            }
+            Self::deposit_event(Event::LegalFeeReturned(*loc_id, hold.payer, hold.amount));
+        }
+
+        fn is_untouched_by_owner(loc_id: &T::LocId, loc: &LegalOfficerCaseOf<T>) -> bool {
+            let owner = SupportedAccountId::Polkadot(loc.owner.clone());
+            loc.links.is_empty()
+                && loc.metadata.iter().all(|item| !item.acknowledged && item.submitter != owner)
+                && loc.files.iter().all(|file| !file.acknowledged && file.submitter != owner)
+                && <VerifiedIssuersByLocMap<T>>::iter_key_prefix(loc_id).next().is_none()
+        }
+
+        pub fn calculate_legal_fee(loc_type: LocType, legal_officer: &T::AccountId) -> BalanceOf<T> {
+            let fee = Self::legal_fee_in_fiat(loc_type, legal_officer);
+            let exchange_rate: BalanceOf<T> = Self::current_exchange_rate(fee.currency);
+            exchange_rate.saturating_mul(fee.amount.into())
+        }
+
+        /// The legal fee published by given legal officer, the default one otherwise.
+        pub fn legal_fee_in_fiat(loc_type: LocType, legal_officer: &T::AccountId) -> FiatAmount {
+            T::LegalFeeSchedule::get_legal_fee(legal_officer, loc_type)
+                .unwrap_or_else(|| T::LegalFee::get_legal_fee(loc_type))
+        }
+
+        /// The exchange rate of given currency set on-chain if still valid, the default one otherwise.
+        pub fn current_exchange_rate(currency: FiatCurrency) -> BalanceOf<T> {
+            let now = <frame_system::Pallet<T>>::block_number();
+            Self::exchange_rate(currency)
+                .filter(|record| record.valid_from <= now && now <= record.valid_until)
+                .map_or_else(|| T::ExchangeRate::convert(currency), |record| record.rate)
+        }
+
+        fn is_acceptable_exchange_rate_change(currency: FiatCurrency, rate: BalanceOf<T>) -> bool {
+            let current_rate = Self::current_exchange_rate(currency);
+            let change = if rate > current_rate { rate - current_rate } else { current_rate - rate };
+            change <= T::MaxExchangeRateChange::get() * current_rate
+        }
+
+        fn is_exchange_rate_feed_interval_elapsed(currency: FiatCurrency) -> bool {
+            let now = <frame_system::Pallet<T>>::block_number();
+            Self::exchange_rate(currency)
+                .map_or(true, |record| now >= record.valid_from.saturating_add(T::MinExchangeRateFeedInterval::get()))
         }
 
         fn can_link_to_sponsorship(
             sponsorship_id: &T::SponsorshipId,
             expected_owner: &T::AccountId,
-            expected_sponsored_account: &SupportedAccountId<T::AccountId, T::EthereumAddress>
+            expected_sponsored_account: &SupportedAccountIdOf<T>
         ) -> bool {
             let maybe_sponsorship = Self::sponsorship(sponsorship_id);
             if maybe_sponsorship.is_some() {
                 let sponsorship = maybe_sponsorship.unwrap();
                 sponsorship.legal_officer == *expected_owner
                     && sponsorship.sponsored_account == *expected_sponsored_account
-                    && sponsorship.loc_id.is_none()
+                    && sponsorship.expires_on > <frame_system::Pallet<T>>::block_number()
+                    && sponsorship.max_locs.map_or(true, |max_locs| sponsorship.used_locs < max_locs)
             } else {
                 false
             }
         }
 
-        fn link_sponsorship_to_loc(sponsorship_id: &T::SponsorshipId, loc_id: &T::LocId) -> () {
+        fn can_link_to_optional_sponsorship(
+            sponsorship_id: &Option<T::SponsorshipId>,
+            legal_officer: &T::AccountId,
+            sponsored_account: &SupportedAccountIdOf<T>,
+        ) -> bool {
+            match sponsorship_id {
+                None => true,
+                Some(sponsorship_id) => Self::can_link_to_sponsorship(sponsorship_id, legal_officer, sponsored_account),
+            }
+        }
+
+        /// Records `fee` in the usage of the LOC's sponsorship and returns the sponsor.
+        /// Returns `None` if the LOC is not sponsored. Sponsorships used by LOCs are kept after expiry, they keep paying the fees of those LOCs.
+        fn charge_sponsorship(loc: &LegalOfficerCaseOf<T>, fee: BalanceOf<T>) -> Result<Option<T::AccountId>, DispatchError> {
+            match loc.sponsorship_id {
+                Some(sponsorship_id) => <SponsorshipMap<T>>::try_mutate(sponsorship_id, |maybe_sponsorship| {
+                    match maybe_sponsorship.as_mut() {
+                        Some(sponsorship) => {
+                            let used_balance = sponsorship.used_balance.saturating_add(fee);
+                            ensure!(used_balance <= sponsorship.max_balance, Error::<T>::SponsorshipBudgetExceeded);
+                            sponsorship.used_balance = used_balance;
+                            Ok(Some(sponsorship.sponsor.clone()))
+                        },
+                        None => Err(Error::<T>::SponsorshipNotFound)?,
+                    }
+                }),
+                None => Ok(None),
+            }
+        }
+
+        fn link_sponsorship_to_loc(sponsorship_id: &T::SponsorshipId) -> () {
             <SponsorshipMap<T>>::mutate(sponsorship_id, |maybe_sponsorship| {
                 let sponsorship = maybe_sponsorship.as_mut().unwrap();
-                sponsorship.loc_id = Some(*loc_id);
+                sponsorship.used_locs = sponsorship.used_locs.saturating_add(1);
             });
         }
+
+        /// LOCs requested by given account, `None` if there is none. IDs are returned in storage order,
+        /// which is stable but unrelated to the order of creation.
+        pub fn account_locs(account: T::AccountId) -> Option<Vec<T::LocId>> {
+            Self::non_empty(Self::get_locs_by_requester(&Account(account), None, u32::MAX))
+        }
+
+        /// LOCs requested by given Identity LOC, `None` if there is none. IDs are returned in storage order,
+        /// which is stable but unrelated to the order of creation.
+        pub fn identity_loc_locs(identity_loc_id: T::LocId) -> Option<Vec<T::LocId>> {
+            Self::non_empty(Self::get_locs_by_requester(&Requester::Loc(identity_loc_id), None, u32::MAX))
+        }
+
+        /// LOCs requested by given account of another blockchain, `None` if there is none. IDs are returned
+        /// in storage order, which is stable but unrelated to the order of creation.
+        pub fn other_account_locs(account: OtherAccountIdOf<T>) -> Option<Vec<T::LocId>> {
+            Self::non_empty(Self::get_locs_by_requester(&Requester::OtherAccount(account), None, u32::MAX))
+        }
+
+        fn non_empty(locs: Vec<T::LocId>) -> Option<Vec<T::LocId>> {
+            if locs.is_empty() {
+                None
+            } else {
+                Some(locs)
+            }
+        }
+
+        /// IDs are returned in storage order, which is stable but unrelated to the order of creation.
+        /// At most `limit` IDs are returned, starting after `start_after` if given.
+        pub fn get_locs_by_requester(requester: &RequesterOf<T>, start_after: Option<T::LocId>, limit: u32) -> Vec<T::LocId> {
+            let start_after = match start_after {
+                None => None,
+                Some(loc_id) => match <LocMap<T>>::get(&loc_id) {
+                    None => return Vec::new(),
+                    Some(loc) => Some((loc.loc_type, loc_id)),
+                },
+            };
+            match requester {
+                Requester::None => Vec::new(),
+                Account(requester_account) => {
+                    let prefix = (requester_account.clone(),);
+                    let locs = match start_after {
+                        None => <AccountLocsMap<T>>::iter_key_prefix(prefix),
+                        Some((loc_type, loc_id)) => <AccountLocsMap<T>>::iter_key_prefix_from(prefix, <AccountLocsMap<T>>::hashed_key_for((requester_account, loc_type, loc_id))),
+                    };
+                    locs.map(|(_, loc_id)| loc_id)
+                        .take(limit as usize)
+                        .collect()
+                },
+                Requester::Loc(requester_loc_id) => {
+                    let prefix = (requester_loc_id.clone(),);
+                    let locs = match start_after {
+                        None => <IdentityLocLocsMap<T>>::iter_key_prefix(prefix),
+                        Some((loc_type, loc_id)) => <IdentityLocLocsMap<T>>::iter_key_prefix_from(prefix, <IdentityLocLocsMap<T>>::hashed_key_for((requester_loc_id, loc_type, loc_id))),
+                    };
+                    locs.map(|(_, loc_id)| loc_id)
+                        .take(limit as usize)
+                        .collect()
+                },
+                Requester::OtherAccount(requester_account) => {
+                    let prefix = (requester_account.clone(),);
+                    let locs = match start_after {
+                        None => <OtherAccountLocsMap<T>>::iter_key_prefix(prefix),
+                        Some((loc_type, loc_id)) => <OtherAccountLocsMap<T>>::iter_key_prefix_from(prefix, <OtherAccountLocsMap<T>>::hashed_key_for((requester_account, loc_type, loc_id))),
+                    };
+                    locs.map(|(_, loc_id)| loc_id)
+                        .take(limit as usize)
+                        .collect()
+                },
+            }
+        }
+
+        /// IDs are returned in storage order, which is stable but unrelated to the order of creation.
+        /// Only LOCs with given status are returned if `status` is given.
+        /// At most `limit` IDs are returned, starting after `start_after` if given.
+        pub fn get_locs_by_owner(owner: &T::AccountId, status: Option<LocStatus>, start_after: Option<T::LocId>, limit: u32) -> Vec<T::LocId> {
+            let locs = match start_after {
+                None => <LocsByOwnerMap<T>>::iter_key_prefix(owner),
+                Some(loc_id) => <LocsByOwnerMap<T>>::iter_key_prefix_from(owner, <LocsByOwnerMap<T>>::hashed_key_for(owner, loc_id)),
+            };
+            locs.filter(|loc_id| match status {
+                    None => true,
+                    Some(status) => <LocMap<T>>::get(loc_id).map(|loc| loc.status == status) == Some(true),
+                })
+                .take(limit as usize)
+                .collect()
+        }
+
+        /// Items are returned in storage order, which is stable but unrelated to the order of addition.
+        /// At most `limit` items are returned, starting after the item `start_after` if given.
+        pub fn get_collection_items(collection_loc_id: &T::LocId, start_after: Option<T::CollectionItemId>, limit: u32) -> Vec<(T::CollectionItemId, CollectionItemOf<T>)> {
+            match start_after {
+                None => <CollectionItemsMap<T>>::iter_prefix(collection_loc_id)
+                    .take(limit as usize)
+                    .collect(),
+                Some(item_id) => <CollectionItemsMap<T>>::iter_prefix_from(collection_loc_id, <CollectionItemsMap<T>>::hashed_key_for(collection_loc_id, item_id))
+                    .take(limit as usize)
+                    .collect(),
+            }
+        }
+
+        /// Records are returned in storage order, which is stable but unrelated to the order of addition.
+        /// At most `limit` records are returned, starting after the record `start_after` if given.
+        pub fn get_tokens_records(collection_loc_id: &T::LocId, start_after: Option<T::TokensRecordId>, limit: u32) -> Vec<(T::TokensRecordId, TokensRecordOf<T>)> {
+            match start_after {
+                None => <TokensRecordsMap<T>>::iter_prefix(collection_loc_id)
+                    .take(limit as usize)
+                    .collect(),
+                Some(record_id) => <TokensRecordsMap<T>>::iter_prefix_from(collection_loc_id, <TokensRecordsMap<T>>::hashed_key_for(collection_loc_id, record_id))
+                    .take(limit as usize)
+                    .collect(),
+            }
+        }
+
+        pub fn get_verified_issuers(loc_id: &T::LocId) -> Vec<(T::AccountId, T::LocId)> {
+            match <LocMap<T>>::get(loc_id) {
+                None => Vec::new(),
+                Some(loc) => <VerifiedIssuersByLocMap<T>>::iter_key_prefix(loc_id)
+                    .filter_map(|issuer| Self::verified_issuers(&loc.owner, &issuer)
+                        .map(|verified_issuer| (issuer, verified_issuer.identity_loc)))
+                    .collect(),
+            }
+        }
+
+        /// Only Ethereum accounts sign payloads, the nonce of other accounts is always 0.
+        pub fn get_other_account_nonce(account: &OtherAccountIdOf<T>) -> u64 {
+            match account {
+                OtherAccountId::Ethereum(address) => Self::ethereum_nonce(address),
+                _ => 0,
+            }
+        }
+
+        /// Computes a seal with given format, `None` if the format cannot be computed on-chain.
+        pub fn compute_seal(version: SealVersion, salt: &[u8], personal_data: &[Vec<u8>]) -> Option<SealOf<T>> {
+            match version {
+                SealVersion::V0 => None,
+                SealVersion::V1 => Some(Seal {
+                    version,
+                    hash: T::Hasher::hash(&(version, salt, personal_data).encode()),
+                }),
+            }
+        }
+
+        /// The leaves of a LOC's content tree: its metadata items, then its files, then its links, in order of addition.
+        /// Each leaf is the hash of the SCALE encoding of `(LocItemKind, item)`.
+        pub fn content_leaves(loc: &LegalOfficerCaseOf<T>) -> Vec<<T as Config>::Hash> {
+            let metadata = loc.metadata.iter()
+                .map(|item| merkle::hash_leaf::<_, T::Hasher, _>(&(LocItemKind::Metadata, item)));
+            let files = loc.files.iter()
+                .map(|file| merkle::hash_leaf::<_, T::Hasher, _>(&(LocItemKind::File, file)));
+            let links = loc.links.iter()
+                .map(|link| merkle::hash_leaf::<_, T::Hasher, _>(&(LocItemKind::Link, link)));
+            metadata.chain(files).chain(links).collect()
+        }
+
+        /// The proof of inclusion of given metadata item in the content root of a closed LOC.
+        pub fn get_metadata_item_proof(loc_id: &T::LocId, name: &<T as Config>::Hash) -> Option<merkle::MerkleProof<<T as Config>::Hash>> {
+            Self::content_proof(loc_id, |loc| loc.metadata.iter().position(|item| item.name == *name))
+        }
+
+        /// The proof of inclusion of given file in the content root of a closed LOC.
+        pub fn get_file_proof(loc_id: &T::LocId, hash: &<T as Config>::Hash) -> Option<merkle::MerkleProof<<T as Config>::Hash>> {
+            Self::content_proof(loc_id, |loc| loc.files.iter().position(|file| file.hash == *hash)
+                .map(|index| loc.metadata.len() + index))
+        }
+
+        /// The proof of inclusion of given link in the content root of a closed LOC.
+        pub fn get_link_proof(loc_id: &T::LocId, linked_loc_id: &T::LocId) -> Option<merkle::MerkleProof<<T as Config>::Hash>> {
+            Self::content_proof(loc_id, |loc| loc.links.iter().position(|link| link.id == *linked_loc_id)
+                .map(|index| loc.metadata.len() + loc.files.len() + index))
+        }
+
+        fn content_proof<F>(loc_id: &T::LocId, leaf_index: F) -> Option<merkle::MerkleProof<<T as Config>::Hash>>
+            where F: FnOnce(&LegalOfficerCaseOf<T>) -> Option<usize> {
+            if !<ContentRootsMap<T>>::contains_key(loc_id) {
+                return None;
+            }
+            let loc = <LocMap<T>>::get(loc_id)?;
+            let index = leaf_index(&loc)?;
+            merkle::merkle_proof::<_, T::Hasher>(&Self::content_leaves(&loc), index as u32)
+        }
+
+        /// The collection LOC and the item registering given token, if any.
+        pub fn get_collection_item_by_token(token_type: &<T as Config>::Hash, token_id: &<T as Config>::Hash) -> Option<(T::LocId, T::CollectionItemId)> {
+            <CollectionItemsByTokenMap<T>>::get(token_type, token_id)
+        }
+
+        /// Every registration of given file in a LOC, a collection item or a tokens record.
+        pub fn get_file_occurrences(hash: &<T as Config>::Hash) -> Vec<FileOccurrenceOf<T>> {
+            <FileHashIndexMap<T>>::iter_key_prefix((*hash,))
+                .filter_map(|(loc_id, location)| <LocMap<T>>::get(&loc_id).map(|loc| FileOccurrence {
+                    loc_id,
+                    location,
+                    status: loc.status,
+                    closed: loc.closed,
+                    void: loc.void_info.is_some(),
+                }))
+                .collect()
+        }
+
+        /// The Merkle root of the items of given collection, in order of addition.
+        pub fn get_collection_root(collection_loc_id: &T::LocId) -> Option<<T as Config>::Hash> {
+            let accumulator = <CollectionAccumulatorsMap<T>>::get(collection_loc_id);
+            if accumulator.leaf_count == 0 {
+                None
+            } else {
+                Some(accumulator.root::<T::Hasher>())
+            }
+        }
+
+        /// The proof of inclusion of given item in the current root of its collection.
+        pub fn get_collection_item_proof(collection_loc_id: &T::LocId, item_id: &T::CollectionItemId) -> Option<merkle::MerkleProof<<T as Config>::Hash>> {
+            let leaf_index = <CollectionItemLeafIndexMap<T>>::get(collection_loc_id, item_id)?;
+            <CollectionAccumulatorsMap<T>>::get(collection_loc_id)
+                .proof::<T::Hasher, _>(leaf_index, |level, index| <CollectionAccumulatorNodesMap<T>>::get(collection_loc_id, (level, index)))
+        }
+
+        /// Tells if the seal of given LOC was computed from given private data.
+        pub fn verify_seal(loc_id: &T::LocId, salt: &[u8], personal_data: &[Vec<u8>]) -> bool {
+            match <LocMap<T>>::get(loc_id).and_then(|loc| loc.seal) {
+                None => false,
+                Some(seal) => Self::compute_seal(seal.version, salt, personal_data) == Some(seal),
+            }
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,203 @@
+//! Binary Merkle trees built with a `Hasher`.
+//!
+//! Leaves and inner nodes are hashed with distinct prefixes so that a leaf cannot be passed off as a node.
+//! A node without sibling is promoted as is to the next level.
+
+use frame_support::codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::dispatch::Vec;
+use frame_support::{traits::ConstU32, BoundedVec};
+use scale_info::TypeInfo;
+
+use crate::Hasher;
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// A proof that `leaf` is the leaf at `leaf_index` of a tree with `leaf_count` leaves.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct MerkleProof<Hash> {
+    pub leaf: Hash,
+    pub leaf_index: u32,
+    pub leaf_count: u32,
+    /// The siblings met on the path from the leaf to the root, bottom-up
+    pub siblings: Vec<Hash>,
+}
+
+/// Hashes the SCALE encoding of `data` into a leaf.
+pub fn hash_leaf<Hash, H: Hasher<Hash>, D: Encode>(data: &D) -> Hash {
+    H::hash(&(LEAF_PREFIX, data).encode())
+}
+
+fn hash_node<Hash: Encode, H: Hasher<Hash>>(left: &Hash, right: &Hash) -> Hash {
+    H::hash(&(NODE_PREFIX, left, right).encode())
+}
+
+fn next_level<Hash: Encode + Clone, H: Hasher<Hash>>(level: &[Hash]) -> Vec<Hash> {
+    level.chunks(2)
+        .map(|pair| match pair {
+            [left, right] => hash_node::<Hash, H>(left, right),
+            _ => pair[0].clone(),
+        })
+        .collect()
+}
+
+/// The root of the tree with given leaves. The root of an empty tree is the hash of an empty input.
+pub fn merkle_root<Hash: Encode + Clone, H: Hasher<Hash>>(leaves: &[Hash]) -> Hash {
+    if leaves.is_empty() {
+        return H::hash(&Vec::new());
+    }
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = next_level::<Hash, H>(&level);
+    }
+    level[0].clone()
+}
+
+/// The proof of the leaf at given index, `None` if there is no such leaf.
+pub fn merkle_proof<Hash: Encode + Clone, H: Hasher<Hash>>(leaves: &[Hash], leaf_index: u32) -> Option<MerkleProof<Hash>> {
+    let leaf = leaves.get(leaf_index as usize)?.clone();
+    let mut siblings = Vec::new();
+    let mut index = leaf_index as usize;
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            siblings.push(sibling.clone());
+        }
+        level = next_level::<Hash, H>(&level);
+        index /= 2;
+    }
+    Some(MerkleProof {
+        leaf,
+        leaf_index,
+        leaf_count: leaves.len() as u32,
+        siblings,
+    })
+}
+
+/// Tells if given proof leads to given root.
+pub fn verify_proof<Hash: Encode + Clone + PartialEq, H: Hasher<Hash>>(root: &Hash, proof: &MerkleProof<Hash>) -> bool {
+    if proof.leaf_index >= proof.leaf_count {
+        return false;
+    }
+    let mut node = proof.leaf.clone();
+    let mut index = proof.leaf_index;
+    let mut width = proof.leaf_count;
+    let mut siblings = proof.siblings.iter();
+    while width > 1 {
+        if (index ^ 1) < width {
+            let sibling = match siblings.next() {
+                Some(sibling) => sibling,
+                None => return false,
+            };
+            node = if index % 2 == 0 {
+                hash_node::<Hash, H>(&node, sibling)
+            } else {
+                hash_node::<Hash, H>(sibling, &node)
+            };
+        }
+        index /= 2;
+        width = width / 2 + width % 2;
+    }
+    siblings.next().is_none() && node == *root
+}
+
+/// The maximum number of peaks of an accumulator, enough for `u32::MAX` leaves.
+pub type MaxPeaks = ConstU32<32>;
+
+/// An append-only Merkle tree of which only the roots of the perfect subtrees ("peaks") are kept.
+/// Its root is the one of the tree built with `merkle_root` from the same leaves.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen, Default)]
+pub struct MerkleAccumulator<Hash> {
+    pub leaf_count: u32,
+    /// The peaks, the one of the largest subtree first
+    pub peaks: BoundedVec<Hash, MaxPeaks>,
+}
+
+impl<Hash: Encode + Clone> MerkleAccumulator<Hash> {
+
+    /// Appends given leaf, merging the peaks of subtrees of equal size. Returns the roots of the perfect
+    /// subtrees formed by the leaf as `(level, node)`, the leaf itself being at level 0. The index of a
+    /// node in its level is the index of the leaf shifted right by the level.
+    pub fn append<H: Hasher<Hash>>(&mut self, leaf: Hash) -> Vec<(u32, Hash)> {
+        let mut node = leaf;
+        let mut nodes = Vec::new();
+        let mut level = 0;
+        let mut count = self.leaf_count;
+        nodes.push((level, node.clone()));
+        while count & 1 == 1 {
+            match self.peaks.pop() {
+                Some(left) => node = hash_node::<Hash, H>(&left, &node),
+                None => break,
+            }
+            count >>= 1;
+            level += 1;
+            nodes.push((level, node.clone()));
+        }
+        let _ = self.peaks.try_push(node);
+        self.leaf_count = self.leaf_count.saturating_add(1);
+        nodes
+    }
+
+    /// The proof of the leaf at given index. `node(level, index)` must return the nodes returned by
+    /// `append`, only the siblings met on the path are requested. `None` if there is no such leaf.
+    pub fn proof<H: Hasher<Hash>, F>(&self, leaf_index: u32, node: F) -> Option<MerkleProof<Hash>>
+        where F: Fn(u32, u32) -> Option<Hash> {
+        if leaf_index >= self.leaf_count {
+            return None;
+        }
+        let leaf = node(0, leaf_index)?;
+        let mut siblings = Vec::new();
+        let mut level = 0;
+        let mut width = self.leaf_count;
+        while width > 1 {
+            let sibling = (leaf_index >> level) ^ 1;
+            if sibling < width {
+                let first_leaf = (sibling as u64) << level;
+                let last_leaf = (sibling as u64 + 1) << level;
+                if last_leaf <= self.leaf_count as u64 {
+                    siblings.push(node(level, sibling)?);
+                } else {
+                    siblings.push(self.partial_root::<H>(first_leaf));
+                }
+            }
+            level += 1;
+            width = width / 2 + width % 2;
+        }
+        Some(MerkleProof {
+            leaf,
+            leaf_index,
+            leaf_count: self.leaf_count,
+            siblings,
+        })
+    }
+
+    /// The root of the right-most, incomplete subtree starting at given leaf: the fold of the peaks covering its leaves.
+    /// The peaks are the perfect subtrees of the sizes given by the bits of `leaf_count`, largest first.
+    fn partial_root<H: Hasher<Hash>>(&self, first_leaf: u64) -> Hash {
+        let mut start = 0u64;
+        let sizes = (0..32u32).rev()
+            .filter(|bit| self.leaf_count & (1 << bit) != 0)
+            .map(|bit| 1u64 << bit);
+        let covering: Vec<Hash> = self.peaks.iter().zip(sizes)
+            .filter_map(|(peak, size)| {
+                let covered = start >= first_leaf;
+                start += size;
+                if covered { Some(peak.clone()) } else { None }
+            })
+            .collect();
+        fold_peaks::<Hash, H>(&covering)
+    }
+
+    /// Folds the peaks from right to left. The root of an empty accumulator is the hash of an empty input.
+    pub fn root<H: Hasher<Hash>>(&self) -> Hash {
+        fold_peaks::<Hash, H>(&self.peaks)
+    }
+}
+
+fn fold_peaks<Hash: Encode + Clone, H: Hasher<Hash>>(peaks: &[Hash]) -> Hash {
+    match peaks.split_last() {
+        None => H::hash(&Vec::new()),
+        Some((last, others)) => others.iter().rev()
+            .fold(last.clone(), |node, peak| hash_node::<Hash, H>(peak, &node)),
+    }
+}
@@ -1,4 +1,3 @@
-use frame_support::codec::{Decode, Encode};
 use frame_support::traits::Get;
 use frame_support::dispatch::Vec;
 use frame_support::weights::Weight;
@@ -8,154 +7,485 @@ use crate::{Config, PalletStorageVersion, pallet::StorageVersion};
 use super::*;
 
 
-pub mod v17 {
+pub mod v29 {
+    use frame_support::{storage_alias, Blake2_128Concat};
+    use frame_support::codec::{Decode, Encode};
+    use sp_runtime::traits::Bounded;
+    use sp_std::collections::btree_map::BTreeMap;
     use super::*;
     use crate::*;
 
-    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-    pub struct CollectionItemV16<Hash, LocId, TokenIssuance> {
-        description: Vec<u8>,
-        files: Vec<CollectionItemFileV16<Hash>>,
-        token: Option<CollectionItemTokenV16<TokenIssuance>>,
-        restricted_delivery: bool,
-        terms_and_conditions: Vec<TermsAndConditionsElementV16<LocId>>,
+    /// The storage layout of `StorageVersion::V17HashItemRecordPublicData`, the last released version.
+    /// `OtherAccountId` only gained variants since then, its encoding is unchanged.
+    pub mod v17 {
+        use super::*;
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+        pub struct MetadataItem<AccountId, OtherAccount, Hash> {
+            pub name: Hash,
+            pub value: Hash,
+            pub submitter: SupportedAccountId<AccountId, OtherAccount>,
+            pub acknowledged: bool,
+        }
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+        pub struct LocLink<LocId, Hash> {
+            pub id: LocId,
+            pub nature: Hash,
+        }
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+        pub struct File<Hash, AccountId, OtherAccount> {
+            pub hash: Hash,
+            pub nature: Hash,
+            pub submitter: SupportedAccountId<AccountId, OtherAccount>,
+            pub size: u32,
+            pub acknowledged: bool,
+        }
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+        pub struct LocVoidInfo<LocId> {
+            pub replacer: Option<LocId>,
+        }
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+        pub struct LegalOfficerCase<AccountId, Hash, LocId, BlockNumber, OtherAccount, SponsorshipId> {
+            pub owner: AccountId,
+            pub requester: Requester<AccountId, LocId, OtherAccount>,
+            pub metadata: Vec<MetadataItem<AccountId, OtherAccount, Hash>>,
+            pub files: Vec<File<Hash, AccountId, OtherAccount>>,
+            pub closed: bool,
+            pub loc_type: LocType,
+            pub links: Vec<LocLink<LocId, Hash>>,
+            pub void_info: Option<LocVoidInfo<LocId>>,
+            pub replacer_of: Option<LocId>,
+            pub collection_last_block_submission: Option<BlockNumber>,
+            pub collection_max_size: Option<CollectionSize>,
+            pub collection_can_upload: bool,
+            pub seal: Option<Hash>,
+            pub sponsorship_id: Option<SponsorshipId>,
+        }
+
+        pub type LegalOfficerCaseOf<T> = LegalOfficerCase<
+            <T as frame_system::Config>::AccountId,
+            <T as pallet::Config>::Hash,
+            <T as pallet::Config>::LocId,
+            <T as frame_system::Config>::BlockNumber,
+            OtherAccountIdOf<T>,
+            <T as pallet::Config>::SponsorshipId,
+        >;
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+        pub struct CollectionItem<Hash, LocId, TokenIssuance> {
+            pub description: Hash,
+            pub files: Vec<CollectionItemFile<Hash>>,
+            pub token: Option<CollectionItemToken<TokenIssuance, Hash>>,
+            pub restricted_delivery: bool,
+            pub terms_and_conditions: Vec<TermsAndConditionsElement<LocId, Hash>>,
+        }
+
+        pub type CollectionItemOf<T> = CollectionItem<
+            <T as pallet::Config>::Hash,
+            <T as pallet::Config>::LocId,
+            <T as pallet::Config>::TokenIssuance,
+        >;
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+        pub struct TokensRecord<Hash, BoundedTokensRecordFilesList, AccountId> {
+            pub description: Hash,
+            pub files: BoundedTokensRecordFilesList,
+            pub submitter: AccountId,
+        }
+
+        pub type TokensRecordOf<T> = TokensRecord<
+            <T as pallet::Config>::Hash,
+            BoundedVec<TokensRecordFileOf<T>, <T as pallet::Config>::MaxTokensRecordFiles>,
+            <T as frame_system::Config>::AccountId,
+        >;
+
+        #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+        pub struct Sponsorship<AccountId, OtherAccount, LocId> {
+            pub sponsor: AccountId,
+            pub sponsored_account: SupportedAccountId<AccountId, OtherAccount>,
+            pub legal_officer: AccountId,
+            pub loc_id: Option<LocId>,
+        }
+
+        pub type SponsorshipOf<T> = Sponsorship<
+            <T as frame_system::Config>::AccountId,
+            OtherAccountIdOf<T>,
+            <T as pallet::Config>::LocId,
+        >;
+
+        #[storage_alias]
+        pub type LocMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::LocId, LegalOfficerCaseOf<T>>;
+
+        #[storage_alias]
+        pub type AccountLocsMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, <T as frame_system::Config>::AccountId, Vec<<T as Config>::LocId>>;
+
+        #[storage_alias]
+        pub type IdentityLocLocsMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::LocId, Vec<<T as Config>::LocId>>;
+
+        #[storage_alias]
+        pub type OtherAccountLocsMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, OtherAccountIdOf<T>, Vec<<T as Config>::LocId>>;
+
+        #[storage_alias]
+        pub type CollectionItemsMap<T: Config> = StorageDoubleMap<Pallet<T>, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as Config>::CollectionItemId, CollectionItemOf<T>>;
+
+        /// The V17 value of the LOCs truncated by the migration
+        #[storage_alias]
+        pub type OversizeLocMap<T: Config> = StorageMap<Pallet<T>, Blake2_128Concat, <T as Config>::LocId, LegalOfficerCaseOf<T>>;
+
+        /// The V17 value of the collection items truncated by the migration
+        #[storage_alias]
+        pub type OversizeCollectionItemsMap<T: Config> = StorageDoubleMap<Pallet<T>, Blake2_128Concat, <T as Config>::LocId, Blake2_128Concat, <T as Config>::CollectionItemId, CollectionItemOf<T>>;
     }
 
-    pub type CollectionItemV16Of<T> = CollectionItemV16<
-        <T as pallet::Config>::Hash,
-        <T as pallet::Config>::LocId,
-        <T as pallet::Config>::TokenIssuance,
-    >;
-
-    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-    pub struct CollectionItemFileV16<Hash> {
-        name: Vec<u8>,
-        content_type: Vec<u8>,
-        size: u32,
-        hash: Hash,
+    fn is_oversize_loc<T: Config>(loc: &v17::LegalOfficerCaseOf<T>) -> bool {
+        loc.metadata.len() > T::MaxLocMetadata::get() as usize
+            || loc.files.len() > T::MaxLocFiles::get() as usize
+            || loc.links.len() > T::MaxLocLinks::get() as usize
     }
 
-    pub type CollectionItemFileV16Of<T> = CollectionItemFileV16<<T as pallet::Config>::Hash>;
+    fn is_oversize_item<T: Config>(item: &v17::CollectionItemOf<T>) -> bool {
+        item.files.len() > T::MaxCollectionItemFiles::get() as usize
+            || item.terms_and_conditions.len() > T::MaxCollectionItemTCs::get() as usize
+    }
 
-    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-    pub struct CollectionItemTokenV16<TokenIssuance> {
-        token_type: Vec<u8>,
-        token_id: Vec<u8>,
-        token_issuance: TokenIssuance,
+    /// Tells if the lists of all LOCs and collection items fit in the bounds of the new storage.
+    #[cfg(feature = "try-runtime")]
+    fn fits_bounds<T: Config>() -> bool {
+        let mut fits = true;
+        for (loc_id, loc) in v17::LocMap::<T>::iter() {
+            if is_oversize_loc::<T>(&loc) {
+                log::error!("❗ LOC {:?} exceeds the bounds of metadata, files or links", loc_id);
+                fits = false;
+            }
+        }
+        for (loc_id, item_id, item) in v17::CollectionItemsMap::<T>::iter() {
+            if is_oversize_item::<T>(&item) {
+                log::error!("❗ LOC {:?} item {:?} exceeds the bounds of files or terms and conditions", loc_id, item_id);
+                fits = false;
+            }
+        }
+        fits
     }
 
-    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-    pub struct TermsAndConditionsElementV16<LocId> {
-        tc_type: Vec<u8>,
-        tc_loc: LocId,
-        details: Vec<u8>,
+    /// Copies the LOCs and collection items exceeding the new bounds to the archive maps before they are truncated.
+    fn archive_oversize<T: Config>() {
+        for (loc_id, loc) in v17::LocMap::<T>::iter() {
+            if is_oversize_loc::<T>(&loc) {
+                log::error!("❗ LOC {:?} exceeds the bounds of metadata, files or links, truncated and archived", loc_id);
+                v17::OversizeLocMap::<T>::insert(loc_id, loc);
+            }
+        }
+        for (loc_id, item_id, item) in v17::CollectionItemsMap::<T>::iter() {
+            if is_oversize_item::<T>(&item) {
+                log::error!("❗ LOC {:?} item {:?} exceeds the bounds of files or terms and conditions, truncated and archived", loc_id, item_id);
+                v17::OversizeCollectionItemsMap::<T>::insert(loc_id, item_id, item);
+            }
+        }
     }
 
-    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-    pub struct TokensRecordV16<BoundedDescription, BoundedTokensRecordFilesList, AccountId> {
-        description: BoundedDescription,
-        files: BoundedTokensRecordFilesList,
-        submitter: AccountId,
+    fn loc_type<T: Config>(loc_id: &T::LocId) -> Option<LocType> {
+        let loc_type = LocMap::<T>::get(loc_id).map(|loc| loc.loc_type);
+        if loc_type.is_none() {
+            log::error!("❗ LOC {:?} not found, dropped from requester index", loc_id);
+        }
+        loc_type
     }
 
-    pub type TokensRecordV16Of<T> = TokensRecordV16<
-        BoundedVec<u8, <T as pallet::Config>::MaxTokensRecordDescriptionSize>,
-        BoundedVec<TokensRecordFileV16Of<T>, <T as pallet::Config>::MaxTokensRecordFiles>,
-        <T as frame_system::Config>::AccountId,
-    >;
-
-    #[derive(Encode, Decode, Default, Clone, PartialEq, Eq, Debug, TypeInfo)]
-    pub struct TokensRecordFileV16<Hash, BoundedName, BoundedContentType> {
-        name: BoundedName,
-        content_type: BoundedContentType,
-        size: u32,
-        hash: Hash,
+    /// Indexes the files of LOCs, collection items and tokens records by hash.
+    fn index_file_hashes<T: Config>() {
+        LocMap::<T>::iter().for_each(|(loc_id, loc)| {
+            loc.files.iter().for_each(|file| {
+                FileHashIndexMap::<T>::insert((file.hash, loc_id, FileLocation::Loc), ());
+            });
+        });
+        CollectionItemsMap::<T>::iter().for_each(|(loc_id, item_id, item)| {
+            item.files.iter().for_each(|file| {
+                FileHashIndexMap::<T>::insert((file.hash, loc_id, FileLocation::CollectionItem(item_id)), ());
+            });
+        });
+        TokensRecordsMap::<T>::iter().for_each(|(loc_id, record_id, record)| {
+            record.files.iter().for_each(|file| {
+                FileHashIndexMap::<T>::insert((file.hash, loc_id, FileLocation::TokensRecord(record_id)), ());
+            });
+        });
     }
 
-    pub type TokensRecordFileV16Of<T> = TokensRecordFileV16<
-        <T as pallet::Config>::Hash,
-        BoundedVec<u8, <T as pallet::Config>::MaxFileNameSize>,
-        BoundedVec<u8, <T as pallet::Config>::MaxFileContentTypeSize>,
-    >;
+    /// Schedules the root snapshot of collections of which the last block of submission is yet to come.
+    fn schedule_collection_root_snapshots<T: Config>() {
+        let now = <frame_system::Pallet<T>>::block_number();
+        for (loc_id, loc) in LocMap::<T>::iter() {
+            match loc.collection_last_block_submission {
+                Some(last_block) if last_block > now && loc.void_info.is_none() => {
+                    if CollectionsByLastBlockMap::<T>::try_mutate(last_block, |loc_ids| loc_ids.try_push(loc_id)).is_err() {
+                        log::error!("❗ Too many collections ending at block {:?}, no root snapshot for LOC {:?}", last_block, loc_id);
+                    }
+                },
+                _ => (),
+            }
+        }
+    }
 
-    pub type UnboundedTokensRecordFileV16Of<T> = TokensRecordFileV16<
-        <T as pallet::Config>::Hash,
-        Vec<u8>,
-        Vec<u8>,
-    >;
+    /// Appends the existing items of each collection to its accumulator. V17 items have no timepoint, they are
+    /// appended in the order of the SCALE encoding of `(loc_id, item_id)` so that all nodes compute the same roots.
+    fn accumulate_collection_items<T: Config>() {
+        let mut leaves: Vec<(Vec<u8>, T::LocId, T::CollectionItemId, <T as Config>::Hash)> = CollectionItemsMap::<T>::iter()
+            .map(|(loc_id, item_id, item)| (
+                (loc_id, item_id).encode(),
+                loc_id,
+                item_id,
+                merkle::hash_leaf::<_, T::Hasher, _>(&(&item_id, &item)),
+            ))
+            .collect();
+        leaves.sort_by(|a, b| a.0.cmp(&b.0));
+        for (_, loc_id, item_id, leaf) in leaves {
+            Pallet::<T>::accumulate_collection_item(&loc_id, &item_id, leaf);
+        }
+    }
 
-    pub struct HashItemRecordPublicData<T>(sp_std::marker::PhantomData<T>);
+    /// Registers each token with a single collection item. When several items share a token, the
+    /// item added first is kept, items without timepoint being older than the others and ties being
+    /// broken by the SCALE encoding of `(loc_id, item_id)`. Other items are logged as conflicts.
+    fn index_tokens<T: Config>() {
+        let mut owners: BTreeMap<(T::Hash, T::Hash), (Option<T::BlockNumber>, Vec<u8>, T::LocId, T::CollectionItemId)> = BTreeMap::new();
+        for (loc_id, item_id, item) in CollectionItemsMap::<T>::iter() {
+            if let Some(token) = item.token {
+                let candidate = (
+                    item.added_on.map(|timepoint| timepoint.block_number),
+                    (loc_id, item_id).encode(),
+                    loc_id,
+                    item_id,
+                );
+                let key = (token.token_type, token.token_id);
+                match owners.get(&key) {
+                    Some(owner) if (&owner.0, &owner.1) <= (&candidate.0, &candidate.1) => {
+                        log::warn!("❗ Token {:?} of item {:?} in LOC {:?} conflicts with item {:?} in LOC {:?}, not indexed", key.1, item_id, loc_id, owner.3, owner.2);
+                    },
+                    Some(owner) => {
+                        log::warn!("❗ Token {:?} of item {:?} in LOC {:?} conflicts with item {:?} in LOC {:?}, not indexed", key.1, owner.3, owner.2, item_id, loc_id);
+                        owners.insert(key, candidate);
+                    },
+                    None => {
+                        owners.insert(key, candidate);
+                    },
+                }
+            }
+        }
+        for ((token_type, token_id), (_, _, loc_id, item_id)) in owners {
+            CollectionItemsByTokenMap::<T>::insert(token_type, token_id, (loc_id, item_id));
+        }
+    }
+
+    /// Keeps the first elements of given list, in their order of addition, up to the bound.
+    fn bounded<Item, Bound: Get<u32>>(items: Vec<Item>) -> BoundedVec<Item, Bound> {
+        BoundedVec::truncate_from(items)
+    }
+
+    /// Migrates the storage of the last release (V17) to the current layout:
+    /// - LOCs get a status, timepoints, bounded lists and versioned seals;
+    /// - LOCs are indexed by owner, requester LOCs by type;
+    /// - files are indexed by hash, see `index_file_hashes`;
+    /// - collections still accepting items are scheduled for a root snapshot;
+    /// - collection items and tokens records get timepoints, records an account of any chain as submitter;
+    /// - collection items are appended to the accumulator of their collection, see `accumulate_collection_items`;
+    /// - sponsorships get limits;
+    /// - collection items are indexed by token, see `index_tokens`.
+    ///
+    /// The bounds must be sized to the chain state, `pre_upgrade` fails otherwise. If the upgrade is run anyway,
+    /// the lists of the LOCs and items exceeding the bounds keep their first elements and their V17 value is
+    /// archived in `v17::OversizeLocMap` and `v17::OversizeCollectionItemsMap`, so that no record is lost.
+    pub struct MigrateToV29<T>(sp_std::marker::PhantomData<T>);
+
+    impl<T: Config> OnRuntimeUpgrade for MigrateToV29<T> {
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, sp_runtime::DispatchError> {
+            if PalletStorageVersion::<T>::get() != StorageVersion::V17HashItemRecordPublicData {
+                return Ok(Vec::new());
+            }
+            if !fits_bounds::<T>() {
+                return Err("Some LOCs or collection items exceed the new bounds".into());
+            }
+            let locs = v17::LocMap::<T>::iter_keys().count() as u32;
+            let items = v17::CollectionItemsMap::<T>::iter_keys().count() as u32;
+            let records = TokensRecordsMap::<T>::iter_keys().count() as u32;
+            let sponsorships = SponsorshipMap::<T>::iter_keys().count() as u32;
+            Ok((locs, items, records, sponsorships).encode())
+        }
 
-    impl<T: Config> OnRuntimeUpgrade for HashItemRecordPublicData<T> {
         fn on_runtime_upgrade() -> Weight {
             super::do_storage_upgrade::<T, _>(
-                StorageVersion::V16MoveTokenIssuance,
                 StorageVersion::V17HashItemRecordPublicData,
-                "HashItemRecordPublicData",
+                StorageVersion::V29IndexTokens,
+                "MigrateToV29",
                 || {
-                    CollectionItemsMap::<T>::translate_values(|item: CollectionItemV16Of<T>| {
-                        let description = T::Hasher::hash(&item.description).into();
-
-                        let files = item.files.iter()
-                            .map(|file| {
-                                CollectionItemFile {
-                                    name: T::Hasher::hash(&file.name).into(),
-                                    content_type: T::Hasher::hash(&file.content_type).into(),
-                                    size: file.size,
-                                    hash: file.hash,
-                                }
+                    archive_oversize::<T>();
+
+                    LocMap::<T>::translate_values(|loc: v17::LegalOfficerCaseOf<T>| {
+                        let status = if loc.void_info.is_some() {
+                            LocStatus::Void
+                        } else if loc.closed {
+                            LocStatus::Closed
+                        } else {
+                            LocStatus::Open
+                        };
+                        let metadata = loc.metadata.into_iter()
+                            .map(|item| MetadataItem {
+                                name: item.name,
+                                value: item.value,
+                                submitter: item.submitter,
+                                acknowledged: item.acknowledged,
+                                added_on: None,
+                                acknowledged_on: None,
                             })
                             .collect();
-
-                        let terms_and_conditions = item.terms_and_conditions.iter()
-                            .map(|terms| {
-                                TermsAndConditionsElement {
-                                    details: T::Hasher::hash(&terms.details).into(),
-                                    tc_loc: terms.tc_loc,
-                                    tc_type: T::Hasher::hash(&terms.tc_type).into(),
-                                }
+                        let files = loc.files.into_iter()
+                            .map(|file| File {
+                                hash: file.hash,
+                                nature: file.nature,
+                                submitter: file.submitter,
+                                size: file.size,
+                                acknowledged: file.acknowledged,
+                                added_on: None,
+                                acknowledged_on: None,
                             })
                             .collect();
+                        let links = loc.links.into_iter()
+                            .map(|link| LocLinkItem {
+                                id: link.id,
+                                nature: link.nature,
+                                added_on: None,
+                            })
+                            .collect();
+                        Some(LegalOfficerCase {
+                            owner: loc.owner,
+                            requester: loc.requester,
+                            metadata: bounded(metadata),
+                            files: bounded(files),
+                            status,
+                            closed: loc.closed,
+                            loc_type: loc.loc_type,
+                            links: bounded(links),
+                            void_info: loc.void_info.map(|void_info| LocVoidInfo {
+                                replacer: void_info.replacer,
+                                voided_on: None,
+                            }),
+                            replacer_of: loc.replacer_of,
+                            collection_last_block_submission: loc.collection_last_block_submission,
+                            collection_max_size: loc.collection_max_size,
+                            collection_can_upload: loc.collection_can_upload,
+                            seal: loc.seal.map(|hash| Seal {
+                                version: SealVersion::V0,
+                                hash,
+                            }),
+                            sponsorship_id: loc.sponsorship_id,
+                            closed_on: None,
+                            rejection_reason: None,
+                        })
+                    });
 
-                        let token = item.token.map(|some_token| {
-                            CollectionItemToken {
-                                token_id: T::Hasher::hash(&some_token.token_id).into(),
-                                token_issuance: some_token.token_issuance,
-                                token_type: T::Hasher::hash(&some_token.token_type).into(),
+                    for (loc_id, loc) in LocMap::<T>::iter() {
+                        LocsByOwnerMap::<T>::insert(loc.owner, loc_id, ());
+                    }
+
+                    let account_locs: Vec<_> = v17::AccountLocsMap::<T>::drain().collect();
+                    for (account, locs) in account_locs {
+                        for loc_id in locs {
+                            if let Some(loc_type) = loc_type::<T>(&loc_id) {
+                                AccountLocsMap::<T>::insert((&account, loc_type, &loc_id), ());
+                            }
+                        }
+                    }
+                    let identity_loc_locs: Vec<_> = v17::IdentityLocLocsMap::<T>::drain().collect();
+                    for (identity_loc_id, locs) in identity_loc_locs {
+                        for loc_id in locs {
+                            if let Some(loc_type) = loc_type::<T>(&loc_id) {
+                                IdentityLocLocsMap::<T>::insert((&identity_loc_id, loc_type, &loc_id), ());
+                            }
+                        }
+                    }
+                    let other_account_locs: Vec<_> = v17::OtherAccountLocsMap::<T>::drain().collect();
+                    for (account, locs) in other_account_locs {
+                        for loc_id in locs {
+                            if let Some(loc_type) = loc_type::<T>(&loc_id) {
+                                OtherAccountLocsMap::<T>::insert((&account, loc_type, &loc_id), ());
                             }
-                        });
+                        }
+                    }
 
+                    CollectionItemsMap::<T>::translate_values(|item: v17::CollectionItemOf<T>| {
                         Some(CollectionItem {
-                            description,
-                            files,
+                            description: item.description,
+                            files: bounded(item.files),
+                            token: item.token,
                             restricted_delivery: item.restricted_delivery,
-                            terms_and_conditions,
-                            token,
+                            terms_and_conditions: bounded(item.terms_and_conditions),
+                            added_on: None,
                         })
                     });
 
-                    TokensRecordsMap::<T>::translate_values(|record: TokensRecordV16Of<T>| {
-                        let description = T::Hasher::hash(&record.description).into();
-
-                        let mut files: BoundedVec<TokensRecordFileOf<T>, T::MaxTokensRecordFiles> = BoundedVec::with_bounded_capacity(record.files.len());
-                        for file in record.files.iter() {
-                            files.try_push(TokensRecordFile {
-                                name: T::Hasher::hash(&file.name).into(),
-                                content_type: T::Hasher::hash(&file.content_type).into(),
-                                size: file.size,
-                                hash: file.hash,
-                            }).unwrap();
-                        }
-
+                    TokensRecordsMap::<T>::translate_values(|record: v17::TokensRecordOf<T>| {
                         Some(TokensRecord {
-                            description,
-                            files,
-                            submitter: record.submitter,
+                            description: record.description,
+                            files: record.files,
+                            submitter: SupportedAccountId::Polkadot(record.submitter),
+                            added_on: None,
+                        })
+                    });
+
+                    // A V17 sponsorship could be used by a single LOC, which references it with `sponsorship_id`.
+                    // The back-reference `loc_id` is therefore only kept as the count of used LOCs.
+                    SponsorshipMap::<T>::translate_values(|sponsorship: v17::SponsorshipOf<T>| {
+                        Some(Sponsorship {
+                            sponsor: sponsorship.sponsor,
+                            sponsored_account: sponsorship.sponsored_account,
+                            legal_officer: sponsorship.legal_officer,
+                            expires_on: T::BlockNumber::max_value(),
+                            max_balance: BalanceOf::<T>::max_value(),
+                            max_locs: Some(1),
+                            used_locs: if sponsorship.loc_id.is_some() { 1 } else { 0 },
+                            used_balance: Zero::zero(),
                         })
                     });
+
+                    index_file_hashes::<T>();
+                    accumulate_collection_items::<T>();
+                    schedule_collection_root_snapshots::<T>();
+                    index_tokens::<T>();
                 }
             )
         }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), sp_runtime::DispatchError> {
+            if state.is_empty() {
+                return Ok(());
+            }
+            let (locs, items, records, sponsorships): (u32, u32, u32, u32) = Decode::decode(&mut &state[..])
+                .map_err(|_| "Cannot decode pre-upgrade state")?;
+            frame_support::ensure!(PalletStorageVersion::<T>::get() == StorageVersion::V29IndexTokens, "Unexpected storage version");
+            frame_support::ensure!(LocMap::<T>::iter_values().count() as u32 == locs, "Some LOCs were lost or cannot be decoded");
+            frame_support::ensure!(CollectionItemsMap::<T>::iter_values().count() as u32 == items, "Some collection items were lost or cannot be decoded");
+            frame_support::ensure!(TokensRecordsMap::<T>::iter_values().count() as u32 == records, "Some tokens records were lost or cannot be decoded");
+            frame_support::ensure!(SponsorshipMap::<T>::iter_values().count() as u32 == sponsorships, "Some sponsorships were lost or cannot be decoded");
+            frame_support::ensure!(LocsByOwnerMap::<T>::iter_keys().count() as u32 == locs, "Some LOCs are not indexed by owner");
+            for (loc_id, loc) in LocMap::<T>::iter() {
+                for file in loc.files.iter() {
+                    frame_support::ensure!(FileHashIndexMap::<T>::contains_key((file.hash, loc_id, FileLocation::Loc)), "Some LOC files are not indexed by hash");
+                }
+            }
+            for (loc_id, item_id, _) in CollectionItemsMap::<T>::iter() {
+                frame_support::ensure!(Pallet::<T>::get_collection_item_proof(&loc_id, &item_id).is_some(), "Some collection items are not accumulated");
+            }
+            for (token_type, token_id, (loc_id, item_id)) in CollectionItemsByTokenMap::<T>::iter() {
+                let token = CollectionItemsMap::<T>::get(loc_id, item_id).and_then(|item| item.token);
+                frame_support::ensure!(token.map(|token| token.token_type == token_type && token.token_id == token_id) == Some(true), "Token index points to another item");
+            }
+            Ok(())
+        }
     }
 }
 
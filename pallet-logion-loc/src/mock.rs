@@ -1,11 +1,12 @@
-use crate::{self as pallet_loc, LocType, NegativeImbalanceOf, RequesterOf, Hasher};
-use logion_shared::{Beneficiary, DistributionKey, EuroCent, IsLegalOfficer, LegalFee};
+use crate::{self as pallet_loc, LocType, CreditOf, RequesterOf, Hasher, HoldReason};
+use logion_shared::{Beneficiary, DistributionKey, FiatAmount, FiatCurrency, IsLegalOfficer, LegalFee, LegalFeeSchedule};
 use sp_core::hash::H256;
-use frame_support::{construct_runtime, parameter_types, traits::{EnsureOrigin, Currency}};
+use frame_support::{construct_runtime, parameter_types, traits::{EnsureOrigin, Time, fungible::Balanced}};
 use sp_io::hashing::sha2_256;
-use sp_runtime::{traits::{BlakeTwo256, IdentityLookup}, testing::Header, Percent};
+use sp_runtime::{traits::{BlakeTwo256, Convert, IdentityLookup}, testing::Header, Percent};
 use frame_system as system;
 use sp_core::H160;
+use sp_keystore::{testing::MemoryKeystore, KeystoreExt};
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -14,8 +15,12 @@ pub type AccountId = u64;
 pub type Balance = u128;
 pub type TokenIssuance = u64;
 pub type EthereumAddress = H160;
+pub type BitcoinAddress = [u8; 25];
+pub type SolanaAddress = H256;
+pub type TezosAddress = [u8; 21];
 pub type SponsorshipId = u32;
 pub type Hash = H256;
+pub type Moment = u64;
 
 construct_runtime!(
     pub struct Test where
@@ -78,7 +83,7 @@ impl pallet_balances::Config for Test {
     type DustRemoval = ();
     type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
-    type HoldIdentifier = [u8; 8];
+    type HoldIdentifier = HoldReason;
     type FreezeIdentifier = [u8; 8];
     type MaxFreezes = MaxFreezes;
 	type MaxHolds = MaxHolds;
@@ -95,6 +100,7 @@ pub const ISSUER_ID2: u64 = 6;
 pub const SPONSOR_ID: u64 = 7;
 pub const TREASURY_ACCOUNT_ID: u64 = 8;
 pub const UNAUTHORIZED_CALLER: u64 = 9;
+pub const EXCHANGE_RATE_FEEDER: u64 = 10;
 
 pub struct LoAuthorityListMock;
 impl EnsureOrigin<RuntimeOrigin> for LoAuthorityListMock {
@@ -121,6 +127,16 @@ parameter_types! {
     pub const MaxFileContentTypeSize: u32 = 255;
     pub const MaxIssuers: u32 = 2;
     pub const MaxTokensRecordFiles: u32 = 10;
+    pub const MaxLocMetadata: u32 = 10;
+    pub const MaxLocFiles: u32 = 10;
+    pub const MaxLocLinks: u32 = 10;
+    pub const MaxCollectionItemFiles: u32 = 10;
+    pub const MaxCollectionItemTCs: u32 = 10;
+    pub const MaxIssuerLocs: u32 = 10;
+    pub const MaxExpiringSponsorships: u32 = 2;
+    pub const LegalFeeHoldTimeout: u64 = 50;
+    pub const MaxExpiringLegalFeeHolds: u32 = 10;
+    pub const MaxCollectionsByLastBlock: u32 = 10;
 }
 
 // Fake accounts used to simulate reward beneficiaries balances
@@ -130,19 +146,19 @@ pub const STAKERS_ACCOUNT: AccountId = 22;
 
 // Type used as beneficiary payout handle
 pub struct RewardDistributor;
-impl logion_shared::RewardDistributor<NegativeImbalanceOf<Test>, Balance>
+impl logion_shared::RewardDistributor<Balances, AccountId>
 for RewardDistributor
 {
-    fn payout_reserve(reward: NegativeImbalanceOf<Test>) {
-        Balances::resolve_creating(&RESERVE_ACCOUNT, reward);
+    fn payout_reserve(reward: CreditOf<Test>) {
+        let _ = Balances::resolve(&RESERVE_ACCOUNT, reward);
     }
 
-    fn payout_collators(reward: NegativeImbalanceOf<Test>) {
-        Balances::resolve_creating(&COLLATORS_ACCOUNT, reward);
+    fn payout_collators(reward: CreditOf<Test>) {
+        let _ = Balances::resolve(&COLLATORS_ACCOUNT, reward);
     }
 
-    fn payout_stakers(reward: NegativeImbalanceOf<Test>) {
-        Balances::resolve_creating(&STAKERS_ACCOUNT, reward);
+    fn payout_stakers(reward: CreditOf<Test>) {
+        let _ = Balances::resolve(&STAKERS_ACCOUNT, reward);
     }
 }
 
@@ -154,7 +170,11 @@ parameter_types! {
         collators_percent: Percent::from_percent(30),
         reserve_percent: Percent::from_percent(20),
     };
-    pub const ExchangeRate: Balance = 200_000_000_000_000_000; // 1 euro cent = 0.2 LGNT;
+    pub const EurExchangeRate: Balance = 200_000_000_000_000_000; // 1 euro cent = 0.2 LGNT;
+    pub const ChfExchangeRate: Balance = 220_000_000_000_000_000; // 1 Swiss centime = 0.22 LGNT;
+    pub const UsdExchangeRate: Balance = 180_000_000_000_000_000; // 1 US cent = 0.18 LGNT;
+    pub const MaxExchangeRateChange: Percent = Percent::from_percent(10);
+    pub const MinExchangeRateFeedInterval: u64 = 10;
     pub const TreasuryAccountId: u64 = TREASURY_ACCOUNT_ID;
     pub const CertificateFee: u64 = 4_000_000_000_000_000; // 0.004 LGNT
     pub const CertificateFeeDistributionKey: DistributionKey = DistributionKey {
@@ -164,26 +184,55 @@ parameter_types! {
     };
 }
 
+pub struct ExchangeRate;
+impl Convert<FiatCurrency, Balance> for ExchangeRate {
+    fn convert(currency: FiatCurrency) -> Balance {
+        match currency {
+            FiatCurrency::Eur => EurExchangeRate::get(),
+            FiatCurrency::Chf => ChfExchangeRate::get(),
+            FiatCurrency::Usd => UsdExchangeRate::get(),
+        }
+    }
+}
+
 pub struct LegalFeeImpl;
-impl LegalFee<NegativeImbalanceOf<Test>, Balance, LocType, AccountId> for LegalFeeImpl {
-    fn get_legal_fee(loc_type: LocType) -> EuroCent {
-        match loc_type {
+impl LegalFee<Balances, LocType, AccountId> for LegalFeeImpl {
+    fn get_legal_fee(loc_type: LocType) -> FiatAmount {
+        let amount = match loc_type {
             LocType::Identity => 8_00, // 8.00 euros
             _ => 100_00, // 100.00 euros
+        };
+        FiatAmount {
+            currency: FiatCurrency::Eur,
+            amount,
         }
     }
 
-    fn distribute(amount: NegativeImbalanceOf<Test>, loc_type: LocType, loc_owner: AccountId) -> Beneficiary<AccountId> {
+    fn distribute(amount: CreditOf<Test>, loc_type: LocType, loc_owner: AccountId) -> Beneficiary<AccountId> {
 
         let (beneficiary, target) = match loc_type {
             LocType::Identity => (Beneficiary::Treasury, TREASURY_ACCOUNT_ID),
             _ => (Beneficiary::LegalOfficer(loc_owner), loc_owner),
         };
-        Balances::resolve_creating(&target, amount);
+        let _ = Balances::resolve(&target, amount);
         beneficiary
     }
 }
 
+parameter_types! {
+    pub static PublishedLegalFee: Option<(AccountId, LocType, FiatAmount)> = None;
+    pub static DraftLocDeposit: Balance = 0;
+}
+
+pub struct LegalFeeScheduleMock;
+impl LegalFeeSchedule<AccountId, LocType> for LegalFeeScheduleMock {
+    fn get_legal_fee(legal_officer: &AccountId, loc_type: LocType) -> Option<FiatAmount> {
+        PublishedLegalFee::get()
+            .filter(|(published_by, published_for, _)| published_by == legal_officer && *published_for == loc_type)
+            .map(|(_, _, fee)| fee)
+    }
+}
+
 pub struct SHA256;
 impl Hasher<H256> for SHA256 {
 
@@ -193,6 +242,17 @@ impl Hasher<H256> for SHA256 {
     }
 }
 
+pub const MILLISECS_PER_BLOCK: Moment = 6000;
+
+pub struct TimestampMock;
+impl Time for TimestampMock {
+    type Moment = Moment;
+
+    fn now() -> Moment {
+        System::block_number() * MILLISECS_PER_BLOCK
+    }
+}
+
 impl pallet_loc::Config for Test {
     type LocId = u32;
     type RuntimeEvent = RuntimeEvent;
@@ -210,17 +270,37 @@ impl pallet_loc::Config for Test {
     type MaxTokensRecordFiles = MaxTokensRecordFiles;
     type WeightInfo = ();
     type Currency = Balances;
+    type RuntimeHoldReason = HoldReason;
     type FileStorageByteFee = FileStorageByteFee;
     type FileStorageEntryFee = FileStorageEntryFee;
     type RewardDistributor = RewardDistributor;
     type FileStorageFeeDistributionKey = RewardDistributionKey;
     type EthereumAddress = EthereumAddress;
+    type BitcoinAddress = BitcoinAddress;
+    type SolanaAddress = SolanaAddress;
+    type TezosAddress = TezosAddress;
     type SponsorshipId = SponsorshipId;
     type LegalFee = LegalFeeImpl;
+    type LegalFeeSchedule = LegalFeeScheduleMock;
     type ExchangeRate = ExchangeRate;
+    type ExchangeRateOrigin = system::EnsureRoot<AccountId>;
+    type MaxExchangeRateChange = MaxExchangeRateChange;
+    type MinExchangeRateFeedInterval = MinExchangeRateFeedInterval;
     type CertificateFee = CertificateFee;
     type CertificateFeeDistributionKey = CertificateFeeDistributionKey;
     type TokenIssuance = TokenIssuance;
+    type Timestamp = TimestampMock;
+    type MaxLocMetadata = MaxLocMetadata;
+    type MaxLocFiles = MaxLocFiles;
+    type MaxLocLinks = MaxLocLinks;
+    type MaxCollectionItemFiles = MaxCollectionItemFiles;
+    type MaxCollectionItemTCs = MaxCollectionItemTCs;
+    type MaxIssuerLocs = MaxIssuerLocs;
+    type MaxExpiringSponsorships = MaxExpiringSponsorships;
+    type LegalFeeHoldTimeout = LegalFeeHoldTimeout;
+    type MaxExpiringLegalFeeHolds = MaxExpiringLegalFeeHolds;
+    type MaxCollectionsByLastBlock = MaxCollectionsByLastBlock;
+    type DraftLocDeposit = DraftLocDeposit;
 }
 
 // Build genesis storage according to the mock runtime.
@@ -231,6 +311,7 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
 pub fn new_test_ext_at_block(block_number: u64) -> sp_io::TestExternalities {
     let t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
     let mut ext = sp_io::TestExternalities::new(t);
+    ext.register_extension(KeystoreExt::new(MemoryKeystore::new()));
     ext.execute_with(|| System::set_block_number(block_number));
     ext
 }
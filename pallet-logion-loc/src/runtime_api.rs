@@ -5,20 +5,116 @@
 use sp_api;
 use codec::Codec;
 use sp_runtime::traits::MaybeDisplay;
-use crate::LocType;
+use sp_std::vec::Vec;
+use crate::{LocStatus, LocType, Requester, merkle::MerkleProof};
+use logion_shared::FiatAmount;
 
 sp_api::decl_runtime_apis! {
 
-    pub trait FeesApi<Balance, TokenIssuance>
-    where Balance: Codec + MaybeDisplay, TokenIssuance: Codec + MaybeDisplay
+    #[api_version(3)]
+    pub trait FeesApi<Balance, TokenIssuance, AccountId>
+    where Balance: Codec + MaybeDisplay, TokenIssuance: Codec + MaybeDisplay, AccountId: Codec
     {
         /// Query expected fees for submitting given files
         fn query_file_storage_fee(num_of_entries: u32, tot_size: u32) -> Balance;
 
         /// Query expected legal fees for opening a LOC with given type
+        #[changed_in(2)]
         fn query_legal_fee(loc_type: LocType) -> Balance;
 
+        #[changed_in(3)]
+        fn query_legal_fee(legal_officer: AccountId, loc_type: LocType) -> Balance;
+
+        /// Query expected legal fees for opening a LOC with given type and legal officer, in fiat and in balance
+        fn query_legal_fee(legal_officer: AccountId, loc_type: LocType) -> (FiatAmount, Balance);
+
         /// Query expected item legal fees for adding an item with given type
         fn query_certificate_fee(token_issuance: TokenIssuance) -> Balance;
     }
-}
\ No newline at end of file
+
+    pub trait LocApi<AccountId, LocId, OtherAccountId, Loc, CollectionItemId, CollectionItem, TokensRecordId, TokensRecord>
+    where
+        AccountId: Codec,
+        LocId: Codec,
+        OtherAccountId: Codec,
+        Loc: Codec,
+        CollectionItemId: Codec,
+        CollectionItem: Codec,
+        TokensRecordId: Codec,
+        TokensRecord: Codec,
+    {
+        /// Get the LOC with given ID
+        fn get_loc(loc_id: LocId) -> Option<Loc>;
+
+        /// Get the IDs of at most `limit` LOCs owned by given legal officer, with given status if any, starting after `start_after` if given
+        fn get_locs_by_owner(owner: AccountId, status: Option<LocStatus>, start_after: Option<LocId>, limit: u32) -> Vec<LocId>;
+
+        /// Get the IDs of at most `limit` LOCs requested by given requester (Polkadot account, Identity LOC or account of another blockchain),
+        /// starting after `start_after` if given
+        fn get_locs_by_requester(requester: Requester<AccountId, LocId, OtherAccountId>, start_after: Option<LocId>, limit: u32) -> Vec<LocId>;
+
+        /// Get at most `limit` items of given collection, starting after item `start_after` if given
+        fn get_collection_items(collection_loc_id: LocId, start_after: Option<CollectionItemId>, limit: u32) -> Vec<(CollectionItemId, CollectionItem)>;
+
+        /// Get at most `limit` tokens records of given collection, starting after record `start_after` if given
+        fn get_tokens_records(collection_loc_id: LocId, start_after: Option<TokensRecordId>, limit: u32) -> Vec<(TokensRecordId, TokensRecord)>;
+
+        /// Get the verified issuers selected on given LOC along with their Identity LOC
+        fn get_verified_issuers(loc_id: LocId) -> Vec<(AccountId, LocId)>;
+
+        /// Get the nonce expected in the next signature of given account of another blockchain (always 0 for non-Ethereum accounts)
+        fn get_other_account_nonce(account: OtherAccountId) -> u64;
+    }
+
+    pub trait FileIndexApi<Hash, FileOccurrence>
+    where
+        Hash: Codec,
+        FileOccurrence: Codec,
+    {
+        /// Get every registration of the file with given hash in LOCs, collection items and tokens records, along with the status of their LOC
+        fn get_file_occurrences(hash: Hash) -> Vec<FileOccurrence>;
+    }
+
+    pub trait TokenIndexApi<Hash, LocId, CollectionItemId>
+    where
+        Hash: Codec,
+        LocId: Codec,
+        CollectionItemId: Codec,
+    {
+        /// Get the collection LOC and the item registering the token with given type and ID
+        fn get_collection_item_by_token(token_type: Hash, token_id: Hash) -> Option<(LocId, CollectionItemId)>;
+    }
+
+    pub trait SealApi<LocId>
+    where
+        LocId: Codec,
+    {
+        /// Tell if the seal of given LOC was computed from given salt and personal data
+        fn verify_seal(loc_id: LocId, salt: Vec<u8>, personal_data: Vec<Vec<u8>>) -> bool;
+    }
+
+    pub trait LocContentApi<LocId, Hash, CollectionItemId>
+    where
+        LocId: Codec,
+        Hash: Codec,
+        CollectionItemId: Codec,
+    {
+        /// Get the Merkle root of the content of given closed LOC
+        fn get_content_root(loc_id: LocId) -> Option<Hash>;
+
+        /// Get the proof of inclusion of given metadata item in the content root of given closed LOC
+        fn get_metadata_item_proof(loc_id: LocId, name: Hash) -> Option<MerkleProof<Hash>>;
+
+        /// Get the proof of inclusion of given file in the content root of given closed LOC
+        fn get_file_proof(loc_id: LocId, hash: Hash) -> Option<MerkleProof<Hash>>;
+
+        /// Get the proof of inclusion of given link in the content root of given closed LOC
+        fn get_link_proof(loc_id: LocId, linked_loc_id: LocId) -> Option<MerkleProof<Hash>>;
+
+        /// Get the current Merkle root of the items of given collection
+        fn get_collection_root(collection_loc_id: LocId) -> Option<Hash>;
+
+        /// Get the proof of inclusion of given item in the current root of its collection
+        fn get_collection_item_proof(collection_loc_id: LocId, item_id: CollectionItemId) -> Option<MerkleProof<Hash>>;
+    }
+}
@@ -1,19 +1,26 @@
 use core::str::FromStr;
-use frame_support::{assert_err, assert_ok};
+use codec::Encode;
+use frame_support::{assert_err, assert_ok, BoundedVec};
 use frame_support::error::BadOrigin;
-use frame_support::traits::Len;
-use sp_core::{H256, H160};
+use frame_support::traits::{Get, Hooks, Len};
+use frame_support::traits::fungible::InspectHold;
+use sp_core::{ecdsa, Pair, H256, H160};
 use sp_runtime::traits::BlakeTwo256;
 use sp_runtime::traits::Hash;
+use sp_std::collections::btree_map::BTreeMap;
 
-use logion_shared::{Beneficiary, LocQuery, LocValidity};
+use logion_shared::{Beneficiary, FiatAmount, FiatCurrency, LocQuery, LocValidity};
 
 use crate::TokensRecordFileOf;
+use crate::weights::WeightInfo;
 use crate::{
-    Error, File, LegalOfficerCase, LocLink, LocType, MetadataItem, CollectionItem, CollectionItemFile,
+    Error, File, FileOf, LegalOfficerCase, LocLink, LocLinkItem, LocLinkItemOf, LocType, MetadataItem, MetadataItemOf,
+    CollectionItem, CollectionItemFile, CollectionItemFileOf, Timepoint, TimepointOf,
     CollectionItemToken, mock::*, TermsAndConditionsElement, TokensRecordFile,
-    VerifiedIssuer, OtherAccountId, SupportedAccountId, MetadataItemParams, FileParams, Hasher,
-    Requester::{Account, OtherAccount}, fees::*,
+    VerifiedIssuer, OtherAccountId, OtherAccountIdOf, SupportedAccountId, SupportedAccountIdOf, MetadataItemParams, FileParams, Hasher,
+    Requester, Requester::{Account, OtherAccount}, fees::*,
+    AccountLocsMap, IdentityLocLocsMap, OtherAccountLocsMap, EthereumSignature, LocStatus, HoldReason, LegalFeeHold, DraftDeposit,
+    ExchangeRateRecord, Seal, SealVersion, LocMap, merkle, CollectionRootSnapshot, FileLocation, FileOccurrence, LocsByOwnerMap,
 };
 
 const LOC_ID: u32 = 0;
@@ -28,21 +35,28 @@ const INITIAL_BALANCE: Balance = (3 * 2000 * ONE_LGNT) + ONE_LGNT;
 const INSUFFICIENT_BALANCE: Balance = 99;
 const ACKNOWLEDGED: bool = true;
 const NOT_ACKNOWLEDGED: bool = !ACKNOWLEDGED;
+const SPONSORSHIP_EXPIRY: u64 = 100;
+// Hardhat's account #0
+const ETHEREUM_SECRET_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+const ETHEREUM_ADDRESS: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+const LEGAL_FEE_HOLD_EXPIRY: u64 = 51;
 
 #[test]
 fn it_creates_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
         assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
             owner: LOC_OWNER1,
             requester: LOC_REQUESTER,
-            metadata: vec![],
-            files: vec![],
+            metadata: BoundedVec::default(),
+            files: BoundedVec::default(),
+            status: LocStatus::Open,
             closed: false,
             loc_type: LocType::Transaction,
-            links: vec![],
+            links: BoundedVec::default(),
             void_info: None,
             replacer_of: None,
             collection_last_block_submission: None,
@@ -50,2080 +64,4201 @@ fn it_creates_loc() {
             collection_can_upload: false,
             seal: None,
             sponsorship_id: None,
+            closed_on: None,
+            rejection_reason: None,
         }));
 
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
         let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(LOC_OWNER1));
         fees.assert_balances_events(snapshot);
     });
 }
 
-fn setup_default_balances() {
-    set_balance(LOC_REQUESTER_ID, INITIAL_BALANCE);
-    set_balance(SPONSOR_ID, INITIAL_BALANCE);
-    set_balance(LOC_OWNER1, INITIAL_BALANCE);
-    set_balance(LOC_OWNER2, INITIAL_BALANCE);
-    set_balance(ISSUER_ID1, INITIAL_BALANCE);
-    set_balance(ISSUER_ID2, INITIAL_BALANCE);
-    set_balance(TREASURY_ACCOUNT_ID, INITIAL_BALANCE);
-}
-
-fn set_balance(account_id: AccountId, amount: Balance) {
-    assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), account_id, amount));
-}
-
 #[test]
-fn it_makes_existing_loc_void() {
+fn it_creates_polkadot_loc_as_draft() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
 
-        let void_info = LogionLoc::loc(LOC_ID).unwrap().void_info;
-        assert!(void_info.is_some());
-        assert!(!void_info.unwrap().replacer.is_some());
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Draft);
+        let fees = Fees::only_storage(0, 0);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_makes_existing_loc_void_and_replace_it() {
+fn it_requests_review() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_loc();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
 
-        const REPLACER_LOC_ID: u32 = OTHER_LOC_ID;
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REPLACER_LOC_ID, LOC_OWNER1));
-
-        assert_ok!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, REPLACER_LOC_ID));
-
-        let void_info = LogionLoc::loc(LOC_ID).unwrap().void_info;
-        assert!(void_info.is_some());
-        let replacer: Option<u32> = void_info.unwrap().replacer;
-        assert!(replacer.is_some());
-        assert_eq!(replacer.unwrap(), REPLACER_LOC_ID);
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
 
-        let replacer_loc = LogionLoc::loc(REPLACER_LOC_ID).unwrap();
-        assert!(replacer_loc.replacer_of.is_some());
-        assert_eq!(replacer_loc.replacer_of.unwrap(), LOC_ID)
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::ReviewRequested);
+        assert_eq!(LogionLoc::legal_fee_hold(LOC_ID), Some(LegalFeeHold {
+            payer: LOC_REQUESTER_ID,
+            amount: 2000 * ONE_LGNT,
+            expires_on: Some(LEGAL_FEE_HOLD_EXPIRY),
+        }));
+        assert_eq!(LogionLoc::legal_fee_holds_by_expiry(LEGAL_FEE_HOLD_EXPIRY).to_vec(), vec![LOC_ID]);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 2000 * ONE_LGNT);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LegalFeeHeld { 0: LOC_ID, 1: LOC_REQUESTER_ID, 2: 2000 * ONE_LGNT }));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocReviewRequested { 0: LOC_ID }));
     });
 }
 
 #[test]
-fn it_fails_making_existing_loc_void_for_unauthorized_caller() {
+fn it_holds_draft_deposit_until_accepted() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_err!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::Unauthorized);
-        let void_info = LogionLoc::loc(LOC_ID).unwrap().void_info;
-        assert!(!void_info.is_some());
+        DraftLocDeposit::set(10 * ONE_LGNT);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_eq!(LogionLoc::draft_deposit(LOC_ID), Some(DraftDeposit {
+            depositor: LOC_REQUESTER_ID,
+            amount: 10 * ONE_LGNT,
+        }));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::DraftDeposit, &LOC_REQUESTER_ID), 10 * ONE_LGNT);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::DraftDepositHeld { 0: LOC_ID, 1: LOC_REQUESTER_ID, 2: 10 * ONE_LGNT }));
+
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_eq!(LogionLoc::draft_deposit(LOC_ID), None);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::DraftDeposit, &LOC_REQUESTER_ID), 0);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::DraftDepositReturned { 0: LOC_ID, 1: LOC_REQUESTER_ID, 2: 10 * ONE_LGNT }));
     });
 }
 
 #[test]
-fn it_fails_making_existing_loc_void_for_already_void_loc() {
+fn it_fails_creating_draft_without_deposit() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_err!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::AlreadyVoid);
+        DraftLocDeposit::set(10 * ONE_LGNT);
+        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None), Error::<Test>::InsufficientFunds);
     });
 }
 
 #[test]
-fn it_fails_replacing_with_non_existent_loc() {
+fn it_returns_draft_deposit_on_cancel() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_err!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::ReplacerLocNotFound);
+        DraftLocDeposit::set(10 * ONE_LGNT);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+
+        assert_ok!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        assert_eq!(Balances::balance_on_hold(&HoldReason::DraftDeposit, &LOC_REQUESTER_ID), 0);
     });
 }
 
 #[test]
-fn it_fails_replacing_with_void_loc() {
+fn it_schedules_root_snapshot_of_accepted_collection_only() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        const REPLACER_LOC_ID: u32 = OTHER_LOC_ID;
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_err!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, REPLACER_LOC_ID), Error::<Test>::ReplacerLocAlreadyVoid);
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(COLLECTION_LAST_BLOCK), None, false, None));
+        assert!(LogionLoc::collections_by_last_block(COLLECTION_LAST_BLOCK).is_empty());
+
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_eq!(LogionLoc::collections_by_last_block(COLLECTION_LAST_BLOCK).to_vec(), vec![LOC_ID]);
     });
 }
 
 #[test]
-fn it_fails_replacing_with_loc_already_replacing_another_loc() {
+fn it_holds_legal_fee_published_by_legal_officer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        const REPLACER_LOC_ID: u32 = 2;
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REPLACER_LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, REPLACER_LOC_ID));
-        assert_err!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, REPLACER_LOC_ID), Error::<Test>::ReplacerLocAlreadyReplacing);
+        PublishedLegalFee::set(Some((LOC_OWNER1, LocType::Transaction, FiatAmount { currency: FiatCurrency::Eur, amount: 50_00 })));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 1000 * ONE_LGNT);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LegalFeeHeld { 0: LOC_ID, 1: LOC_REQUESTER_ID, 2: 1000 * ONE_LGNT }));
     });
 }
 
 #[test]
-fn it_fails_replacing_with_wrongly_typed_loc() {
+fn it_calculates_legal_fee_for_legal_officer() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        const REPLACER_LOC_ID: u32 = 2;
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REPLACER_LOC_ID, LOC_OWNER1));
-        assert_err!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, REPLACER_LOC_ID), Error::<Test>::ReplacerLocWrongType);
+        PublishedLegalFee::set(Some((LOC_OWNER1, LocType::Transaction, FiatAmount { currency: FiatCurrency::Eur, amount: 50_00 })));
+
+        assert_eq!(LogionLoc::calculate_legal_fee(LocType::Transaction, &LOC_OWNER1), 1000 * ONE_LGNT);
+        assert_eq!(LogionLoc::calculate_legal_fee(LocType::Collection, &LOC_OWNER1), 2000 * ONE_LGNT);
+        assert_eq!(LogionLoc::calculate_legal_fee(LocType::Transaction, &LOC_OWNER2), 2000 * ONE_LGNT);
     });
 }
 
 #[test]
-fn it_adds_metadata_when_caller_and_submitter_is_owner() {
+fn it_converts_legal_fee_with_currency_exchange_rate() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED));
+        let fee = FiatAmount { currency: FiatCurrency::Chf, amount: 100_00 };
+        PublishedLegalFee::set(Some((LOC_OWNER1, LocType::Transaction, fee)));
+
+        assert_eq!(LogionLoc::legal_fee_in_fiat(LocType::Transaction, &LOC_OWNER1), fee);
+        assert_eq!(LogionLoc::calculate_legal_fee(LocType::Transaction, &LOC_OWNER1), 2200 * ONE_LGNT);
+
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Chf, 250_000_000_000_000_000, 10));
+        assert_eq!(LogionLoc::calculate_legal_fee(LocType::Transaction, &LOC_OWNER1), 2500 * ONE_LGNT);
+        assert_eq!(LogionLoc::calculate_legal_fee(LocType::Transaction, &LOC_OWNER2), 2000 * ONE_LGNT);
     });
 }
 
-fn sha256(data: &Vec<u8>) -> H256 {
-    <SHA256 as Hasher<H256>>::hash(data)
-}
+#[test]
+fn it_fails_requesting_review_if_not_requester() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
 
-fn expected_metadata(metadata: MetadataItemParams<AccountId, EthereumAddress, crate::mock::Hash>, acknowledged: bool) -> MetadataItem<AccountId, EthereumAddress, crate::mock::Hash> {
-    return MetadataItem {
-        name: metadata.name,
-        value: metadata.value,
-        submitter: metadata.submitter,
-        acknowledged,
-    };
+        assert_err!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::Unauthorized);
+    });
 }
 
 #[test]
-fn it_adds_metadata_when_caller_is_owner_and_submitter_is_requester() {
+fn it_fails_requesting_review_when_insufficient_funds() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
+
+        assert_err!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::InsufficientFunds);
     });
 }
 
 #[test]
-fn it_adds_metadata_when_caller_is_requester() {
+fn it_fails_adding_metadata_when_review_requested() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
         let metadata = MetadataItemParams {
             name: sha256(&vec![1, 2, 3]),
             value: sha256(&vec![4, 5, 6]),
             submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
         };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, NOT_ACKNOWLEDGED));
+
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata), Error::<Test>::CannotMutate);
     });
 }
 
 #[test]
-fn it_acknowledges_metadata() {
+fn it_accepts_loc() {
     new_test_ext().execute_with(|| {
-        let metadata = create_loc_with_metadata_from_requester();
-        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), ACKNOWLEDGED));
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        assert_ok!(LogionLoc::accept_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Open);
+        assert!(LogionLoc::legal_fee_hold(LOC_ID).is_some());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocAccepted { 0: LOC_ID }));
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 2000 * ONE_LGNT);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_unknown_metadata() {
+fn it_withdraws_reserved_legal_fee_on_close() {
     new_test_ext().execute_with(|| {
-        create_loc_with_metadata_from_requester();
-        let name = sha256(&"unknown_metadata".as_bytes().to_vec());
-        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, name), Error::<Test>::ItemNotFound);
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_eq!(LogionLoc::legal_fee_hold(LOC_ID), None);
+        assert!(LogionLoc::legal_fee_holds_by_expiry(LEGAL_FEE_HOLD_EXPIRY).is_empty());
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 0);
+        let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(LOC_OWNER1));
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_already_acknowledged_metadata() {
+fn it_fails_accepting_draft_loc() {
     new_test_ext().execute_with(|| {
-        let metadata = create_loc_with_metadata_from_requester();
-        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()));
-        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()), Error::<Test>::ItemAlreadyAcknowledged);
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+
+        assert_err!(LogionLoc::accept_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::InvalidLocStatus);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_metadata_when_unauthorized_caller() {
+fn it_fails_accepting_loc_if_not_owner() {
     new_test_ext().execute_with(|| {
-        let metadata = create_loc_with_metadata_from_requester();
-        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, metadata.name.clone()), BadOrigin);
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        assert_err!(LogionLoc::accept_loc(RuntimeOrigin::signed(LOC_OWNER2), LOC_ID), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_to_close_loc_with_unacknowledged_metadata() {
+fn it_fails_closing_draft_loc() {
     new_test_ext().execute_with(|| {
-        create_loc_with_metadata_from_requester();
-        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::CannotCloseUnacknowledged);
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::InvalidLocStatus);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_metadata_when_loc_voided() {
+fn it_rejects_loc() {
     new_test_ext().execute_with(|| {
-        let metadata = create_loc_with_metadata_from_requester();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()), Error::<Test>::CannotMutateVoid);
-    });
-}
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+        let reason = sha256(&"Missing identity documents".as_bytes().to_vec());
 
-fn create_loc_with_metadata_from_requester() -> MetadataItemParams<AccountId, EthereumAddress, crate::mock::Hash> {
-    setup_default_balances();
-    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-    let metadata = MetadataItemParams {
-        name: sha256(&vec![1, 2, 3]),
-        value: sha256(&vec![4, 5, 6]),
-        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-    };
-    assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
-    let loc = LogionLoc::loc(LOC_ID).unwrap();
-    assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED));
-    metadata
+        assert_ok!(LogionLoc::reject_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, reason));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.status, LocStatus::Rejected);
+        assert_eq!(loc.rejection_reason, Some(reason));
+        assert_eq!(LogionLoc::legal_fee_hold(LOC_ID), None);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 0);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocRejected { 0: LOC_ID, 1: reason }));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LegalFeeReturned { 0: LOC_ID, 1: LOC_REQUESTER_ID, 2: 2000 * ONE_LGNT }));
+        let fees = Fees::only_storage(0, 0);
+        fees.assert_balances_events(snapshot);
+    });
 }
 
 #[test]
-fn it_fails_adding_metadata_for_unauthorized_caller() {
+fn it_accepts_resubmitted_rejected_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+        assert_ok!(LogionLoc::reject_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, sha256(&"Missing identity documents".as_bytes().to_vec())));
         let metadata = MetadataItemParams {
             name: sha256(&vec![1, 2, 3]),
             value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
         };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, metadata.clone()), Error::<Test>::Unauthorized);
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata));
+
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.status, LocStatus::Open);
+        assert_eq!(loc.rejection_reason, None);
     });
 }
 
 #[test]
-fn it_fails_adding_metadata_when_closed() {
+fn it_releases_legal_fee_when_voiding_loc_under_review() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_loc();
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
-        };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()), Error::<Test>::CannotMutate);
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Void);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 0);
+        let fees = Fees::only_storage(0, 0);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_metadata_when_invalid_submitter() {
+fn it_cancels_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
-        };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()), Error::<Test>::Unauthorized);
-    });
-}
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
 
-fn create_closed_loc() {
-    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_ok!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Cancelled);
+        assert!(!AccountLocsMap::<Test>::contains_key((LOC_REQUESTER_ID, LocType::Transaction, LOC_ID)));
+        assert!(!LocsByOwnerMap::<Test>::contains_key(LOC_OWNER1, LOC_ID));
+        assert_eq!(LogionLoc::legal_fee_hold(LOC_ID), None);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 0);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCancelled { 0: LOC_ID }));
+        check_no_fees(snapshot);
+    });
 }
 
 #[test]
-fn it_adds_file_when_caller_owner_and_submitter_is_owner() {
+fn it_cancels_sponsored_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
-            size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, 2000 * ONE_LGNT, None));
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
 
-        let fees = Fees::only_storage(1, file.size);
-        fees.assert_balances_events(snapshot);
+        assert_ok!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        let sponsorship = LogionLoc::sponsorship(sponsorship_id).unwrap();
+        assert_eq!(sponsorship.used_locs, 0);
+        assert_eq!(sponsorship.used_balance, 0);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &SPONSOR_ID), 0);
+        check_no_fees(snapshot);
     });
 }
 
-fn expected_file(file: &FileParams<H256, AccountId, EthereumAddress>, acknowledged: bool) -> File<H256, AccountId, EthereumAddress> {
-    return File {
-        hash: file.hash,
-        nature: file.nature.clone(),
-        submitter: file.submitter,
-        size: file.size,
-        acknowledged,
-    }
+#[test]
+fn it_keeps_expired_sponsorship_used_when_cancelling_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, 2000 * ONE_LGNT, None));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        System::set_block_number(SPONSORSHIP_EXPIRY);
+
+        assert_ok!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().used_locs, 1);
+    });
 }
 
 #[test]
-fn it_adds_file_when_caller_is_owner_and_submitter_is_requester() {
+fn it_cancels_logion_loc_as_owner() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
-        let fees = Fees::only_storage(1, file.size);
-        fees.assert_balances_events(snapshot);
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOGION_IDENTITY_LOC_ID));
+
+        assert_ok!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Cancelled);
+        assert!(!IdentityLocLocsMap::<Test>::contains_key((LOGION_IDENTITY_LOC_ID, LocType::Transaction, LOC_ID)));
+        assert!(!LocsByOwnerMap::<Test>::contains_key(LOC_OWNER1, LOC_ID));
     });
 }
 
 #[test]
-fn it_adds_file_when_caller_is_requester() {
+fn it_cancels_loc_with_ethereum_signature() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
-        let fees = Fees::only_storage(1, file.size);
-        fees.assert_balances_events(snapshot);
+        let requester = OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Other(requester), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_other_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester, Some(sponsorship_id)));
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"cancel_loc", &LOC_ID, 0));
+
+        assert_ok!(LogionLoc::cancel_loc_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, SupportedAccountId::Other(requester), 0, signature));
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Cancelled);
+        assert!(!OtherAccountLocsMap::<Test>::contains_key((requester, LocType::Transaction, LOC_ID)));
+        assert!(!LocsByOwnerMap::<Test>::contains_key(LOC_OWNER1, LOC_ID));
+        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().used_locs, 0);
+        assert_eq!(LogionLoc::ethereum_nonce(H160::from_str(ETHEREUM_ADDRESS).unwrap()), 1);
     });
 }
 
 #[test]
-fn it_acknowledges_file() {
+fn it_fails_cancelling_loc_with_ethereum_signature_of_other_account() {
     new_test_ext().execute_with(|| {
-        let file = create_loc_with_file_from_requester();
-        assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
+        setup_default_balances();
+        let requester = OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        assert_ok!(LogionLoc::create_other_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester, None));
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"cancel_loc", &OTHER_LOC_ID, 0));
+
+        assert_err!(LogionLoc::cancel_loc_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, SupportedAccountId::Other(requester), 0, signature), Error::<Test>::InvalidEthereumSignature);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_unknown_file() {
+fn it_fails_cancelling_loc_if_not_requester() {
     new_test_ext().execute_with(|| {
-        create_loc_with_file_from_requester();
-        let hash = BlakeTwo256::hash_of(&"unknown_hash".as_bytes().to_vec());
-        assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, hash), Error::<Test>::ItemNotFound);
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_err!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_already_acknowledged_file() {
+fn it_fails_cancelling_loc_under_review() {
     new_test_ext().execute_with(|| {
-        let file = create_loc_with_file_from_requester();
-        assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash.clone()));
-        assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash.clone()), Error::<Test>::ItemAlreadyAcknowledged);
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        assert_err!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::InvalidLocStatus);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_file_when_unauthorized_caller() {
+fn it_fails_cancelling_identity_loc() {
     new_test_ext().execute_with(|| {
-        let file = create_loc_with_file_from_requester();
-        assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, file.hash.clone()), BadOrigin);
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_err!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::CannotCancel);
     });
 }
 
 #[test]
-fn it_fails_to_close_loc_with_unacknowledged_file() {
+fn it_fails_cancelling_loc_acknowledged_by_owner() {
     new_test_ext().execute_with(|| {
-        create_loc_with_file_from_requester();
-        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::CannotCloseUnacknowledged);
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name));
+
+        assert_err!(LogionLoc::cancel_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::CannotCancel);
     });
 }
 
 #[test]
-fn it_fails_to_acknowledge_file_when_loc_voided() {
+fn it_returns_legal_fee_on_hold_after_timeout() {
     new_test_ext().execute_with(|| {
-        let file = create_loc_with_file_from_requester();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash.clone()), Error::<Test>::CannotMutateVoid);
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+
+        let weight = LogionLoc::on_initialize(LEGAL_FEE_HOLD_EXPIRY);
+
+        assert_eq!(weight, <() as WeightInfo>::expire_sponsorships(0)
+            .saturating_add(<() as WeightInfo>::expire_legal_fee_holds(1))
+            .saturating_add(<() as WeightInfo>::snapshot_collection_roots(0)));
+        assert_eq!(LogionLoc::legal_fee_hold(LOC_ID), None);
+        assert!(LogionLoc::legal_fee_holds_by_expiry(LEGAL_FEE_HOLD_EXPIRY).is_empty());
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 0);
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Draft);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LegalFeeReturned { 0: LOC_ID, 1: LOC_REQUESTER_ID, 2: 2000 * ONE_LGNT }));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocReviewExpired { 0: LOC_ID }));
+        assert_err!(LogionLoc::accept_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::InvalidLocStatus);
+        check_no_fees(snapshot);
     });
 }
 
-fn create_loc_with_file_from_requester() -> FileParams<H256, AccountId, EthereumAddress> {
-    setup_default_balances();
-    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-    let file = FileParams {
-        hash: sha256(&"test".as_bytes().to_vec()),
-        nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        size: FILE_SIZE,
-    };
-    assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
-    let loc = LogionLoc::loc(LOC_ID).unwrap();
-    assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
-    file
-}
-
 #[test]
-fn it_fails_adding_file_for_unauthorized_caller() {
+fn it_keeps_legal_fee_of_accepted_loc_on_hold_after_timeout() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
-            size: FILE_SIZE,
-        };
-        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, file.clone()), Error::<Test>::Unauthorized);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_eq!(LogionLoc::legal_fee_hold(LOC_ID).unwrap().expires_on, None);
+        assert!(LogionLoc::legal_fee_holds_by_expiry(LEGAL_FEE_HOLD_EXPIRY).is_empty());
+
+        LogionLoc::on_initialize(LEGAL_FEE_HOLD_EXPIRY);
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Open);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &LOC_REQUESTER_ID), 2000 * ONE_LGNT);
     });
 }
 
 #[test]
-fn it_fails_adding_file_when_insufficient_funds() {
+fn it_returns_sponsored_legal_fee_on_hold_after_timeout() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-            size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()), Error::<Test>::InsufficientFunds);
-        check_no_fees(snapshot);
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, None));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID));
+        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().used_balance, 2000 * ONE_LGNT);
+
+        LogionLoc::on_initialize(LEGAL_FEE_HOLD_EXPIRY);
+
+        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().used_balance, 0);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &SPONSOR_ID), 0);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LegalFeeReturned { 0: LOC_ID, 1: SPONSOR_ID, 2: 2000 * ONE_LGNT }));
     });
 }
 
-fn check_no_fees(previous_balances: BalancesSnapshot) {
-    let current_balances = BalancesSnapshot::take(previous_balances.payer_account, previous_balances.legal_officer_account);
-    let balances_delta = current_balances.delta_since(&previous_balances);
+fn review_and_accept(requester: AccountId, loc_id: u32, legal_officer: AccountId) {
+    assert_ok!(LogionLoc::request_review(RuntimeOrigin::signed(requester), loc_id));
+    assert_ok!(LogionLoc::accept_loc(RuntimeOrigin::signed(legal_officer), loc_id));
+}
 
-    assert_eq!(balances_delta.total_credited(), 0);
-    assert_eq!(balances_delta.total_debited(), 0);
+fn setup_default_balances() {
+    set_balance(LOC_REQUESTER_ID, INITIAL_BALANCE);
+    set_balance(SPONSOR_ID, INITIAL_BALANCE);
+    set_balance(LOC_OWNER1, INITIAL_BALANCE);
+    set_balance(LOC_OWNER2, INITIAL_BALANCE);
+    set_balance(ISSUER_ID1, INITIAL_BALANCE);
+    set_balance(ISSUER_ID2, INITIAL_BALANCE);
+    set_balance(TREASURY_ACCOUNT_ID, INITIAL_BALANCE);
 }
 
-#[test]
-fn it_fails_adding_file_when_closed() {
-    new_test_ext().execute_with(|| {
-        setup_default_balances();
-        create_closed_loc();
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
-            size: FILE_SIZE,
-        };
-        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()), Error::<Test>::CannotMutate);
-    });
+fn set_balance(account_id: AccountId, amount: Balance) {
+    assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), account_id, amount));
 }
 
 #[test]
-fn it_adds_link() {
+fn it_makes_existing_loc_void() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-        let link = LocLink {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-        };
-        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.links[0], link);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let void_info = LogionLoc::loc(LOC_ID).unwrap().void_info;
+        assert!(void_info.is_some());
+        let void_info = void_info.unwrap();
+        assert!(!void_info.replacer.is_some());
+        assert_eq!(void_info.voided_on, Some(current_timepoint()));
     });
 }
 
 #[test]
-fn it_fails_adding_link_for_unauthorized_caller() {
+fn it_makes_existing_loc_void_and_replace_it() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-        let link = LocLink {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-        };
-        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()), Error::<Test>::Unauthorized);
+        create_closed_loc();
+
+        const REPLACER_LOC_ID: u32 = OTHER_LOC_ID;
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REPLACER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, REPLACER_LOC_ID, LOC_OWNER1);
+
+        assert_ok!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, REPLACER_LOC_ID));
+
+        let void_info = LogionLoc::loc(LOC_ID).unwrap().void_info;
+        assert!(void_info.is_some());
+        let replacer: Option<u32> = void_info.unwrap().replacer;
+        assert!(replacer.is_some());
+        assert_eq!(replacer.unwrap(), REPLACER_LOC_ID);
+
+        let replacer_loc = LogionLoc::loc(REPLACER_LOC_ID).unwrap();
+        assert!(replacer_loc.replacer_of.is_some());
+        assert_eq!(replacer_loc.replacer_of.unwrap(), LOC_ID)
     });
 }
 
 #[test]
-fn it_fails_adding_link_when_closed() {
+fn it_fails_making_existing_loc_void_for_unauthorized_caller() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_loc();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-        let link = LocLink {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-        };
-        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()), Error::<Test>::CannotMutate);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::Unauthorized);
+        let void_info = LogionLoc::loc(LOC_ID).unwrap().void_info;
+        assert!(!void_info.is_some());
     });
 }
 
 #[test]
-fn it_fails_adding_wrong_link() {
+fn it_fails_making_existing_loc_void_for_already_void_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let link = LocLink {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
-        };
-        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()), Error::<Test>::LinkedLocNotFound);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_err!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::AlreadyVoid);
     });
 }
 
 #[test]
-fn it_closes_loc() {
+fn it_fails_replacing_with_non_existent_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert!(loc.closed);
-        assert!(loc.seal.is_none());
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::ReplacerLocNotFound);
     });
 }
 
 #[test]
-fn it_fails_closing_loc_for_unauthorized_caller() {
+fn it_fails_replacing_with_void_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::Unauthorized);
+        const REPLACER_LOC_ID: u32 = OTHER_LOC_ID;
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, REPLACER_LOC_ID), Error::<Test>::ReplacerLocAlreadyVoid);
     });
 }
 
 #[test]
-fn it_fails_closing_loc_for_already_closed() {
+fn it_fails_replacing_with_loc_already_replacing_another_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_loc();
-        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::AlreadyClosed);
+        const REPLACER_LOC_ID: u32 = 2;
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REPLACER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, REPLACER_LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, REPLACER_LOC_ID));
+        assert_err!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, REPLACER_LOC_ID), Error::<Test>::ReplacerLocAlreadyReplacing);
     });
 }
 
 #[test]
-fn it_links_locs_to_account() {
+fn it_fails_replacing_with_wrongly_typed_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-        assert!(LogionLoc::account_locs(LOC_REQUESTER_ID).is_some());
-        assert!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap().len() == 2);
-        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap()[0], LOC_ID);
-        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap()[1], OTHER_LOC_ID);
+        const REPLACER_LOC_ID: u32 = 2;
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), REPLACER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, REPLACER_LOC_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::make_void_and_replace(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, REPLACER_LOC_ID), Error::<Test>::ReplacerLocWrongType);
     });
 }
 
 #[test]
-fn it_fails_creating_loc_with_non_legal_officer() {
+fn it_adds_metadata_when_caller_and_submitter_is_owner() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_err!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID), Error::<Test>::Unauthorized);
-        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID), Error::<Test>::Unauthorized);
-        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, None, Some(10), false), Error::<Test>::Unauthorized);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED));
     });
 }
 
 #[test]
-fn it_detects_existing_identity_loc() {
+fn it_refunds_weight_when_adding_metadata() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
 
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER2));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER2), OTHER_LOC_ID));
+        let post_info = LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata).unwrap();
 
-        let legal_officers = Vec::from([LOC_OWNER1, LOC_OWNER2]);
-        assert!(LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers));
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::add_metadata(0)));
     });
 }
 
+fn sha256(data: &Vec<u8>) -> H256 {
+    <SHA256 as Hasher<H256>>::hash(data)
+}
+
+fn expected_metadata(metadata: MetadataItemParams<AccountId, OtherAccountIdOf<Test>, crate::mock::Hash>, acknowledged: bool) -> MetadataItemOf<Test> {
+    return MetadataItem {
+        name: metadata.name,
+        value: metadata.value,
+        submitter: metadata.submitter,
+        acknowledged,
+        added_on: Some(current_timepoint()),
+        acknowledged_on: if acknowledged { Some(current_timepoint()) } else { None },
+    };
+}
+
+fn current_timepoint() -> TimepointOf<Test> {
+    let block_number = System::block_number();
+    Timepoint {
+        block_number,
+        moment: Some(block_number * MILLISECS_PER_BLOCK),
+    }
+}
+
 #[test]
-fn it_detects_valid_loc_with_owner() {
+fn it_adds_metadata_when_caller_is_owner_and_submitter_is_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER1), true);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED));
     });
 }
 
 #[test]
-fn it_detects_non_existing_loc_as_invalid() {
+fn it_adds_metadata_when_caller_is_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER1), false);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::MetadataItemAdded { 0: LOC_ID, 1: metadata.name, 2: metadata.submitter }));
     });
 }
 
 #[test]
-fn it_detects_open_loc_as_invalid() {
+fn it_acknowledges_metadata() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER1), false);
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), ACKNOWLEDGED));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::MetadataItemAcknowledged { 0: LOC_ID, 1: metadata.name, 2: metadata.submitter }));
     });
 }
 
 #[test]
-fn it_detects_void_loc_as_invalid() {
+fn it_fails_to_acknowledge_unknown_metadata() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER1), false);
+        create_loc_with_metadata_from_requester();
+        let name = sha256(&"unknown_metadata".as_bytes().to_vec());
+        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, name), Error::<Test>::ItemNotFound);
     });
 }
 
 #[test]
-fn it_detects_loc_with_wrong_owner_as_invalid() {
+fn it_fails_to_acknowledge_already_acknowledged_metadata() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER2), false);
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()));
+        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()), Error::<Test>::ItemAlreadyAcknowledged);
     });
 }
 
 #[test]
-fn it_creates_logion_identity_loc() {
+fn it_fails_to_acknowledge_metadata_when_unauthorized_caller() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
-
-        assert!(LogionLoc::loc(LOGION_IDENTITY_LOC_ID).is_some());
-        assert!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).is_none());
-
-        check_no_fees(snapshot);
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, metadata.name.clone()), BadOrigin);
     });
 }
 
 #[test]
-fn it_creates_and_links_logion_locs_to_identity_loc() {
+fn it_fails_to_close_loc_with_unacknowledged_metadata() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
-
-        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOGION_IDENTITY_LOC_ID));
-        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOGION_IDENTITY_LOC_ID));
-
-        assert!(LogionLoc::loc(LOC_ID).is_some());
-        assert!(LogionLoc::loc(OTHER_LOC_ID).is_some());
-        assert!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).is_some());
-        assert!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).unwrap().len() == 2);
-        assert_eq!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).unwrap()[0], LOC_ID);
-        assert_eq!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).unwrap()[1], OTHER_LOC_ID);
-
-        check_no_fees(snapshot);
+        create_loc_with_metadata_from_requester();
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::CannotCloseUnacknowledged);
     });
 }
 
 #[test]
-fn it_fails_creating_logion_loc_with_polkadot_identity_loc() {
+fn it_fails_to_acknowledge_metadata_when_loc_voided() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_err!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name.clone()), Error::<Test>::CannotMutateVoid);
     });
 }
 
+fn create_loc_with_metadata_from_requester() -> MetadataItemParams<AccountId, OtherAccountIdOf<Test>, crate::mock::Hash> {
+    setup_default_balances();
+    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+    review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+    let metadata = MetadataItemParams {
+        name: sha256(&vec![1, 2, 3]),
+        value: sha256(&vec![4, 5, 6]),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+    };
+    assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()));
+    let loc = LogionLoc::loc(LOC_ID).unwrap();
+    assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED));
+    metadata
+}
+
 #[test]
-fn it_fails_creating_logion_loc_with_polkadot_transaction_loc() {
+fn it_fails_adding_metadata_for_unauthorized_caller() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, metadata.clone()), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_creating_logion_loc_with_logion_transaction_loc() {
+fn it_fails_adding_metadata_when_closed() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
-        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOGION_IDENTITY_LOC_ID));
-
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+        create_closed_loc();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()), Error::<Test>::CannotMutate);
     });
 }
 
 #[test]
-fn it_fails_creating_logion_loc_with_open_logion_identity_loc() {
+fn it_fails_adding_metadata_when_invalid_submitter() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
-
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOGION_IDENTITY_LOC_ID), Error::<Test>::UnexpectedRequester);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone()), Error::<Test>::Unauthorized);
     });
 }
 
+fn create_closed_loc() {
+    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+    review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+}
+
 #[test]
-fn it_fails_creating_logion_loc_with_closed_void_logion_identity_loc() {
+fn it_adds_file_when_caller_owner_and_submitter_is_owner() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
 
-        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOGION_IDENTITY_LOC_ID), Error::<Test>::UnexpectedRequester);
+        let fees = Fees::only_storage(1, file.size);
+        fees.assert_balances_events(snapshot);
     });
 }
 
+fn expected_file(file: &FileParams<H256, AccountId, OtherAccountIdOf<Test>>, acknowledged: bool) -> FileOf<Test> {
+    return File {
+        hash: file.hash,
+        nature: file.nature.clone(),
+        submitter: file.submitter,
+        size: file.size,
+        acknowledged,
+        added_on: Some(current_timepoint()),
+        acknowledged_on: if acknowledged { Some(current_timepoint()) } else { None },
+    }
+}
+
+fn expected_link(link: &LocLink<u32, H256>) -> LocLinkItemOf<Test> {
+    return LocLinkItem {
+        id: link.id,
+        nature: link.nature,
+        added_on: Some(current_timepoint()),
+    }
+}
+
 #[test]
-fn it_creates_collection_loc() {
+fn it_adds_file_when_caller_is_owner_and_submitter_is_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
-            owner: LOC_OWNER1,
-            requester: LOC_REQUESTER,
-            metadata: vec![],
-            files: vec![],
-            closed: false,
-            loc_type: LocType::Collection,
-            links: vec![],
-            void_info: None,
-            replacer_of: None,
-            collection_last_block_submission: None,
-            collection_max_size: Some(10),
-            collection_can_upload: false,
-            seal: None,
-            sponsorship_id: None,
-        }));
-
-        let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(LOC_OWNER1));
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
+        let fees = Fees::only_storage(1, file.size);
         fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_creating_collection_loc_without_limit() {
+fn it_adds_file_when_caller_is_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, None, false), Error::<Test>::CollectionHasNoLimit);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
+        let fees = Fees::only_storage(1, file.size);
+        fees.assert_balances_events(snapshot);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FileAdded { 0: LOC_ID, 1: file.hash, 2: file.submitter }));
     });
 }
 
 #[test]
-fn it_fails_adding_item_to_open_collection_loc() {
+fn it_acknowledges_file() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+        let file = create_loc_with_file_from_requester();
+        assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FileAcknowledged { 0: LOC_ID, 1: file.hash, 2: file.submitter }));
     });
 }
 
 #[test]
-fn it_adds_item_to_closed_collection_loc() {
+fn it_fails_to_acknowledge_unknown_file() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()));
-        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
-            description: collection_item_description,
-            files: vec![],
-            token: None,
-            restricted_delivery: false,
-            terms_and_conditions: vec![],
-        }));
-        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+        create_loc_with_file_from_requester();
+        let hash = BlakeTwo256::hash_of(&"unknown_hash".as_bytes().to_vec());
+        assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, hash), Error::<Test>::ItemNotFound);
     });
 }
 
 #[test]
-fn it_fails_to_item_with_terms_and_conditions_when_non_existent_tc_loc() {
+fn it_fails_to_acknowledge_already_acknowledged_file() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let terms_and_conditions_details = "ITEM-A, ITEM-B".as_bytes().to_vec();
-        let terms_and_conditions = vec![TermsAndConditionsElement {
-            tc_type: sha256(&"Logion".as_bytes().to_vec()),
-            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
-            details: sha256(&terms_and_conditions_details),
-        }];
-        assert_err!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocNotFound);
+        let file = create_loc_with_file_from_requester();
+        assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash.clone()));
+        assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash.clone()), Error::<Test>::ItemAlreadyAcknowledged);
     });
 }
 
 #[test]
-fn it_fails_to_item_with_terms_and_conditions_when_open_tc_loc() {
+fn it_fails_to_acknowledge_file_when_unauthorized_caller() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let terms_and_conditions_details = sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec());
-        let terms_and_conditions = vec![TermsAndConditionsElement {
-            tc_type: sha256(&"Logion".as_bytes().to_vec()),
-            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
-            details: terms_and_conditions_details.clone(),
-        }];
-        assert_err!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocNotClosed);
+        let file = create_loc_with_file_from_requester();
+        assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, file.hash.clone()), BadOrigin);
     });
 }
 
 #[test]
-fn it_fails_to_item_with_terms_and_conditions_when_void_tc_loc() {
+fn it_fails_to_close_loc_with_unacknowledged_file() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOGION_CLASSIFICATION_LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let terms_and_conditions_details = sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec());
-        let terms_and_conditions = vec![TermsAndConditionsElement {
-            tc_type: sha256(&"Logion".as_bytes().to_vec()),
-            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
-            details: terms_and_conditions_details,
-        }];
-        assert_err!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocVoid);
+        create_loc_with_file_from_requester();
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::CannotCloseUnacknowledged);
     });
 }
 
 #[test]
-fn it_adds_item_with_terms_and_conditions_to_closed_collection_loc() {
+fn it_fails_to_acknowledge_file_when_loc_voided() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_CLASSIFICATION_LOC_ID));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), ADDITIONAL_TC_LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), ADDITIONAL_TC_LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let tc1 = TermsAndConditionsElement {
-            tc_type: sha256(&"Logion".as_bytes().to_vec()),
-            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
-            details: sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec()),
-        };
-        let tc2 = TermsAndConditionsElement {
-            tc_type: sha256(&"Specific".as_bytes().to_vec()),
-            tc_loc: ADDITIONAL_TC_LOC_ID,
-            details: sha256(&"Some more details".as_bytes().to_vec()),
-        };
-        let terms_and_conditions = vec![tc1, tc2];
-        assert_ok!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions.clone()));
-        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
-            description: collection_item_description,
-            files: vec![],
-            token: None,
-            restricted_delivery: false,
-            terms_and_conditions: terms_and_conditions.clone(),
-        }));
-        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+        let file = create_loc_with_file_from_requester();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_err!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash.clone()), Error::<Test>::CannotMutateVoid);
     });
 }
 
+fn create_loc_with_file_from_requester() -> FileParams<H256, AccountId, OtherAccountIdOf<Test>> {
+    setup_default_balances();
+    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+    review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+    let file = FileParams {
+        hash: sha256(&"test".as_bytes().to_vec()),
+        nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+        submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        size: FILE_SIZE,
+    };
+    assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+    let loc = LogionLoc::loc(LOC_ID).unwrap();
+    assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
+    file
+}
+
 #[test]
-fn it_fails_adding_item_to_collection_loc_if_not_requester() {
+fn it_fails_adding_file_for_unauthorized_caller() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+            size: FILE_SIZE,
+        };
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, file.clone()), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_adding_item_if_duplicate_key() {
+fn it_fails_adding_file_when_insufficient_funds() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionItemAlreadyExists);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()), Error::<Test>::InsufficientFunds);
+        check_no_fees(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_item_if_size_limit_reached() {
+fn it_fails_adding_file_when_fee_would_reap_payer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
-        let collection_item_id2 = BlakeTwo256::hash_of(&"item-id2".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id2, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionLimitsReached);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        set_balance(LOC_REQUESTER_ID, Fees::storage_fees(1, FILE_SIZE));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()), Error::<Test>::InsufficientFunds);
+        check_no_fees(snapshot);
     });
 }
 
-#[test]
-fn it_fails_adding_item_if_block_limit_reached() {
-    let current_block: u64 = 10;
-    new_test_ext_at_block(current_block).execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(current_block - 1), None, false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+fn check_no_fees(previous_balances: BalancesSnapshot) {
+    let current_balances = BalancesSnapshot::take(previous_balances.payer_account, previous_balances.legal_officer_account);
+    let balances_delta = current_balances.delta_since(&previous_balances);
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionLimitsReached);
-    });
+    assert_eq!(balances_delta.total_credited(), 0);
+    assert_eq!(balances_delta.total_debited(), 0);
 }
 
 #[test]
-fn it_fails_adding_item_if_collection_void() {
+fn it_fails_adding_file_when_closed() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), false));
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+        create_closed_loc();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+            size: FILE_SIZE,
+        };
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()), Error::<Test>::CannotMutate);
     });
 }
 
 #[test]
-fn it_fails_adding_item_if_files_attached_but_upload_not_enabled() {
+fn it_adds_link() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), false));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: 123456,
-        }];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::CannotUpload);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        let link = LocLink {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+        };
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.links[0], expected_link(&link));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LinkAdded { 0: LOC_ID, 1: OTHER_LOC_ID, 2: LOC_OWNER1 }));
     });
 }
 
 #[test]
-fn it_adds_item_if_no_files_attached_and_upload_enabled() {
+fn it_fails_adding_link_for_unauthorized_caller() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        let link = LocLink {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+        };
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, link.clone()), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_adds_item_with_one_file_attached() {
+fn it_fails_adding_link_when_closed() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: FILE_SIZE,
-        }];
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
-        let fees = Fees::only_storage(1, FILE_SIZE);
-        fees.assert_balances_events(snapshot);
+        create_closed_loc();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        let link = LocLink {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+        };
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()), Error::<Test>::CannotMutate);
     });
 }
 
 #[test]
-fn it_fails_adding_item_with_insufficient_balance() {
+fn it_fails_adding_wrong_link() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: FILE_SIZE,
-        }];
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::InsufficientFunds);
-        check_no_fees(snapshot);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let link = LocLink {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+        };
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()), Error::<Test>::LinkedLocNotFound);
     });
 }
 
 #[test]
-fn it_adds_item_with_token() {
+fn it_deletes_metadata_when_caller_is_submitter() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: 123456,
-        }];
-        let collection_item_token = CollectionItemToken {
-            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
-            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
-            token_issuance: 2,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), Some(collection_item_token), true, Vec::new()));
-        let fees = Fees {
-            storage_fees: Fees::storage_fees(1, collection_item_files[0].size),
-            legal_fees: 0,
-            legal_fee_beneficiary: None,
-            certificate_fees: 8_000_000_000_000_000,
-        };
-        fees.assert_balances_events(snapshot);
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.name));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert!(loc.metadata.is_empty());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::MetadataItemDeleted { 0: LOC_ID, 1: metadata.name }));
     });
 }
 
 #[test]
-fn it_fails_adding_item_with_missing_token() {
+fn it_deletes_acknowledged_metadata_when_caller_is_owner() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: 123456,
-        }];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, true, Vec::new()), Error::<Test>::MissingToken);
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name));
+        assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert!(loc.metadata.is_empty());
     });
 }
 
 #[test]
-fn it_fails_adding_item_with_missing_files() {
+fn it_fails_deleting_acknowledged_metadata_when_caller_is_submitter() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![];
-        let collection_item_token = CollectionItemToken {
-            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
-            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
-            token_issuance: 1,
-        };
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, Some(collection_item_token), true, Vec::new()), Error::<Test>::MissingFiles);
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::acknowledge_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name));
+        assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.name), Error::<Test>::ItemAlreadyAcknowledged);
     });
 }
 
 #[test]
-fn it_adds_item_with_two_files_attached() {
+fn it_fails_deleting_metadata_for_unauthorized_caller() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), LOC_ID, metadata.name), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_fails_deleting_unknown_metadata() {
+    new_test_ext().execute_with(|| {
+        create_loc_with_metadata_from_requester();
+        let name = sha256(&"unknown_metadata".as_bytes().to_vec());
+        assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, name), Error::<Test>::ItemNotFound);
+    });
+}
+
+#[test]
+fn it_fails_deleting_metadata_when_loc_voided() {
+    new_test_ext().execute_with(|| {
+        let metadata = create_loc_with_metadata_from_requester();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_err!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name), Error::<Test>::CannotMutateVoid);
+    });
+}
+
+#[test]
+fn it_deletes_file_without_refund() {
+    new_test_ext().execute_with(|| {
+        let file = create_loc_with_file_from_requester();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.hash));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert!(loc.files.is_empty());
+        check_no_fees(snapshot);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::FileDeleted { 0: LOC_ID, 1: file.hash }));
+    });
+}
+
+#[test]
+fn it_fails_deleting_acknowledged_file_when_caller_is_submitter() {
+    new_test_ext().execute_with(|| {
+        let file = create_loc_with_file_from_requester();
+        assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash));
+        assert_err!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.hash), Error::<Test>::ItemAlreadyAcknowledged);
+        assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash));
+    });
+}
+
+#[test]
+fn it_fails_deleting_file_when_closed() {
+    new_test_ext().execute_with(|| {
+        let file = create_loc_with_file_from_requester();
+        assert_ok!(LogionLoc::acknowledge_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_err!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.hash), Error::<Test>::CannotMutate);
+    });
+}
+
+#[test]
+fn it_deletes_link() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        let link = LocLink {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link-nature".as_bytes().to_vec()),
+        };
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link.clone()));
+        assert_err!(LogionLoc::delete_link(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, OTHER_LOC_ID), Error::<Test>::Unauthorized);
+        assert_ok!(LogionLoc::delete_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert!(loc.links.is_empty());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LinkDeleted { 0: LOC_ID, 1: OTHER_LOC_ID }));
+        assert_err!(LogionLoc::delete_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::ItemNotFound);
+    });
+}
+
+#[test]
+fn it_fails_adding_metadata_when_too_many() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        for i in 0..MaxLocMetadata::get() {
+            let metadata = MetadataItemParams {
+                name: sha256(&i.to_string().as_bytes().to_vec()),
+                value: sha256(&vec![4, 5, 6]),
+                submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+            };
+            assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata));
+        }
+        let metadata = MetadataItemParams {
+            name: sha256(&"one too many".as_bytes().to_vec()),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata), Error::<Test>::LocMetadataTooMuchData);
+    });
+}
+
+#[test]
+fn it_fails_adding_file_when_too_many() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        for i in 0..MaxLocFiles::get() {
+            let file = FileParams {
+                hash: sha256(&i.to_string().as_bytes().to_vec()),
+                nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+                submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+                size: FILE_SIZE,
+            };
+            assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file));
+        }
+        let file = FileParams {
+            hash: sha256(&"one too many".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file), Error::<Test>::LocFilesTooMuchData);
+        check_no_fees(snapshot);
+    });
+}
+
+#[test]
+fn it_closes_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.status, LocStatus::Closed);
+        assert!(loc.seal.is_none());
+        assert_eq!(loc.closed_on, Some(current_timepoint()));
+    });
+}
+
+#[test]
+fn it_records_timepoint_without_moment_when_no_timestamp() {
+    new_test_ext_at_block(0).execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.closed_on, Some(Timepoint { block_number: 0, moment: None }));
+    });
+}
+
+#[test]
+fn it_fails_closing_loc_for_unauthorized_caller() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_fails_closing_loc_for_already_closed() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_loc();
+        assert_err!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID), Error::<Test>::AlreadyClosed);
+    });
+}
+
+#[test]
+fn it_links_locs_to_account() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        assert!(AccountLocsMap::<Test>::contains_key((LOC_REQUESTER_ID, LocType::Transaction, LOC_ID)));
+        assert!(AccountLocsMap::<Test>::contains_key((LOC_REQUESTER_ID, LocType::Identity, OTHER_LOC_ID)));
+        assert!(!AccountLocsMap::<Test>::contains_key((LOC_REQUESTER_ID, LocType::Identity, LOC_ID)));
+        let mut account_locs = LogionLoc::account_locs(LOC_REQUESTER_ID).unwrap();
+        account_locs.sort();
+        assert_eq!(account_locs, vec![LOC_ID, OTHER_LOC_ID]);
+    });
+}
+
+#[test]
+fn it_fails_creating_loc_with_non_legal_officer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_err!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, None), Error::<Test>::Unauthorized);
+        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, None), Error::<Test>::Unauthorized);
+        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_REQUESTER_ID, None, Some(10), false, None), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_detects_existing_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER2, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER2);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER2), OTHER_LOC_ID));
+
+        let legal_officers = Vec::from([LOC_OWNER1, LOC_OWNER2]);
+        assert!(LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers));
+    });
+}
+
+#[test]
+fn it_detects_void_closed_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER2, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER2);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER2), OTHER_LOC_ID));
+
+        let legal_officers = Vec::from([LOC_OWNER1, LOC_OWNER2]);
+        assert!(LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers));
+    });
+}
+
+#[test]
+fn it_detects_void_open_identity_loc_as_missing() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER2, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER2);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER2), OTHER_LOC_ID));
+
+        let legal_officers = Vec::from([LOC_OWNER1, LOC_OWNER2]);
+        assert!(!LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers));
+    });
+}
+
+#[test]
+fn it_detects_missing_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER2, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER2);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER2), OTHER_LOC_ID));
+
+        let legal_officers = Vec::from([LOC_OWNER1, LOC_OWNER2]);
+        assert!(!LogionLoc::has_closed_identity_locs(&LOC_REQUESTER_ID, &legal_officers));
+    });
+}
+
+#[test]
+fn it_detects_valid_loc_with_owner() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER1), true);
+    });
+}
+
+#[test]
+fn it_detects_non_existing_loc_as_invalid() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER1), false);
+    });
+}
+
+#[test]
+fn it_detects_open_loc_as_invalid() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER1), false);
+    });
+}
+
+#[test]
+fn it_detects_void_loc_as_invalid() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER1), false);
+    });
+}
+
+#[test]
+fn it_detects_loc_with_wrong_owner_as_invalid() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_eq!(LogionLoc::loc_valid_with_owner(&LOC_ID, &LOC_OWNER2), false);
+    });
+}
+
+#[test]
+fn it_creates_logion_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+
+        assert!(LogionLoc::loc(LOGION_IDENTITY_LOC_ID).is_some());
+        assert_eq!(LogionLoc::get_locs_by_requester(&Requester::Loc(LOGION_IDENTITY_LOC_ID), None, 10), vec![]);
+        assert!(LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).is_none());
+
+        check_no_fees(snapshot);
+    });
+}
+
+#[test]
+fn it_creates_and_links_logion_locs_to_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+
+        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOGION_IDENTITY_LOC_ID));
+
+        assert!(LogionLoc::loc(LOC_ID).is_some());
+        assert!(LogionLoc::loc(OTHER_LOC_ID).is_some());
+        assert!(IdentityLocLocsMap::<Test>::contains_key((LOGION_IDENTITY_LOC_ID, LocType::Transaction, LOC_ID)));
+        assert!(IdentityLocLocsMap::<Test>::contains_key((LOGION_IDENTITY_LOC_ID, LocType::Transaction, OTHER_LOC_ID)));
+        let mut identity_loc_locs = LogionLoc::identity_loc_locs(LOGION_IDENTITY_LOC_ID).unwrap();
+        identity_loc_locs.sort();
+        assert_eq!(identity_loc_locs, vec![LOC_ID, OTHER_LOC_ID]);
+
+        check_no_fees(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_creating_logion_loc_with_polkadot_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+    });
+}
+
+#[test]
+fn it_fails_creating_logion_loc_with_polkadot_transaction_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+    });
+}
+
+#[test]
+fn it_fails_creating_logion_loc_with_logion_transaction_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, LOGION_IDENTITY_LOC_ID));
+
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OTHER_LOC_ID), Error::<Test>::UnexpectedRequester);
+    });
+}
+
+#[test]
+fn it_fails_creating_logion_loc_with_open_logion_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOGION_IDENTITY_LOC_ID), Error::<Test>::UnexpectedRequester);
+    });
+}
+
+#[test]
+fn it_fails_creating_logion_loc_with_closed_void_logion_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOGION_IDENTITY_LOC_ID));
+
+        assert_err!(LogionLoc::create_logion_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, LOGION_IDENTITY_LOC_ID), Error::<Test>::UnexpectedRequester);
+    });
+}
+
+#[test]
+fn it_creates_collection_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
+            owner: LOC_OWNER1,
+            requester: LOC_REQUESTER,
+            metadata: BoundedVec::default(),
+            files: BoundedVec::default(),
+            status: LocStatus::Open,
+            closed: false,
+            loc_type: LocType::Collection,
+            links: BoundedVec::default(),
+            void_info: None,
+            replacer_of: None,
+            collection_last_block_submission: None,
+            collection_max_size: Some(10),
+            collection_can_upload: false,
+            seal: None,
+            sponsorship_id: None,
+            closed_on: None,
+            rejection_reason: None,
+        }));
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(LOC_OWNER1));
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_creating_collection_loc_without_limit() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, None, false, None), Error::<Test>::CollectionHasNoLimit);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_to_open_collection_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+    });
+}
+
+#[test]
+fn it_adds_item_to_closed_collection_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()));
+        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
+            description: collection_item_description,
+            files: BoundedVec::default(),
+            token: None,
+            restricted_delivery: false,
+            terms_and_conditions: BoundedVec::default(),
+            added_on: Some(current_timepoint()),
+        }));
+        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+    });
+}
+
+#[test]
+fn it_fails_to_item_with_terms_and_conditions_when_non_existent_tc_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let terms_and_conditions_details = "ITEM-A, ITEM-B".as_bytes().to_vec();
+        let terms_and_conditions = vec![TermsAndConditionsElement {
+            tc_type: sha256(&"Logion".as_bytes().to_vec()),
+            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+            details: sha256(&terms_and_conditions_details),
+        }];
+        assert_err!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocNotFound);
+    });
+}
+
+#[test]
+fn it_fails_to_item_with_terms_and_conditions_when_open_tc_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1);
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let terms_and_conditions_details = sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec());
+        let terms_and_conditions = vec![TermsAndConditionsElement {
+            tc_type: sha256(&"Logion".as_bytes().to_vec()),
+            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+            details: terms_and_conditions_details.clone(),
+        }];
+        assert_err!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocNotClosed);
+    });
+}
+
+#[test]
+fn it_fails_to_item_with_terms_and_conditions_when_void_tc_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOGION_CLASSIFICATION_LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let terms_and_conditions_details = sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec());
+        let terms_and_conditions = vec![TermsAndConditionsElement {
+            tc_type: sha256(&"Logion".as_bytes().to_vec()),
+            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+            details: terms_and_conditions_details,
+        }];
+        assert_err!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions), Error::<Test>::TermsAndConditionsLocVoid);
+    });
+}
+
+#[test]
+fn it_adds_item_with_terms_and_conditions_to_closed_collection_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOGION_CLASSIFICATION_LOC_ID));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), ADDITIONAL_TC_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, ADDITIONAL_TC_LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), ADDITIONAL_TC_LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let tc1 = TermsAndConditionsElement {
+            tc_type: sha256(&"Logion".as_bytes().to_vec()),
+            tc_loc: LOGION_CLASSIFICATION_LOC_ID,
+            details: sha256(&"ITEM-A, ITEM-B".as_bytes().to_vec()),
+        };
+        let tc2 = TermsAndConditionsElement {
+            tc_type: sha256(&"Specific".as_bytes().to_vec()),
+            tc_loc: ADDITIONAL_TC_LOC_ID,
+            details: sha256(&"Some more details".as_bytes().to_vec()),
+        };
+        let terms_and_conditions = vec![tc1, tc2];
+        assert_ok!(LogionLoc::add_collection_item_with_terms_and_conditions(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, terms_and_conditions.clone()));
+        assert_eq!(LogionLoc::collection_items(LOC_ID, collection_item_id), Some(CollectionItem {
+            description: collection_item_description,
+            files: BoundedVec::default(),
+            token: None,
+            restricted_delivery: false,
+            terms_and_conditions: terms_and_conditions.clone().try_into().unwrap(),
+            added_on: Some(current_timepoint()),
+        }));
+        assert_eq!(LogionLoc::collection_size(LOC_ID), Some(1));
+    });
+}
+
+#[test]
+fn it_fails_adding_item_to_collection_loc_if_not_requester() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_if_duplicate_key() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionItemAlreadyExists);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_if_size_limit_reached() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id.clone(), collection_item_description.clone(), vec![], None, false, Vec::new()));
+        let collection_item_id2 = BlakeTwo256::hash_of(&"item-id2".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id2, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionLimitsReached);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_if_block_limit_reached() {
+    let current_block: u64 = 10;
+    new_test_ext_at_block(current_block).execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(current_block - 1), None, false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::CollectionLimitsReached);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_if_collection_void() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()), Error::<Test>::WrongCollectionLoc);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_if_files_attached_but_upload_not_enabled() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: 123456,
+        }];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::CannotUpload);
+    });
+}
+
+#[test]
+fn it_adds_item_if_no_files_attached_and_upload_enabled() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], None, false, Vec::new()));
+    });
+}
+
+#[test]
+fn it_adds_item_with_one_file_attached() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: FILE_SIZE,
+        }];
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_with_insufficient_balance() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: FILE_SIZE,
+        }];
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::InsufficientFunds);
+        check_no_fees(snapshot);
+    });
+}
+
+#[test]
+fn it_adds_item_with_token() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: 123456,
+        }];
+        let collection_item_token = CollectionItemToken {
+            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
+            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
+            token_issuance: 2,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), Some(collection_item_token), true, Vec::new()));
+        let fees = Fees {
+            storage_fees: Fees::storage_fees(1, collection_item_files[0].size),
+            legal_fees: 0,
+            legal_fee_beneficiary: None,
+            certificate_fees: 8_000_000_000_000_000,
+        };
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_with_missing_token() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: 123456,
+        }];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, true, Vec::new()), Error::<Test>::MissingToken);
+    });
+}
+
+#[test]
+fn it_fails_adding_item_with_missing_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![];
+        let collection_item_token = CollectionItemToken {
+            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
+            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
+            token_issuance: 1,
+        };
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, Some(collection_item_token), true, Vec::new()), Error::<Test>::MissingFiles);
+    });
+}
+
+#[test]
+fn it_indexes_collection_item_by_token() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let token = erc721_token();
+        assert!(LogionLoc::get_collection_item_by_token(&token.token_type, &token.token_id).is_none());
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, sha256(&"item-description".as_bytes().to_vec()), vec![], Some(token.clone()), false, Vec::new()));
+
+        assert_eq!(LogionLoc::get_collection_item_by_token(&token.token_type, &token.token_id), Some((LOC_ID, collection_item_id)));
+        assert!(LogionLoc::get_collection_item_by_token(&sha256(&"owner".as_bytes().to_vec()), &token.token_id).is_none());
+    });
+}
+
+#[test]
+fn it_fails_adding_item_with_duplicate_token() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        for loc_id in [LOC_ID, OTHER_LOC_ID] {
+            assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), loc_id, LOC_OWNER1, None, Some(10), false, None));
+            review_and_accept(LOC_REQUESTER_ID, loc_id, LOC_OWNER1);
+            assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), loc_id));
+        }
+        let token = erc721_token();
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let other_collection_item_id = BlakeTwo256::hash_of(&"other-item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, vec![], Some(token.clone()), false, Vec::new()));
+
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, other_collection_item_id, collection_item_description, vec![], Some(token.clone()), false, Vec::new()), Error::<Test>::DuplicateToken);
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, other_collection_item_id, collection_item_description, vec![], Some(token), false, Vec::new()), Error::<Test>::DuplicateToken);
+        assert_eq!(LogionLoc::collection_size(OTHER_LOC_ID), None);
+    });
+}
+
+fn erc721_token() -> CollectionItemToken<TokenIssuance, H256> {
+    CollectionItemToken {
+        token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
+        token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
+        token_issuance: 1,
+    }
+}
+
+#[test]
+fn it_adds_item_with_two_files_attached() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![
+            CollectionItemFile {
+                name: sha256(&"picture.png".as_bytes().to_vec()),
+                content_type: sha256(&"image/png".as_bytes().to_vec()),
+                hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+                size: 123456,
+            },
+            CollectionItemFile {
+                name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                hash: BlakeTwo256::hash_of(&"some other content".as_bytes().to_vec()),
+                size: 789,
+            },
+        ];
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
+
+        let fees = Fees::only_storage(2, 123456 + 789);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_to_add_item_with_duplicate_hash() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let same_hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
+        let collection_item_files = vec![
+            CollectionItemFile {
+                name: sha256(&"picture.png".as_bytes().to_vec()),
+                content_type: sha256(&"image/png".as_bytes().to_vec()),
+                hash: same_hash,
+                size: 123456,
+            },
+            CollectionItemFile {
+                name: sha256(&"doc.pdf".as_bytes().to_vec()),
+                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
+                hash: same_hash,
+                size: 789,
+            },
+        ];
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::DuplicateFile);
+    });
+}
+
+#[test]
+fn it_closes_and_seals_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let seal = LogionLoc::compute_seal(SealVersion::V1, &seal_salt(), &seal_personal_data()).unwrap();
+        assert_ok!(LogionLoc::close_and_seal(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, seal));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.status, LocStatus::Closed);
+        assert!(loc.seal.is_some());
+        assert_eq!(loc.seal.unwrap(), seal);
+    });
+}
+
+#[test]
+fn it_proves_content_of_closed_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_loc_with_content();
+
+        assert!(LogionLoc::get_file_proof(&LOC_ID, &content_file().hash).is_none());
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let root = LogionLoc::content_root(LOC_ID).unwrap();
+        assert_eq!(root, merkle::merkle_root::<_, SHA256>(&LogionLoc::content_leaves(&LogionLoc::loc(LOC_ID).unwrap())));
+        for i in 0..3u8 {
+            let proof = LogionLoc::get_metadata_item_proof(&LOC_ID, &sha256(&vec![i])).unwrap();
+            assert_eq!(proof.leaf_index, i as u32);
+            assert!(merkle::verify_proof::<_, SHA256>(&root, &proof));
+        }
+        let file_proof = LogionLoc::get_file_proof(&LOC_ID, &content_file().hash).unwrap();
+        assert_eq!(file_proof.leaf_index, 3);
+        assert_eq!(file_proof.leaf_count, 5);
+        assert!(merkle::verify_proof::<_, SHA256>(&root, &file_proof));
+        let link_proof = LogionLoc::get_link_proof(&LOC_ID, &OTHER_LOC_ID).unwrap();
+        assert_eq!(link_proof.leaf_index, 4);
+        assert!(merkle::verify_proof::<_, SHA256>(&root, &link_proof));
+    });
+}
+
+#[test]
+fn it_rejects_invalid_content_proof() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_loc_with_content();
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let root = LogionLoc::content_root(LOC_ID).unwrap();
+        let proof = LogionLoc::get_file_proof(&LOC_ID, &content_file().hash).unwrap();
+
+        let mut wrong_leaf = proof.clone();
+        wrong_leaf.leaf = sha256(&vec![42]);
+        assert!(!merkle::verify_proof::<_, SHA256>(&root, &wrong_leaf));
+        let mut wrong_index = proof.clone();
+        wrong_index.leaf_index = 2;
+        assert!(!merkle::verify_proof::<_, SHA256>(&root, &wrong_index));
+        let mut missing_sibling = proof.clone();
+        missing_sibling.siblings.pop();
+        assert!(!merkle::verify_proof::<_, SHA256>(&root, &missing_sibling));
+        assert!(!merkle::verify_proof::<_, SHA256>(&sha256(&vec![42]), &proof));
+        assert!(LogionLoc::get_file_proof(&LOC_ID, &sha256(&vec![42])).is_none());
+    });
+}
+
+fn content_file() -> FileParams<H256, AccountId, OtherAccountIdOf<Test>> {
+    FileParams {
+        hash: sha256(&"content-file".as_bytes().to_vec()),
+        nature: sha256(&"content-file-nature".as_bytes().to_vec()),
+        submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        size: FILE_SIZE,
+    }
+}
+
+fn create_loc_with_content() {
+    assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+    review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+    for i in 0..3u8 {
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![i]),
+            value: sha256(&vec![i, i]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata));
+    }
+    assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, content_file()));
+    assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+    let link = LocLink {
+        id: OTHER_LOC_ID,
+        nature: sha256(&"content-link-nature".as_bytes().to_vec()),
+    };
+    assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link));
+}
+
+#[test]
+fn it_indexes_files_by_hash() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let hash = sha256(&"indexed-file".as_bytes().to_vec());
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let item_id = collection_item_id(0);
+        let item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash,
+            size: FILE_SIZE,
+        }];
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, item_id, sha256(&vec![0]), item_files, None, false, Vec::new()));
+        let record_id = build_record_id();
+        let record_files = vec![TokensRecordFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            size: FILE_SIZE,
+            hash,
+        }];
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, build_record_description(), record_files));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        let file = FileParams {
+            hash,
+            nature: sha256(&"indexed-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+            size: FILE_SIZE,
+        };
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, file));
+
+        let occurrences = LogionLoc::get_file_occurrences(&hash);
+        assert_eq!(occurrences.len(), 3);
+        assert!(occurrences.contains(&FileOccurrence { loc_id: LOC_ID, location: FileLocation::CollectionItem(item_id), status: LocStatus::Closed, closed: true, void: false }));
+        assert!(occurrences.contains(&FileOccurrence { loc_id: LOC_ID, location: FileLocation::TokensRecord(record_id), status: LocStatus::Closed, closed: true, void: false }));
+        assert!(occurrences.contains(&FileOccurrence { loc_id: OTHER_LOC_ID, location: FileLocation::Loc, status: LocStatus::Open, closed: false, void: false }));
+
+        assert_ok!(LogionLoc::delete_file(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, hash));
+        assert_eq!(LogionLoc::get_file_occurrences(&hash).len(), 2);
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert!(LogionLoc::get_file_occurrences(&hash).iter().all(|occurrence| occurrence.void && occurrence.closed && occurrence.status == LocStatus::Void));
+        assert!(LogionLoc::get_file_occurrences(&sha256(&"unknown-file".as_bytes().to_vec())).is_empty());
+    });
+}
+
+#[test]
+fn it_accumulates_same_root_as_merkle_tree() {
+    let leaves: Vec<H256> = (0..10u8).map(|i| sha256(&vec![i])).collect();
+    let mut accumulator = merkle::MerkleAccumulator::<H256>::default();
+    assert_eq!(accumulator.root::<SHA256>(), merkle::merkle_root::<_, SHA256>(&[]));
+    let mut nodes = BTreeMap::new();
+    for (count, leaf) in leaves.iter().enumerate() {
+        for (level, node) in accumulator.append::<SHA256>(*leaf) {
+            nodes.insert((level, count as u32 >> level), node);
+        }
+        assert_eq!(accumulator.leaf_count, count as u32 + 1);
+        assert_eq!(accumulator.root::<SHA256>(), merkle::merkle_root::<_, SHA256>(&leaves[..=count]));
+        for index in 0..=count as u32 {
+            let proof = accumulator.proof::<SHA256, _>(index, |level, index| nodes.get(&(level, index)).cloned());
+            assert_eq!(proof, merkle::merkle_proof::<_, SHA256>(&leaves[..=count], index));
+        }
+    }
+}
+
+#[test]
+fn it_proves_collection_item() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection(None, Some(10));
+        assert!(LogionLoc::get_collection_root(&LOC_ID).is_none());
+        add_collection_items(7);
+
+        let root = LogionLoc::get_collection_root(&LOC_ID).unwrap();
+        for i in 0..7u8 {
+            let proof = LogionLoc::get_collection_item_proof(&LOC_ID, &collection_item_id(i)).unwrap();
+            assert_eq!(proof.leaf_index, i as u32);
+            assert_eq!(proof.leaf_count, 7);
+            assert!(merkle::verify_proof::<_, SHA256>(&root, &proof));
+        }
+        assert!(LogionLoc::get_collection_item_proof(&LOC_ID, &collection_item_id(7)).is_none());
+        assert!(LogionLoc::collection_root_snapshot(LOC_ID).is_none());
+    });
+}
+
+#[test]
+fn it_snapshots_collection_root_when_max_size_reached() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection(None, Some(2));
+        add_collection_items(1);
+        assert!(LogionLoc::collection_root_snapshot(LOC_ID).is_none());
+        add_collection_items_from(1, 1);
+
+        let root = LogionLoc::get_collection_root(&LOC_ID).unwrap();
+        assert_eq!(LogionLoc::collection_root_snapshot(LOC_ID), Some(CollectionRootSnapshot {
+            root,
+            item_count: 2,
+            taken_on: 1,
+        }));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::CollectionRootSnapshotTaken { 0: LOC_ID, 1: root }));
+    });
+}
+
+#[test]
+fn it_snapshots_collection_root_at_last_block() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection(Some(COLLECTION_LAST_BLOCK), None);
+        add_collection_items(2);
+        assert_eq!(LogionLoc::collections_by_last_block(COLLECTION_LAST_BLOCK).to_vec(), vec![LOC_ID]);
+
+        System::set_block_number(COLLECTION_LAST_BLOCK);
+        let weight = LogionLoc::on_initialize(COLLECTION_LAST_BLOCK);
+
+        let root = LogionLoc::get_collection_root(&LOC_ID).unwrap();
+        assert_eq!(LogionLoc::collection_root_snapshot(LOC_ID), Some(CollectionRootSnapshot {
+            root,
+            item_count: 2,
+            taken_on: COLLECTION_LAST_BLOCK,
+        }));
+        assert!(LogionLoc::collections_by_last_block(COLLECTION_LAST_BLOCK).is_empty());
+        assert_eq!(weight, <() as WeightInfo>::expire_sponsorships(0)
+            .saturating_add(<() as WeightInfo>::expire_legal_fee_holds(0))
+            .saturating_add(<() as WeightInfo>::snapshot_collection_roots(1)));
+    });
+}
+
+const COLLECTION_LAST_BLOCK: u64 = 10;
+
+fn create_closed_collection(last_block: Option<u64>, max_size: Option<u32>) {
+    assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, last_block, max_size, false, None));
+    review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+}
+
+fn collection_item_id(i: u8) -> H256 {
+    BlakeTwo256::hash_of(&[i])
+}
+
+fn add_collection_items(count: u8) {
+    add_collection_items_from(0, count);
+}
+
+fn add_collection_items_from(first: u8, count: u8) {
+    for i in first..(first + count) {
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id(i), sha256(&vec![i]), vec![], None, false, Vec::new()));
+    }
+}
+
+fn seal_salt() -> Vec<u8> {
+    "some salt".as_bytes().to_vec()
+}
+
+fn seal_personal_data() -> Vec<Vec<u8>> {
+    vec!["John".as_bytes().to_vec(), "Doe".as_bytes().to_vec()]
+}
+
+#[test]
+fn it_verifies_seal() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let seal = LogionLoc::compute_seal(SealVersion::V1, &seal_salt(), &seal_personal_data()).unwrap();
+        assert_ok!(LogionLoc::close_and_seal(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, seal));
+
+        assert!(LogionLoc::verify_seal(&LOC_ID, &seal_salt(), &seal_personal_data()));
+        assert!(!LogionLoc::verify_seal(&LOC_ID, "other salt".as_bytes(), &seal_personal_data()));
+        assert!(!LogionLoc::verify_seal(&LOC_ID, &seal_salt(), &["Jane".as_bytes().to_vec(), "Doe".as_bytes().to_vec()]));
+        assert!(!LogionLoc::verify_seal(&OTHER_LOC_ID, &seal_salt(), &seal_personal_data()));
+    });
+}
+
+#[test]
+fn it_does_not_verify_legacy_seal() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let hash = BlakeTwo256::hash_of(&"some external private data".as_bytes().to_vec());
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        LocMap::<Test>::mutate(LOC_ID, |loc| loc.as_mut().unwrap().seal = Some(Seal { version: SealVersion::V0, hash }));
+
+        assert!(LogionLoc::compute_seal(SealVersion::V0, &seal_salt(), &seal_personal_data()).is_none());
+        assert!(!LogionLoc::verify_seal(&LOC_ID, &seal_salt(), &seal_personal_data()));
+    });
+}
+
+#[test]
+fn it_fails_closing_with_legacy_seal() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let hash = BlakeTwo256::hash_of(&"some external private data".as_bytes().to_vec());
+
+        assert_err!(LogionLoc::close_and_seal(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, Seal { version: SealVersion::V0, hash }), Error::<Test>::InvalidSealVersion);
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().status, LocStatus::Open);
+    });
+}
+
+#[test]
+fn it_fails_adding_file_with_same_hash() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let file1 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file1.clone()));
+        let file2 = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file2-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file2.clone()), Error::<Test>::DuplicateLocFile);
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_adding_metadata_with_same_name() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata1 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value1".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata1.clone()));
+        let metadata2 = MetadataItemParams {
+            name: sha256(&"name".as_bytes().to_vec()),
+            value: sha256(&"value2".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata2.clone()), Error::<Test>::DuplicateLocMetadata);
+    });
+}
+
+#[test]
+fn it_fails_adding_link_with_same_target() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        let link1 = LocLink {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
+        };
+        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link1.clone()));
+        let link2 = LocLink {
+            id: OTHER_LOC_ID,
+            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
+        };
+        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link2.clone()), Error::<Test>::DuplicateLocLink);
+    });
+}
+
+#[test]
+fn it_adds_several_metadata() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata1 = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata1.clone()));
+        let metadata2 = MetadataItemParams {
+            name: sha256(&vec![1, 2, 4]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata2.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata[0], expected_metadata(metadata1, ACKNOWLEDGED));
+        assert_eq!(loc.metadata[1], expected_metadata(metadata2, ACKNOWLEDGED));
+    });
+}
+
+#[test]
+fn it_nominates_an_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+
+        assert_eq!(LogionLoc::verified_issuers(LOC_OWNER1, ISSUER_ID1), Some(VerifiedIssuer { identity_loc: ISSUER1_IDENTITY_LOC_ID }));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::IssuerNominated { 0: LOC_OWNER1, 1: ISSUER_ID1, 2: ISSUER1_IDENTITY_LOC_ID }));
+    });
+}
+
+#[test]
+fn it_dismisses_an_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+
+        assert_ok!(LogionLoc::dismiss_issuer(RuntimeOrigin::signed(LOC_OWNER1), ISSUER_ID1));
+
+        assert_eq!(LogionLoc::verified_issuers(LOC_OWNER1, ISSUER_ID1), None);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::IssuerDismissed { 0: LOC_OWNER1, 1: ISSUER_ID1 }));
+    });
+}
+
+fn nominate_issuer(issuer: u64, identity_loc: u32) {
+    assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(issuer), identity_loc, LOC_OWNER1, None));
+    review_and_accept(issuer, identity_loc, LOC_OWNER1);
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), identity_loc));
+    assert_ok!(LogionLoc::nominate_issuer(RuntimeOrigin::signed(LOC_OWNER1), issuer, identity_loc));
+}
+
+#[test]
+fn it_selects_an_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true));
+
+        assert_eq!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID1), Some(()));
+        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, LOC_OWNER1, LOC_ID)), Some(()));
+    });
+}
+
+fn create_collection_and_nominated_issuer() {
+    assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), true, None));
+    review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+    nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+}
+
+#[test]
+fn it_fails_selecting_an_issuer_loc_not_found() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true), Error::<Test>::NotFound);
+    });
+}
+
+#[test]
+fn it_fails_selecting_an_issuer_not_nominated() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true), Error::<Test>::NotNominated);
+    });
+}
+
+#[test]
+fn it_fails_selecting_an_issuer_unauthorized() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER2), LOC_ID, ISSUER_ID1, true), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_selects_an_issuer_closed() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true));
+    });
+}
+
+#[test]
+fn it_fails_selecting_an_issuer_void() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_and_nominated_issuer();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true), Error::<Test>::CannotMutateVoid);
+    });
+}
+
+#[test]
+fn it_selects_an_issuer_not_collection() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true));
+    });
+}
+
+#[test]
+fn it_fails_selecting_an_issuer_on_too_many_locs() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+        let first_loc_id = 100;
+        for loc_id in first_loc_id..(first_loc_id + MaxIssuerLocs::get()) {
+            assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), loc_id));
+            assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), loc_id, ISSUER_ID1, true));
+        }
+        let loc_id = first_loc_id + MaxIssuerLocs::get();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), loc_id));
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), loc_id, ISSUER_ID1, true), Error::<Test>::IssuerLocsLimitReached);
+    });
+}
+
+#[test]
+fn it_unselects_an_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_with_selected_issuer();
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, false));
+
+        assert_eq!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID1), None);
+        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, LOC_OWNER1, LOC_ID)), None);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::IssuerSelectionChanged { 0: LOC_ID, 1: ISSUER_ID1, 2: true }));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::IssuerSelectionChanged { 0: LOC_ID, 1: ISSUER_ID1, 2: false }));
+    });
+}
+
+fn create_collection_with_selected_issuer() {
+    create_collection_and_nominated_issuer();
+    assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true));
+}
+
+#[test]
+fn it_fails_unselecting_an_issuer_loc_not_found() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, false), Error::<Test>::NotFound);
+    });
+}
+
+#[test]
+fn it_fails_unselecting_an_issuer_unauthorized() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_with_selected_issuer();
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER2), LOC_ID, ISSUER_ID1, false), Error::<Test>::Unauthorized);
+    });
+}
+
+#[test]
+fn it_unselects_an_issuer_closed() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_with_selected_issuer();
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, false));
+    });
+}
+
+#[test]
+fn it_fails_unselecting_an_issuer_void() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_with_selected_issuer();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, false), Error::<Test>::CannotMutateVoid);
+    });
+}
+
+#[test]
+fn it_unselects_issuer_on_dismiss() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        nominate_issuer(ISSUER_ID2, ISSUER2_IDENTITY_LOC_ID);
+        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID2, true));
+        assert!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID1).is_some());
+        assert!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID2).is_some());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, LOC_OWNER1, LOC_ID)).is_some());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID2, LOC_OWNER1, LOC_ID)).is_some());
+
+        assert_ok!(LogionLoc::dismiss_issuer(RuntimeOrigin::signed(LOC_OWNER1), ISSUER_ID1));
+
+        assert!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID1).is_none());
+        assert!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID2).is_some());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, LOC_OWNER1, LOC_ID)).is_none());
+        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID2, LOC_OWNER1, LOC_ID)).is_some());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::IssuerSelectionChanged { 0: LOC_ID, 1: ISSUER_ID1, 2: false }));
+    });
+}
+
+#[test]
+fn it_refunds_weight_on_dismiss() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+
+        let post_info = LogionLoc::dismiss_issuer(RuntimeOrigin::signed(LOC_OWNER1), ISSUER_ID1).unwrap();
+
+        assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::dismiss_issuer(1)));
+    });
+}
+
+#[test]
+fn it_adds_tokens_record_issuer() {
+    it_adds_tokens_record(ISSUER_ID1);
+}
+
+fn it_adds_tokens_record(submitter: AccountId) {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(submitter), LOC_ID, record_id, record_description.clone(), record_files.clone()));
+
+        let record = LogionLoc::tokens_records(LOC_ID, record_id).unwrap();
+        assert_eq!(record.description, record_description);
+        assert_eq!(record.submitter, SupportedAccountId::Polkadot(submitter));
+        assert_eq!(record.added_on, Some(current_timepoint()));
+        assert_eq!(record.files.len(), 1);
+        assert_eq!(record.files[0].name, record_files[0].name);
+        assert_eq!(record.files[0].content_type, record_files[0].content_type);
+        assert_eq!(record.files[0].size, record_files[0].size);
+        assert_eq!(record.files[0].hash, record_files[0].hash);
+
+        let fees = Fees::only_storage(1, record_files[0].size);
+        fees.assert_balances_events(snapshot);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::TokensRecordAdded { 0: LOC_ID, 1: record_id, 2: SupportedAccountId::Polkadot(submitter) }));
+    });
+}
+
+fn create_closed_collection_with_selected_issuer() {
+    create_collection_with_selected_issuer();
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+}
+
+fn build_record_id() -> H256 {
+    BlakeTwo256::hash_of(&"Record ID".as_bytes().to_vec())
+}
+
+fn build_record_description() -> H256 {
+    sha256(&"Some description".as_bytes().to_vec())
+}
+
+fn build_record_files(files: usize) -> Vec<TokensRecordFileOf<Test>> {
+    let mut record_files = Vec::with_capacity(files);
+    for i in 0..files {
+        let file = TokensRecordFile {
+            name: sha256(&"File name".as_bytes().to_vec()),
+            content_type: sha256(&"text/plain".as_bytes().to_vec()),
+            size: i as u32 % 10,
+            hash: BlakeTwo256::hash_of(&i.to_string().as_bytes().to_vec()),
+        };
+        record_files.push(file);
+    }
+    record_files
+}
+
+#[test]
+fn it_adds_tokens_record_requester() {
+    it_adds_tokens_record(LOC_REQUESTER_ID);
+}
+
+#[test]
+fn it_adds_tokens_record_owner() {
+    it_adds_tokens_record(LOC_OWNER1);
+}
+
+#[test]
+fn it_fails_adding_tokens_record_already_exists() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description.clone(), record_files.clone()));
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files.clone()), Error::<Test>::TokensRecordAlreadyExists);
+        let file = record_files.get(0).unwrap();
+
+        let fees = Fees::only_storage(1, file.size);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_not_contributor() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID2), LOC_ID, record_id, record_description, record_files), Error::<Test>::CannotAddRecord);
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_collection_open() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::CannotAddRecord);
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_collection_void() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_collection_with_selected_issuer();
+        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::CannotAddRecord);
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_not_collection() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::CannotAddRecord);
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_no_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = vec![];
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::MustUpload);
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_duplicate_file() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let file1 = TokensRecordFile {
+            name: sha256(&"File name".as_bytes().to_vec()),
+            content_type: sha256(&"text/plain".as_bytes().to_vec()),
+            size: 4,
+            hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+        };
+        let file2 = TokensRecordFile {
+            name: sha256(&"File name 2".as_bytes().to_vec()),
+            content_type: sha256(&"text/plain".as_bytes().to_vec()),
+            size: 4,
+            hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
+        };
+        let record_files = vec![file1, file2];
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::DuplicateFile);
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_too_many_files() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(256);
+
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::TokensRecordTooMuchData);
+    });
+}
+
+#[test]
+fn it_fails_adding_tokens_record_when_insufficient_funds() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        create_closed_collection_with_selected_issuer();
+        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![
-            CollectionItemFile {
-                name: sha256(&"picture.png".as_bytes().to_vec()),
-                content_type: sha256(&"image/png".as_bytes().to_vec()),
-                hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-                size: 123456,
-            },
-            CollectionItemFile {
-                name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                hash: BlakeTwo256::hash_of(&"some other content".as_bytes().to_vec()),
-                size: 789,
-            },
-        ];
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
+        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::InsufficientFunds);
+        check_no_fees(snapshot);
+    });
+}
 
-        let fees = Fees::only_storage(2, 123456 + 789);
+#[test]
+fn it_adds_file_on_logion_identity_loc_when_caller_is_owner_and_submitter_is_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        nominated_and_select_issuer(LOC_ID);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
+
+        let fees = Fees::only_storage(1, FILE_SIZE);
         fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_to_add_item_with_duplicate_hash() {
+fn it_adds_file_on_logion_identity_loc_when_caller_and_submitter_is_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        nominated_and_select_issuer(LOC_ID);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, file.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
 
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let same_hash = BlakeTwo256::hash_of(&"file content".as_bytes().to_vec());
-        let collection_item_files = vec![
-            CollectionItemFile {
-                name: sha256(&"picture.png".as_bytes().to_vec()),
-                content_type: sha256(&"image/png".as_bytes().to_vec()),
-                hash: same_hash,
-                size: 123456,
-            },
-            CollectionItemFile {
-                name: sha256(&"doc.pdf".as_bytes().to_vec()),
-                content_type: sha256(&"application/pdf".as_bytes().to_vec()),
-                hash: same_hash,
-                size: 789,
-            },
-        ];
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()), Error::<Test>::DuplicateFile);
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
     });
 }
 
+fn nominated_and_select_issuer(loc_id: u32) {
+    nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+    assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), loc_id, ISSUER_ID1, true));
+}
+
 #[test]
-fn it_closes_and_seals_loc() {
+fn it_adds_file_on_polkadot_transaction_loc_when_caller_is_owner_and_submitter_is_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let seal = BlakeTwo256::hash_of(&"some external private data".as_bytes().to_vec());
-        assert_ok!(LogionLoc::close_and_seal(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, seal));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        nominated_and_select_issuer(LOC_ID);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
         let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert!(loc.closed);
-        assert!(loc.seal.is_some());
-        assert_eq!(loc.seal.unwrap(), seal);
+        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
+
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_file_with_same_hash() {
+fn it_adds_file_on_polkadot_transaction_loc_when_caller_is_submitter_and_issuer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let file1 = FileParams {
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        nominated_and_select_issuer(LOC_ID);
+        let file = FileParams {
             hash: sha256(&"test".as_bytes().to_vec()),
             nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
             size: FILE_SIZE,
         };
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file1.clone()));
-        let file2 = FileParams {
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, file.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
+
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_fails_adding_file_on_polkadot_transaction_loc_cannot_submit() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let file = FileParams {
             hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file2-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER2),
             size: FILE_SIZE,
         };
-        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file2.clone()), Error::<Test>::DuplicateLocFile);
-        let fees = Fees::only_storage(1, FILE_SIZE);
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()), Error::<Test>::CannotSubmit);
+    });
+}
+
+#[test]
+fn it_adds_metadata_on_logion_identity_loc_for_when_submitter_is_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        nominated_and_select_issuer(LOC_ID);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+    });
+}
+
+#[test]
+fn it_fails_adding_metadata_on_logion_identity_loc_cannot_submit() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER2),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()), Error::<Test>::CannotSubmit);
+    });
+}
+
+#[test]
+fn it_adds_metadata_on_polkadot_transaction_loc_when_submitter_is_issuer() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        nominated_and_select_issuer(LOC_ID);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+    });
+}
+
+#[test]
+fn it_fails_adding_metadata_on_polkadot_transaction_loc_cannot_submit() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Polkadot(LOC_OWNER2),
+        };
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()), Error::<Test>::CannotSubmit);
+    });
+}
+
+#[test]
+fn it_creates_sponsorship() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+
+        let sponsorship = LogionLoc::sponsorship(sponsorship_id).unwrap();
+        assert_eq!(sponsorship.legal_officer, LOC_OWNER1);
+        assert_eq!(sponsorship.sponsor, SPONSOR_ID);
+        assert_eq!(sponsorship.sponsored_account, sponsored_account);
+        assert_eq!(sponsorship.expires_on, SPONSORSHIP_EXPIRY);
+        assert_eq!(sponsorship.max_balance, INITIAL_BALANCE);
+        assert_eq!(sponsorship.max_locs, Some(1));
+        assert_eq!(sponsorship.used_locs, 0);
+        assert_eq!(sponsorship.used_balance, 0);
+        assert_eq!(LogionLoc::sponsorships_by_expiry(SPONSORSHIP_EXPIRY).into_inner(), vec![sponsorship_id]);
+    });
+}
+
+#[test]
+fn it_fails_creating_sponsorship_with_duplicate_id() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_err!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)), Error::<Test>::AlreadyExists);
+    });
+}
+
+#[test]
+fn it_withdraws_unused_sponsorship() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert!(LogionLoc::sponsorship(sponsorship_id).is_some());
+
+        assert_ok!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id));
+
+        assert!(LogionLoc::sponsorship(sponsorship_id).is_none());
+    });
+}
+
+#[test]
+fn it_creates_ethereum_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
+        let requester_account_id = OtherAccountId::Ethereum(ethereum_address);
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(ethereum_address));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id.clone(), sponsorship_id));
+        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
+            owner: LOC_OWNER1,
+            requester: OtherAccount(requester_account_id.clone()),
+            metadata: BoundedVec::default(),
+            files: BoundedVec::default(),
+            status: LocStatus::Open,
+            closed: false,
+            loc_type: LocType::Identity,
+            links: BoundedVec::default(),
+            void_info: None,
+            replacer_of: None,
+            collection_last_block_submission: None,
+            collection_max_size: None,
+            collection_can_upload: false,
+            seal: None,
+            sponsorship_id: Some(sponsorship_id),
+            closed_on: None,
+            rejection_reason: None,
+        }));
+        assert!(OtherAccountLocsMap::<Test>::contains_key((requester_account_id, LocType::Identity, LOC_ID)));
+        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().used_locs, 1);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated { 0: LOC_ID }));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LegalFeeHeld { 0: LOC_ID, 1: SPONSOR_ID, 2: 160 * ONE_LGNT }));
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let fees = Fees::only_legal(160 * ONE_LGNT, Beneficiary::Treasury);
+        fees.assert_balances_events(snapshot);
+    });
+}
+
+#[test]
+fn it_creates_polkadot_identity_loc() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
+            owner: LOC_OWNER1,
+            requester: Account(LOC_REQUESTER_ID),
+            metadata: BoundedVec::default(),
+            files: BoundedVec::default(),
+            status: LocStatus::Open,
+            closed: false,
+            loc_type: LocType::Identity,
+            links: BoundedVec::default(),
+            void_info: None,
+            replacer_of: None,
+            collection_last_block_submission: None,
+            collection_max_size: None,
+            collection_can_upload: false,
+            seal: None,
+            sponsorship_id: None,
+            closed_on: None,
+            rejection_reason: None,
+        }));
+        assert!(AccountLocsMap::<Test>::contains_key((LOC_REQUESTER_ID, LocType::Identity, LOC_ID)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated { 0: LOC_ID }));
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let fees = Fees::only_legal(160 * ONE_LGNT, Beneficiary::Treasury);
         fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_metadata_with_same_name() {
+fn it_fails_creating_ethereum_identity_loc_if_duplicate_loc_id() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let metadata1 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value1".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata1.clone()));
-        let metadata2 = MetadataItemParams {
-            name: sha256(&"name".as_bytes().to_vec()),
-            value: sha256(&"value2".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
-        };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata2.clone()), Error::<Test>::DuplicateLocMetadata);
+        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
+        let requester_address = OtherAccountId::Ethereum(ethereum_address);
+        let sponsorship_id = 1;
+        let sponsored_account: SupportedAccountId<AccountId, H160> = SupportedAccountId::Other(requester_address);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address.clone(), sponsorship_id));
+        assert_err!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address.clone(), sponsorship_id), Error::<Test>::AlreadyExists);
     });
 }
 
 #[test]
-fn it_fails_adding_link_with_same_target() {
+fn it_fails_creating_several_ethereum_identity_loc_with_single_sponsorship() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1));
-        let link1 = LocLink {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link1-nature".as_bytes().to_vec()),
-        };
-        assert_ok!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link1.clone()));
-        let link2 = LocLink {
-            id: OTHER_LOC_ID,
-            nature: sha256(&"test-link2-nature".as_bytes().to_vec()),
-        };
-        assert_err!(LogionLoc::add_link(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, link2.clone()), Error::<Test>::DuplicateLocLink);
+        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
+        let requester_address = OtherAccountId::Ethereum(ethereum_address);
+        let sponsorship_id = 1;
+        let sponsored_account: SupportedAccountId<AccountId, H160> = SupportedAccountId::Other(requester_address);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address.clone(), sponsorship_id));
+        assert_err!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, requester_address.clone(), sponsorship_id), Error::<Test>::CannotLinkToSponsorship);
     });
 }
 
 #[test]
-fn it_adds_several_metadata() {
+fn it_creates_sponsored_polkadot_transaction_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let metadata1 = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata1.clone()));
-        let metadata2 = MetadataItemParams {
-            name: sha256(&vec![1, 2, 4]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER1),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata2.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata1, ACKNOWLEDGED));
-        assert_eq!(loc.metadata[1], expected_metadata(metadata2, ACKNOWLEDGED));
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
+
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().sponsorship_id, Some(sponsorship_id));
+        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().used_locs, 1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(LOC_OWNER1));
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_nominates_an_issuer() {
+fn it_bills_sponsor_for_files_of_sponsored_polkadot_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
 
-        assert_eq!(LogionLoc::verified_issuers(LOC_OWNER1, ISSUER_ID1), Some(VerifiedIssuer { identity_loc: ISSUER1_IDENTITY_LOC_ID }));
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+
+        let fees = Fees::only_storage(1, file.size);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_dismisses_an_issuer() {
+fn it_bills_sponsor_for_items_and_records_of_sponsored_collection() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
 
-        assert_ok!(LogionLoc::dismiss_issuer(RuntimeOrigin::signed(LOC_OWNER1), ISSUER_ID1));
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: FILE_SIZE,
+        }];
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new()));
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
 
-        assert_eq!(LogionLoc::verified_issuers(LOC_OWNER1, ISSUER_ID1), None);
+        let record_id = BlakeTwo256::hash_of(&"record-id".as_bytes().to_vec());
+        let record_description = sha256(&"record-description".as_bytes().to_vec());
+        let record_files = vec![TokensRecordFile {
+            name: sha256(&"file.txt".as_bytes().to_vec()),
+            content_type: sha256(&"text/plain".as_bytes().to_vec()),
+            size: FILE_SIZE,
+            hash: BlakeTwo256::hash_of(&"record file content".as_bytes().to_vec()),
+        }];
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, record_description, record_files));
+        let fees = Fees::only_storage(1, FILE_SIZE);
+        fees.assert_balances_events(snapshot);
     });
 }
 
-fn nominate_issuer(issuer: u64, identity_loc: u32) {
-    assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(issuer), identity_loc, LOC_OWNER1));
-    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), identity_loc));
-    assert_ok!(LogionLoc::nominate_issuer(RuntimeOrigin::signed(LOC_OWNER1), issuer, identity_loc));
-}
-
 #[test]
-fn it_selects_an_issuer() {
+fn it_fails_creating_polkadot_loc_with_sponsorship_of_other_account() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
-
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true));
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(ISSUER_ID1), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
 
-        assert_eq!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID1), Some(()));
-        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, LOC_OWNER1, LOC_ID)), Some(()));
+        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)), Error::<Test>::CannotLinkToSponsorship);
+        assert_err!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, LOC_OWNER2, Some(sponsorship_id)), Error::<Test>::CannotLinkToSponsorship);
     });
 }
 
-fn create_collection_and_nominated_issuer() {
-    assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), true));
-    nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
-}
-
 #[test]
-fn it_fails_selecting_an_issuer_loc_not_found() {
+fn it_fails_creating_several_polkadot_locs_with_single_sponsorship() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true), Error::<Test>::NotFound);
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+
+        assert_err!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None, Some(1), false, Some(sponsorship_id)), Error::<Test>::CannotLinkToSponsorship);
     });
 }
 
 #[test]
-fn it_fails_selecting_an_issuer_not_nominated() {
+fn it_fails_withdrawing_used_sponsorship() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), true));
+        let sponsorship_id = 1;
+        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let requester_address = OtherAccountId::Ethereum(beneficiary);
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address, sponsorship_id));
 
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true), Error::<Test>::NotNominated);
+        assert_err!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id), Error::<Test>::AlreadyUsed);
     });
 }
 
 #[test]
-fn it_fails_selecting_an_issuer_unauthorized() {
-    new_test_ext().execute_with(|| {
+fn it_fails_creating_sponsorship_with_past_expiry() {
+    new_test_ext_at_block(SPONSORSHIP_EXPIRY).execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Polkadot(LOC_REQUESTER_ID);
 
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER2), LOC_ID, ISSUER_ID1, true), Error::<Test>::Unauthorized);
+        assert_err!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)), Error::<Test>::InvalidSponsorshipExpiry);
     });
 }
 
 #[test]
-fn it_selects_an_issuer_closed() {
+fn it_fails_creating_sponsorship_when_too_many_expiring() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let sponsored_account = SupportedAccountId::Polkadot(LOC_REQUESTER_ID);
+        for sponsorship_id in 0..MaxExpiringSponsorships::get() {
+            assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        }
 
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true));
+        assert_err!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), MaxExpiringSponsorships::get(), sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)), Error::<Test>::SponsorshipExpiryLimitReached);
     });
 }
 
 #[test]
-fn it_fails_selecting_an_issuer_void() {
+fn it_removes_withdrawn_sponsorship_from_expiry_index() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_and_nominated_issuer();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Polkadot(LOC_REQUESTER_ID);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
 
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true), Error::<Test>::CannotMutateVoid);
+        assert_ok!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id));
+
+        assert!(LogionLoc::sponsorships_by_expiry(SPONSORSHIP_EXPIRY).is_empty());
     });
 }
 
 #[test]
-fn it_selects_an_issuer_not_collection() {
+fn it_expires_sponsorship() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Polkadot(LOC_REQUESTER_ID);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
 
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true));
+        let weight = LogionLoc::on_initialize(SPONSORSHIP_EXPIRY);
+
+        assert_eq!(weight, <() as WeightInfo>::expire_sponsorships(1)
+            .saturating_add(<() as WeightInfo>::expire_legal_fee_holds(0))
+            .saturating_add(<() as WeightInfo>::snapshot_collection_roots(0)));
+        assert!(LogionLoc::sponsorship(sponsorship_id).is_none());
+        assert!(LogionLoc::sponsorships_by_expiry(SPONSORSHIP_EXPIRY).is_empty());
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::SponsorshipExpired { 0: sponsorship_id, 1: SPONSOR_ID, 2: sponsored_account }));
+        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)), Error::<Test>::CannotLinkToSponsorship);
     });
 }
 
 #[test]
-fn it_unselects_an_issuer() {
+fn it_keeps_used_sponsorship_after_expiry() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(2)));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
 
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, false));
+        LogionLoc::on_initialize(SPONSORSHIP_EXPIRY);
 
-        assert_eq!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID1), None);
-        assert_eq!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, LOC_OWNER1, LOC_ID)), None);
+        assert!(LogionLoc::sponsorship(sponsorship_id).is_some());
+        assert!(LogionLoc::sponsorships_by_expiry(SPONSORSHIP_EXPIRY).is_empty());
+        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID + 1, LOC_OWNER1, Some(sponsorship_id)), Error::<Test>::CannotLinkToSponsorship);
     });
 }
 
-fn create_collection_with_selected_issuer() {
-    create_collection_and_nominated_issuer();
-    assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, true));
-}
-
 #[test]
-fn it_fails_unselecting_an_issuer_loc_not_found() {
+fn it_bills_sponsor_for_files_once_sponsorship_expired() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, false), Error::<Test>::NotFound);
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        LogionLoc::on_initialize(SPONSORSHIP_EXPIRY);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
+
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone()));
+
+        let fees = Fees::only_storage(1, file.size);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_unselecting_an_issuer_unauthorized() {
+fn it_reuses_sponsorship_up_to_max_locs() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(2)));
 
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER2), LOC_ID, ISSUER_ID1, false), Error::<Test>::Unauthorized);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+        assert_err!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER1, Some(sponsorship_id)), Error::<Test>::CannotLinkToSponsorship);
+
+        let sponsorship = LogionLoc::sponsorship(sponsorship_id).unwrap();
+        assert_eq!(sponsorship.used_locs, 2);
+        assert_eq!(sponsorship.used_balance, 2 * 2000 * ONE_LGNT);
     });
 }
 
 #[test]
-fn it_unselects_an_issuer_closed() {
+fn it_fails_exceeding_sponsorship_budget() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Polkadot(LOC_REQUESTER_ID), LOC_OWNER1, SPONSORSHIP_EXPIRY, 2000 * ONE_LGNT, None));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Polkadot(LOC_REQUESTER_ID),
+            size: FILE_SIZE,
+        };
 
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, false));
+        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file), Error::<Test>::SponsorshipBudgetExceeded);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, Some(sponsorship_id)));
+        assert_err!(LogionLoc::request_review(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID), Error::<Test>::SponsorshipBudgetExceeded);
     });
 }
 
 #[test]
-fn it_fails_unselecting_an_issuer_void() {
+fn it_adds_metadata_when_submitter_is_ethereum_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        assert_err!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID1, false), Error::<Test>::CannotMutateVoid);
+        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
+        let requester_address = OtherAccountId::Ethereum(ethereum_address);
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(requester_address);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address, sponsorship_id));
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: sponsored_account,
+        };
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED));
     });
 }
 
 #[test]
-fn it_unselects_issuer_on_dismiss() {
+fn it_adds_file_when_submitter_is_ethereum_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        nominate_issuer(ISSUER_ID2, ISSUER2_IDENTITY_LOC_ID);
-        assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, ISSUER_ID2, true));
-        assert!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID1).is_some());
-        assert!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID2).is_some());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, LOC_OWNER1, LOC_ID)).is_some());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID2, LOC_OWNER1, LOC_ID)).is_some());
-
-        assert_ok!(LogionLoc::dismiss_issuer(RuntimeOrigin::signed(LOC_OWNER1), ISSUER_ID1));
+        let requester = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(requester));
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OtherAccountId::Ethereum(requester), sponsorship_id));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Other(OtherAccountId::Ethereum(requester)),
+            size: FILE_SIZE,
+        };
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
 
-        assert!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID1).is_none());
-        assert!(LogionLoc::verified_issuers_by_loc(LOC_ID, ISSUER_ID2).is_some());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID1, LOC_OWNER1, LOC_ID)).is_none());
-        assert!(LogionLoc::locs_by_verified_issuer((ISSUER_ID2, LOC_OWNER1, LOC_ID)).is_some());
+        let fees = Fees::only_storage(1, file.size);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_adds_tokens_record_issuer() {
-    it_adds_tokens_record(ISSUER_ID1);
-}
-
-fn it_adds_tokens_record(submitter: AccountId) {
+fn it_creates_bitcoin_identity_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
-
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(submitter), LOC_ID, record_id, record_description.clone(), record_files.clone()));
+        let requester_account_id = OtherAccountId::Bitcoin([1; 25]);
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Other(requester_account_id), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
 
-        let record = LogionLoc::tokens_records(LOC_ID, record_id).unwrap();
-        assert_eq!(record.description, record_description);
-        assert_eq!(record.submitter, submitter);
-        assert_eq!(record.files.len(), 1);
-        assert_eq!(record.files[0].name, record_files[0].name);
-        assert_eq!(record.files[0].content_type, record_files[0].content_type);
-        assert_eq!(record.files[0].size, record_files[0].size);
-        assert_eq!(record.files[0].hash, record_files[0].hash);
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id, sponsorship_id));
 
-        let fees = Fees::only_storage(1, record_files[0].size);
-        fees.assert_balances_events(snapshot);
+        assert_eq!(LogionLoc::loc(LOC_ID).unwrap().requester, OtherAccount(requester_account_id));
+        assert!(OtherAccountLocsMap::<Test>::contains_key((requester_account_id, LocType::Identity, LOC_ID)));
+        assert_eq!(LogionLoc::get_locs_by_requester(&OtherAccount(requester_account_id), None, 10), vec![LOC_ID]);
+        assert_eq!(LogionLoc::other_account_locs(requester_account_id), Some(vec![LOC_ID]));
     });
 }
 
-fn create_closed_collection_with_selected_issuer() {
-    create_collection_with_selected_issuer();
-    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-}
+#[test]
+fn it_adds_metadata_when_submitter_is_solana_requester() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let requester_account_id = OtherAccountId::Solana(H256::repeat_byte(1));
+        let sponsorship_id = 1;
+        let sponsored_account = SupportedAccountId::Other(requester_account_id);
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id, sponsorship_id));
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: sponsored_account,
+        };
 
-fn build_record_id() -> H256 {
-    BlakeTwo256::hash_of(&"Record ID".as_bytes().to_vec())
-}
+        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
 
-fn build_record_description() -> H256 {
-    sha256(&"Some description".as_bytes().to_vec())
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED));
+    });
 }
 
-fn build_record_files(files: usize) -> Vec<TokensRecordFileOf<Test>> {
-    let mut record_files = Vec::with_capacity(files);
-    for i in 0..files {
-        let file = TokensRecordFile {
-            name: sha256(&"File name".as_bytes().to_vec()),
-            content_type: sha256(&"text/plain".as_bytes().to_vec()),
-            size: i as u32 % 10,
-            hash: BlakeTwo256::hash_of(&i.to_string().as_bytes().to_vec()),
+#[test]
+fn it_fails_adding_metadata_when_submitter_is_account_of_other_blockchain() {
+    new_test_ext().execute_with(|| {
+        setup_default_balances();
+        let requester_account_id = OtherAccountId::Tezos([1; 21]);
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Other(requester_account_id), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id, sponsorship_id));
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter: SupportedAccountId::Other(OtherAccountId::Bitcoin([1; 25])),
         };
-        record_files.push(file);
-    }
-    record_files
-}
 
-#[test]
-fn it_adds_tokens_record_requester() {
-    it_adds_tokens_record(LOC_REQUESTER_ID);
+        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata), Error::<Test>::CannotSubmit);
+    });
 }
 
 #[test]
-fn it_adds_tokens_record_owner() {
-    it_adds_tokens_record(LOC_OWNER1);
+fn it_keeps_ethereum_account_encoding() {
+    let ethereum_address = H160::from_str(ETHEREUM_ADDRESS).unwrap();
+    let account: OtherAccountIdOf<Test> = OtherAccountId::Ethereum(ethereum_address);
+
+    assert_eq!(account.encode(), [&[0u8][..], ethereum_address.as_bytes()].concat());
 }
 
 #[test]
-fn it_fails_adding_tokens_record_already_exists() {
+fn it_adds_metadata_with_ethereum_signature() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        let submitter = create_ethereum_identity_loc();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter,
+        };
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_metadata", &(LOC_ID, &metadata), 0));
 
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description.clone(), record_files.clone()));
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files.clone()), Error::<Test>::TokensRecordAlreadyExists);
-        let file = record_files.get(0).unwrap();
+        assert_ok!(LogionLoc::add_metadata_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone(), 0, signature));
 
-        let fees = Fees::only_storage(1, file.size);
-        fees.assert_balances_events(snapshot);
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.metadata[0], expected_metadata(metadata.clone(), NOT_ACKNOWLEDGED));
+        assert_eq!(LogionLoc::ethereum_nonce(H160::from_str(ETHEREUM_ADDRESS).unwrap()), 1);
+        assert_eq!(LogionLoc::get_other_account_nonce(&OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap())), 1);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::MetadataItemAdded { 0: LOC_ID, 1: metadata.name, 2: submitter }));
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_not_contributor() {
+fn it_adds_file_with_ethereum_signature() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        let submitter = create_ethereum_identity_loc();
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter,
+            size: FILE_SIZE,
+        };
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_file", &(LOC_ID, &file), 0));
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID2), LOC_ID, record_id, record_description, record_files), Error::<Test>::CannotAddRecord);
+        assert_ok!(LogionLoc::add_file_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone(), 0, signature));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
+        let fees = Fees::only_storage(1, file.size);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_collection_open() {
+fn it_fails_adding_unsponsored_file_with_ethereum_signature() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        let requester = OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        assert_ok!(LogionLoc::create_other_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester, None));
+        let file = FileParams {
+            hash: sha256(&"test".as_bytes().to_vec()),
+            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
+            submitter: SupportedAccountId::Other(requester),
+            size: FILE_SIZE,
+        };
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_file", &(LOC_ID, &file), 0));
+        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::CannotAddRecord);
+        assert_err!(LogionLoc::add_file_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, file.clone(), 0, signature), Error::<Test>::SponsorshipRequired);
+
+        check_no_fees(snapshot);
+        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_collection_void() {
+fn it_fails_replaying_ethereum_signature() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_collection_with_selected_issuer();
-        assert_ok!(LogionLoc::make_void(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        let submitter = create_ethereum_identity_loc();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter,
+        };
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_metadata", &(LOC_ID, &metadata), 0));
+        assert_ok!(LogionLoc::add_metadata_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata.clone(), 0, signature.clone()));
+        assert_ok!(LogionLoc::delete_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.name));
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::CannotAddRecord);
+        assert_err!(LogionLoc::add_metadata_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata, 0, signature), Error::<Test>::InvalidEthereumNonce);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_not_collection() {
+fn it_fails_adding_metadata_with_signature_of_other_ethereum_account() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        let submitter = create_ethereum_identity_loc();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter,
+        };
+        let other_secret_key = "0x59c6995e998f97a5a0044966f0945389dc9e86dae88c7a8412f4603b6b78690d";
+        let signature = ethereum_sign(other_secret_key, LogionLoc::ethereum_signable_hash(b"add_metadata", &(LOC_ID, &metadata), 0));
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::CannotAddRecord);
+        assert_err!(LogionLoc::add_metadata_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata, 0, signature), Error::<Test>::InvalidEthereumSignature);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_no_files() {
+fn it_fails_adding_metadata_with_signature_of_other_call() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = vec![];
+        let submitter = create_ethereum_identity_loc();
+        let metadata = MetadataItemParams {
+            name: sha256(&vec![1, 2, 3]),
+            value: sha256(&vec![4, 5, 6]),
+            submitter,
+        };
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_file", &(LOC_ID, &metadata), 0));
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::MustUpload);
+        assert_err!(LogionLoc::add_metadata_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, metadata, 0, signature), Error::<Test>::InvalidEthereumSignature);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_duplicate_file() {
+fn it_creates_other_transaction_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let file1 = TokensRecordFile {
-            name: sha256(&"File name".as_bytes().to_vec()),
-            content_type: sha256(&"text/plain".as_bytes().to_vec()),
-            size: 4,
-            hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
-        };
-        let file2 = TokensRecordFile {
-            name: sha256(&"File name 2".as_bytes().to_vec()),
-            content_type: sha256(&"text/plain".as_bytes().to_vec()),
-            size: 4,
-            hash: BlakeTwo256::hash_of(&"test".as_bytes().to_vec()),
-        };
-        let record_files = vec![file1, file2];
+        let requester_account_id = OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::DuplicateFile);
+        assert_ok!(LogionLoc::create_other_transaction_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id, None));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.requester, OtherAccount(requester_account_id));
+        assert_eq!(loc.loc_type, LocType::Transaction);
+        assert_eq!(loc.sponsorship_id, None);
+        assert!(OtherAccountLocsMap::<Test>::contains_key((requester_account_id, LocType::Transaction, LOC_ID)));
+        let fees = Fees::only_storage(0, 0);
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_too_many_files() {
+fn it_fails_creating_other_transaction_loc_if_not_legal_officer() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(256);
+        let requester_account_id = OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap());
 
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::TokensRecordTooMuchData);
+        assert_err!(LogionLoc::create_other_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, requester_account_id, None), BadOrigin);
     });
 }
 
 #[test]
-fn it_fails_adding_tokens_record_when_insufficient_funds() {
+fn it_creates_sponsored_other_collection_loc() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        create_closed_collection_with_selected_issuer();
-        set_balance(LOC_REQUESTER_ID, INSUFFICIENT_BALANCE);
-        let record_id = build_record_id();
-        let record_description = build_record_description();
-        let record_files = build_record_files(1);
+        let requester_account_id = OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        let sponsorship_id = 1;
+        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, SupportedAccountId::Other(requester_account_id), LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
 
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_err!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description, record_files), Error::<Test>::InsufficientFunds);
-        check_no_fees(snapshot);
+        assert_ok!(LogionLoc::create_other_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id, None, Some(10), true, Some(sponsorship_id)));
+
+        let loc = LogionLoc::loc(LOC_ID).unwrap();
+        assert_eq!(loc.requester, OtherAccount(requester_account_id));
+        assert_eq!(loc.loc_type, LocType::Collection);
+        assert_eq!(loc.sponsorship_id, Some(sponsorship_id));
+        assert!(OtherAccountLocsMap::<Test>::contains_key((requester_account_id, LocType::Collection, LOC_ID)));
+        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().used_locs, 1);
+        assert_eq!(Balances::balance_on_hold(&HoldReason::LegalFee, &SPONSOR_ID), 2000 * ONE_LGNT);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let fees = Fees::only_legal(2000 * ONE_LGNT, Beneficiary::LegalOfficer(LOC_OWNER1));
+        fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_adds_file_on_logion_identity_loc_when_caller_is_owner_and_submitter_is_issuer() {
+fn it_adds_collection_item_with_ethereum_signature() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        nominated_and_select_issuer(LOC_ID);
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+        let submitter = create_closed_ethereum_collection_loc(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
             size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
+        }];
+        let item = (LOC_ID, collection_item_id, collection_item_description, &collection_item_files, &None::<CollectionItemToken<TokenIssuance, H256>>, false, &Vec::<TermsAndConditionsElement<u32, H256>>::new());
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_collection_item", &item, 0));
+        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
+
+        assert_ok!(LogionLoc::add_collection_item_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), None, false, Vec::new(), submitter, 0, signature));
 
+        assert!(LogionLoc::collection_items(LOC_ID, collection_item_id).is_some());
+        assert_eq!(LogionLoc::ethereum_nonce(H160::from_str(ETHEREUM_ADDRESS).unwrap()), 1);
         let fees = Fees::only_storage(1, FILE_SIZE);
         fees.assert_balances_events(snapshot);
     });
 }
 
 #[test]
-fn it_adds_file_on_logion_identity_loc_when_caller_and_submitter_is_issuer() {
+fn it_fails_adding_collection_item_with_ethereum_signature_if_not_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        nominated_and_select_issuer(LOC_ID);
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
-            size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(LOC_OWNER1, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
+        create_closed_ethereum_collection_loc(H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap());
+        let submitter = SupportedAccountId::Other(OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap()));
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let item = (LOC_ID, collection_item_id, collection_item_description, &Vec::<CollectionItemFileOf<Test>>::new(), &None::<CollectionItemToken<TokenIssuance, H256>>, false, &Vec::<TermsAndConditionsElement<u32, H256>>::new());
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_collection_item", &item, 0));
 
-        let fees = Fees::only_storage(1, FILE_SIZE);
-        fees.assert_balances_events(snapshot);
+        assert_err!(LogionLoc::add_collection_item_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, Vec::new(), None, false, Vec::new(), submitter, 0, signature), Error::<Test>::WrongCollectionLoc);
     });
 }
 
-fn nominated_and_select_issuer(loc_id: u32) {
-    nominate_issuer(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID);
-    assert_ok!(LogionLoc::set_issuer_selection(RuntimeOrigin::signed(LOC_OWNER1), loc_id, ISSUER_ID1, true));
-}
-
 #[test]
-fn it_adds_file_on_polkadot_transaction_loc_when_caller_is_owner_and_submitter_is_issuer() {
+fn it_fails_adding_unsponsored_collection_item_with_ethereum_signature() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        nominated_and_select_issuer(LOC_ID);
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+        let requester_account_id = OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        assert_ok!(LogionLoc::create_other_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id, None, Some(10), true, None));
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let submitter = SupportedAccountId::Other(requester_account_id);
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
             size: FILE_SIZE,
-        };
+        }];
+        let item = (LOC_ID, collection_item_id, collection_item_description, &collection_item_files, &None::<CollectionItemToken<TokenIssuance, H256>>, false, &Vec::<TermsAndConditionsElement<u32, H256>>::new());
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_collection_item", &item, 0));
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
 
-        let fees = Fees::only_storage(1, FILE_SIZE);
-        fees.assert_balances_events(snapshot);
+        assert_err!(LogionLoc::add_collection_item_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files, None, false, Vec::new(), submitter, 0, signature), Error::<Test>::SponsorshipRequired);
+
+        check_no_fees(snapshot);
     });
 }
 
 #[test]
-fn it_adds_file_on_polkadot_transaction_loc_when_caller_is_submitter_and_issuer() {
+fn it_withdraws_certificate_fee_of_ethereum_collection_item_from_dispatcher() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        nominated_and_select_issuer(LOC_ID);
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
-            size: FILE_SIZE,
-        };
+        let submitter = create_closed_ethereum_collection_loc(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_token = Some(erc721_token());
+        let item = (LOC_ID, collection_item_id, collection_item_description, &Vec::<CollectionItemFileOf<Test>>::new(), &collection_item_token, false, &Vec::<TermsAndConditionsElement<u32, H256>>::new());
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_collection_item", &item, 0));
+        let sponsor_balance = Balances::free_balance(SPONSOR_ID);
         let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, NOT_ACKNOWLEDGED));
 
-        let fees = Fees::only_storage(1, FILE_SIZE);
+        assert_ok!(LogionLoc::add_collection_item_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, Vec::new(), collection_item_token, false, Vec::new(), submitter, 0, signature));
+
+        let fees = Fees {
+            storage_fees: 0,
+            legal_fees: 0,
+            legal_fee_beneficiary: None,
+            certificate_fees: 4_000_000_000_000_000,
+        };
         fees.assert_balances_events(snapshot);
+        assert_eq!(Balances::free_balance(SPONSOR_ID), sponsor_balance);
     });
 }
 
 #[test]
-fn it_fails_adding_file_on_polkadot_transaction_loc_cannot_submit() {
+fn it_adds_tokens_record_with_ethereum_signature() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER2),
-            size: FILE_SIZE,
-        };
-        assert_err!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()), Error::<Test>::CannotSubmit);
+        let submitter = create_closed_ethereum_collection_loc(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+        let signature = ethereum_sign(ETHEREUM_SECRET_KEY, LogionLoc::ethereum_signable_hash(b"add_tokens_record", &(LOC_ID, record_id, record_description, &record_files), 0));
+
+        assert_ok!(LogionLoc::add_tokens_record_with_ethereum_signature(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, record_id, record_description, record_files, submitter, 0, signature));
+
+        let record = LogionLoc::tokens_records(LOC_ID, record_id).unwrap();
+        assert_eq!(record.submitter, submitter);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::TokensRecordAdded { 0: LOC_ID, 1: record_id, 2: submitter }));
     });
 }
 
+fn create_closed_ethereum_collection_loc(requester: H160) -> SupportedAccountIdOf<Test> {
+    let requester_account_id = OtherAccountId::Ethereum(requester);
+    let sponsorship_id = 1;
+    let sponsored_account = SupportedAccountId::Other(requester_account_id);
+    assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+    assert_ok!(LogionLoc::create_other_collection_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id, None, Some(10), true, Some(sponsorship_id)));
+    assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+    sponsored_account
+}
+
+fn create_ethereum_identity_loc() -> SupportedAccountIdOf<Test> {
+    let requester = OtherAccountId::Ethereum(H160::from_str(ETHEREUM_ADDRESS).unwrap());
+    let sponsorship_id = 1;
+    let sponsored_account = SupportedAccountId::Other(requester);
+    assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1, SPONSORSHIP_EXPIRY, INITIAL_BALANCE, Some(1)));
+    assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester, sponsorship_id));
+    sponsored_account
+}
+
+fn ethereum_sign(secret_key: &str, hash: [u8; 32]) -> EthereumSignature {
+    let pair = ecdsa::Pair::from_string(secret_key, None).unwrap();
+    EthereumSignature(pair.sign_prehashed(&hash).0)
+}
+
 #[test]
-fn it_adds_metadata_on_logion_identity_loc_for_when_submitter_is_issuer() {
+fn it_fails_adding_item_with_token_with_zero_issuance() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        nominated_and_select_issuer(LOC_ID);
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+
+        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        let collection_item_files = vec![CollectionItemFile {
+            name: sha256(&"picture.png".as_bytes().to_vec()),
+            content_type: sha256(&"image/png".as_bytes().to_vec()),
+            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
+            size: 123456,
+        }];
+        let collection_item_token = CollectionItemToken {
+            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
+            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
+            token_issuance: 0,
         };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), Some(collection_item_token), true, Vec::new()), Error::<Test>::BadTokenIssuance);
     });
 }
 
 #[test]
-fn it_fails_adding_metadata_on_logion_identity_loc_cannot_submit() {
+fn it_gets_locs_by_requester() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER2),
-        };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()), Error::<Test>::CannotSubmit);
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), OTHER_LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, OTHER_LOC_ID, LOC_OWNER1);
+
+        let requester_locs = LogionLoc::get_locs_by_requester(&Account(LOC_REQUESTER_ID), None, 10);
+        let mut sorted_locs = requester_locs.clone();
+        sorted_locs.sort();
+        assert_eq!(sorted_locs, vec![LOC_ID, OTHER_LOC_ID]);
+        assert_eq!(LogionLoc::get_locs_by_requester(&Account(LOC_REQUESTER_ID), None, 1)[..], requester_locs[0..1]);
+        assert_eq!(LogionLoc::get_locs_by_requester(&Account(LOC_REQUESTER_ID), Some(requester_locs[0]), 10)[..], requester_locs[1..2]);
+        assert_eq!(LogionLoc::get_locs_by_requester(&Account(LOC_REQUESTER_ID), Some(requester_locs[1]), 10), vec![]);
+        assert_eq!(LogionLoc::get_locs_by_requester(&Account(LOC_OWNER2), None, 10), vec![]);
+        assert_eq!(LogionLoc::get_locs_by_requester(&Requester::None, None, 10), vec![]);
     });
 }
 
 #[test]
-fn it_adds_metadata_on_polkadot_transaction_loc_when_submitter_is_issuer() {
+fn it_links_locs_to_owner() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        nominated_and_select_issuer(LOC_ID);
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(ISSUER_ID1),
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
+        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::create_logion_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER2, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOGION_CLASSIFICATION_LOC_ID, LOC_OWNER2);
+
+        assert!(LogionLoc::locs_by_owner(LOC_OWNER1, LOC_ID).is_some());
+        assert!(LogionLoc::locs_by_owner(LOC_OWNER1, OTHER_LOC_ID).is_some());
+        assert!(LogionLoc::locs_by_owner(LOC_OWNER2, LOGION_CLASSIFICATION_LOC_ID).is_some());
+        assert!(LogionLoc::locs_by_owner(LOC_OWNER2, LOC_ID).is_none());
+
+        let owner1_locs = LogionLoc::get_locs_by_owner(&LOC_OWNER1, None, None, 10);
+        let mut sorted_owner1_locs = owner1_locs.clone();
+        sorted_owner1_locs.sort();
+        assert_eq!(sorted_owner1_locs, vec![LOC_ID, OTHER_LOC_ID]);
+        assert_eq!(LogionLoc::get_locs_by_owner(&LOC_OWNER1, None, None, 1)[..], owner1_locs[0..1]);
+        assert_eq!(LogionLoc::get_locs_by_owner(&LOC_OWNER1, None, Some(owner1_locs[0]), 10)[..], owner1_locs[1..2]);
+        assert_eq!(LogionLoc::get_locs_by_owner(&LOC_OWNER1, None, Some(owner1_locs[1]), 10), vec![]);
+        assert_eq!(LogionLoc::get_locs_by_owner(&LOC_OWNER2, None, None, 10), vec![LOGION_CLASSIFICATION_LOC_ID]);
+
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID));
+        assert_eq!(LogionLoc::get_locs_by_owner(&LOC_OWNER1, Some(LocStatus::Closed), None, 10), vec![OTHER_LOC_ID]);
+        assert_eq!(LogionLoc::get_locs_by_owner(&LOC_OWNER1, Some(LocStatus::Open), None, 10), vec![LOC_ID]);
+        assert_eq!(LogionLoc::get_locs_by_owner(&LOC_OWNER1, Some(LocStatus::Open), Some(LOC_ID), 10), vec![]);
+        assert_eq!(LogionLoc::get_locs_by_owner(&LOC_OWNER2, Some(LocStatus::Draft), None, 10), vec![]);
     });
 }
 
 #[test]
-fn it_fails_adding_metadata_on_polkadot_transaction_loc_cannot_submit() {
+fn it_gets_collection_items_with_pagination() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        assert_ok!(LogionLoc::create_polkadot_transaction_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: SupportedAccountId::Polkadot(LOC_OWNER2),
-        };
-        assert_err!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()), Error::<Test>::CannotSubmit);
+        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(10), false, None));
+        review_and_accept(LOC_REQUESTER_ID, LOC_ID, LOC_OWNER1);
+        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
+        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
+        for i in 0..3 {
+            let collection_item_id = BlakeTwo256::hash_of(&i.to_string().as_bytes().to_vec());
+            assert_ok!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description.clone(), vec![], None, false, Vec::new()));
+        }
+
+        let all_items = LogionLoc::get_collection_items(&LOC_ID, None, 10);
+        assert_eq!(all_items.len(), 3);
+        assert_eq!(LogionLoc::get_collection_items(&LOC_ID, None, 2)[..], all_items[0..2]);
+        assert_eq!(LogionLoc::get_collection_items(&LOC_ID, Some(all_items[1].0), 2)[..], all_items[2..3]);
+        assert_eq!(LogionLoc::get_collection_items(&LOC_ID, Some(all_items[2].0), 2), vec![]);
+        assert_eq!(LogionLoc::get_collection_items(&OTHER_LOC_ID, None, 10), vec![]);
     });
 }
 
 #[test]
-fn it_creates_sponsorship() {
+fn it_gets_tokens_records_with_pagination() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
+        create_closed_collection_with_selected_issuer();
+        let record_id = build_record_id();
+        let record_description = build_record_description();
+        let record_files = build_record_files(1);
+        assert_ok!(LogionLoc::add_tokens_record(RuntimeOrigin::signed(ISSUER_ID1), LOC_ID, record_id, record_description.clone(), record_files.clone()));
 
-        let sponsorship = LogionLoc::sponsorship(sponsorship_id).unwrap();
-        assert_eq!(sponsorship.legal_officer, LOC_OWNER1);
-        assert_eq!(sponsorship.sponsor, SPONSOR_ID);
-        assert_eq!(sponsorship.sponsored_account, sponsored_account);
-        assert_eq!(sponsorship.loc_id, None);
+        let records = LogionLoc::get_tokens_records(&LOC_ID, None, 10);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].0, record_id);
+        assert_eq!(records[0].1, LogionLoc::tokens_records(LOC_ID, record_id).unwrap());
+        assert_eq!(LogionLoc::get_tokens_records(&LOC_ID, Some(record_id), 10), vec![]);
     });
 }
 
 #[test]
-fn it_fails_creating_sponsorship_with_duplicate_id() {
+fn it_gets_verified_issuers() {
     new_test_ext().execute_with(|| {
         setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
+        create_collection_with_selected_issuer();
 
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
-        assert_err!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1), Error::<Test>::AlreadyExists);
+        assert_eq!(LogionLoc::get_verified_issuers(&LOC_ID), vec![(ISSUER_ID1, ISSUER1_IDENTITY_LOC_ID)]);
+        assert_eq!(LogionLoc::get_verified_issuers(&OTHER_LOC_ID), vec![]);
     });
 }
 
+const RAISED_EXCHANGE_RATE: Balance = 210_000_000_000_000_000; // 1 euro cent = 0.21 LGNT
+
 #[test]
-fn it_withdraws_unused_sponsorship() {
+fn it_sets_exchange_rate() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
-        assert!(LogionLoc::sponsorship(sponsorship_id).is_some());
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, RAISED_EXCHANGE_RATE, 10));
 
-        assert_ok!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id));
+        let record = ExchangeRateRecord {
+            rate: RAISED_EXCHANGE_RATE,
+            valid_from: 1,
+            valid_until: 10,
+        };
+        assert_eq!(LogionLoc::exchange_rate(FiatCurrency::Eur), Some(record.clone()));
+        assert_eq!(LogionLoc::exchange_rate_history((FiatCurrency::Eur, 1, 0)), Some(record));
+        assert_eq!(LogionLoc::exchange_rate(FiatCurrency::Chf), None);
+        assert_eq!(LogionLoc::calculate_legal_fee(LocType::Transaction, &LOC_OWNER1), 2100 * ONE_LGNT);
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ExchangeRateUpdated { 0: FiatCurrency::Eur, 1: RAISED_EXCHANGE_RATE, 2: 1, 3: 10 }));
+    });
+}
 
-        assert!(LogionLoc::sponsorship(sponsorship_id).is_none());
+#[test]
+fn it_falls_back_to_default_exchange_rate_when_expired() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, RAISED_EXCHANGE_RATE, 10));
+        System::set_block_number(11);
+
+        assert_eq!(LogionLoc::current_exchange_rate(FiatCurrency::Eur), EurExchangeRate::get());
+        assert_eq!(LogionLoc::exchange_rate_history((FiatCurrency::Eur, 1, 0)).unwrap().rate, RAISED_EXCHANGE_RATE);
     });
 }
 
 #[test]
-fn it_creates_ethereum_identity_loc() {
+fn it_lets_feeder_set_exchange_rate() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
-        let requester_account_id = OtherAccountId::Ethereum(ethereum_address);
-        let sponsorship_id = 1;
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(ethereum_address));
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
-        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_account_id.clone(), sponsorship_id));
-        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
-            owner: LOC_OWNER1,
-            requester: OtherAccount(requester_account_id.clone()),
-            metadata: vec![],
-            files: vec![],
-            closed: false,
-            loc_type: LocType::Identity,
-            links: vec![],
-            void_info: None,
-            replacer_of: None,
-            collection_last_block_submission: None,
-            collection_max_size: None,
-            collection_can_upload: false,
-            seal: None,
-            sponsorship_id: Some(sponsorship_id),
-        }));
-        assert_eq!(LogionLoc::other_account_locs(requester_account_id), Some(vec![LOC_ID]));
-        assert_eq!(LogionLoc::sponsorship(sponsorship_id).unwrap().loc_id, Some(LOC_ID));
-        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated { 0: LOC_ID }));
+        assert_ok!(LogionLoc::set_exchange_rate_feeder(RuntimeOrigin::root(), Some(EXCHANGE_RATE_FEEDER)));
+        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::ExchangeRateFeederSet { 0: Some(EXCHANGE_RATE_FEEDER) }));
 
-        let fees = Fees::only_legal(160 * ONE_LGNT, Beneficiary::Treasury);
-        fees.assert_balances_events(snapshot);
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::signed(EXCHANGE_RATE_FEEDER), FiatCurrency::Eur, RAISED_EXCHANGE_RATE, 10));
+
+        assert_eq!(LogionLoc::current_exchange_rate(FiatCurrency::Eur), RAISED_EXCHANGE_RATE);
     });
 }
 
 #[test]
-fn it_creates_polkadot_identity_loc() {
+fn it_fails_setting_exchange_rate_if_not_feeder() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let snapshot = BalancesSnapshot::take(LOC_REQUESTER_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::create_polkadot_identity_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1));
-        assert_eq!(LogionLoc::loc(LOC_ID), Some(LegalOfficerCase {
-            owner: LOC_OWNER1,
-            requester: Account(LOC_REQUESTER_ID),
-            metadata: vec![],
-            files: vec![],
-            closed: false,
-            loc_type: LocType::Identity,
-            links: vec![],
-            void_info: None,
-            replacer_of: None,
-            collection_last_block_submission: None,
-            collection_max_size: None,
-            collection_can_upload: false,
-            seal: None,
-            sponsorship_id: None,
-        }));
-        assert_eq!(LogionLoc::account_locs(LOC_REQUESTER_ID), Some(vec![LOC_ID]));
-        System::assert_has_event(RuntimeEvent::LogionLoc(crate::Event::LocCreated { 0: LOC_ID }));
+        assert_ok!(LogionLoc::set_exchange_rate_feeder(RuntimeOrigin::root(), Some(EXCHANGE_RATE_FEEDER)));
 
-        let fees = Fees::only_legal(160 * ONE_LGNT, Beneficiary::Treasury);
-        fees.assert_balances_events(snapshot);
+        assert_err!(LogionLoc::set_exchange_rate(RuntimeOrigin::signed(UNAUTHORIZED_CALLER), FiatCurrency::Eur, RAISED_EXCHANGE_RATE, 10), Error::<Test>::Unauthorized);
     });
 }
 
 #[test]
-fn it_fails_creating_ethereum_identity_loc_if_duplicate_loc_id() {
+fn it_fails_setting_exchange_rate_feeder_if_not_authorized() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
-        let requester_address = OtherAccountId::Ethereum(ethereum_address);
-        let sponsorship_id = 1;
-        let sponsored_account: SupportedAccountId<AccountId, H160> = SupportedAccountId::Other(requester_address);
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address.clone(), sponsorship_id));
-        assert_err!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address.clone(), sponsorship_id), Error::<Test>::AlreadyExists);
+        assert_err!(LogionLoc::set_exchange_rate_feeder(RuntimeOrigin::signed(EXCHANGE_RATE_FEEDER), Some(EXCHANGE_RATE_FEEDER)), BadOrigin);
     });
 }
 
 #[test]
-fn it_fails_creating_several_ethereum_identity_loc_with_single_sponsorship() {
+fn it_fails_feeding_exchange_rate_with_too_large_change() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
-        let requester_address = OtherAccountId::Ethereum(ethereum_address);
-        let sponsorship_id = 1;
-        let sponsored_account: SupportedAccountId<AccountId, H160> = SupportedAccountId::Other(requester_address);
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address.clone(), sponsorship_id));
-        assert_err!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), OTHER_LOC_ID, requester_address.clone(), sponsorship_id), Error::<Test>::CannotLinkToSponsorship);
+        assert_ok!(LogionLoc::set_exchange_rate_feeder(RuntimeOrigin::root(), Some(EXCHANGE_RATE_FEEDER)));
+        let rate = EurExchangeRate::get() + MaxExchangeRateChange::get() * EurExchangeRate::get() + 1;
+
+        assert_err!(LogionLoc::set_exchange_rate(RuntimeOrigin::signed(EXCHANGE_RATE_FEEDER), FiatCurrency::Eur, rate, 10), Error::<Test>::ExchangeRateChangeTooLarge);
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, rate, 10));
     });
 }
 
 #[test]
-fn it_fails_withdrawing_used_sponsorship() {
+fn it_records_exchange_rates_set_in_same_block() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let sponsorship_id = 1;
-        let beneficiary = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let requester_address = OtherAccountId::Ethereum(beneficiary);
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(beneficiary));
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address, sponsorship_id));
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, RAISED_EXCHANGE_RATE, 10));
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, EurExchangeRate::get(), 10));
 
-        assert_err!(LogionLoc::withdraw_sponsorship(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id), Error::<Test>::AlreadyUsed);
+        assert_eq!(LogionLoc::exchange_rate_history((FiatCurrency::Eur, 1, 0)).unwrap().rate, RAISED_EXCHANGE_RATE);
+        assert_eq!(LogionLoc::exchange_rate_history((FiatCurrency::Eur, 1, 1)).unwrap().rate, EurExchangeRate::get());
+        assert_eq!(LogionLoc::current_exchange_rate(FiatCurrency::Eur), EurExchangeRate::get());
     });
 }
 
 #[test]
-fn it_adds_metadata_when_submitter_is_ethereum_requester() {
+fn it_restarts_exchange_rate_history_index_in_next_block() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let ethereum_address = H160::from_str("0x590E9c11b1c2f20210b9b84dc2417B4A7955d4e6").unwrap();
-        let requester_address = OtherAccountId::Ethereum(ethereum_address);
-        let sponsorship_id = 1;
-        let sponsored_account = SupportedAccountId::Other(requester_address);
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, requester_address, sponsorship_id));
-        let metadata = MetadataItemParams {
-            name: sha256(&vec![1, 2, 3]),
-            value: sha256(&vec![4, 5, 6]),
-            submitter: sponsored_account,
-        };
-        assert_ok!(LogionLoc::add_metadata(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, metadata.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.metadata[0], expected_metadata(metadata, ACKNOWLEDGED));
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, RAISED_EXCHANGE_RATE, 10));
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Chf, RAISED_EXCHANGE_RATE, 10));
+        System::set_block_number(2);
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, EurExchangeRate::get(), 10));
+
+        assert_eq!(LogionLoc::exchange_rate_history((FiatCurrency::Eur, 1, 0)).unwrap().rate, RAISED_EXCHANGE_RATE);
+        assert_eq!(LogionLoc::exchange_rate_history((FiatCurrency::Chf, 1, 0)).unwrap().rate, RAISED_EXCHANGE_RATE);
+        assert_eq!(LogionLoc::exchange_rate_history((FiatCurrency::Eur, 2, 0)).unwrap().rate, EurExchangeRate::get());
+        assert!(LogionLoc::exchange_rate_history((FiatCurrency::Eur, 2, 1)).is_none());
     });
 }
 
 #[test]
-fn it_adds_file_when_submitter_is_ethereum_requester() {
+fn it_fails_feeding_exchange_rate_too_early() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        let requester = H160::from_str("0x900edc98db53508e6742723988b872dd08cd09c2").unwrap();
-        let sponsorship_id = 1;
-        let sponsored_account = SupportedAccountId::Other(OtherAccountId::Ethereum(requester));
-        assert_ok!(LogionLoc::sponsor(RuntimeOrigin::signed(SPONSOR_ID), sponsorship_id, sponsored_account, LOC_OWNER1));
-        assert_ok!(LogionLoc::create_other_identity_loc(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, OtherAccountId::Ethereum(requester), sponsorship_id));
-        let file = FileParams {
-            hash: sha256(&"test".as_bytes().to_vec()),
-            nature: sha256(&"test-file-nature".as_bytes().to_vec()),
-            submitter: SupportedAccountId::Other(OtherAccountId::Ethereum(requester)),
-            size: FILE_SIZE,
-        };
-        let snapshot = BalancesSnapshot::take(SPONSOR_ID, LOC_OWNER1);
-        assert_ok!(LogionLoc::add_file(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID, file.clone()));
-        let loc = LogionLoc::loc(LOC_ID).unwrap();
-        assert_eq!(loc.files[0], expected_file(&file, ACKNOWLEDGED));
+        assert_ok!(LogionLoc::set_exchange_rate_feeder(RuntimeOrigin::root(), Some(EXCHANGE_RATE_FEEDER)));
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::signed(EXCHANGE_RATE_FEEDER), FiatCurrency::Eur, RAISED_EXCHANGE_RATE, 100));
+        let rate = RAISED_EXCHANGE_RATE + MaxExchangeRateChange::get() * RAISED_EXCHANGE_RATE;
 
-        let fees = Fees::only_storage(1, file.size);
-        fees.assert_balances_events(snapshot);
+        System::set_block_number(MinExchangeRateFeedInterval::get());
+        assert_err!(LogionLoc::set_exchange_rate(RuntimeOrigin::signed(EXCHANGE_RATE_FEEDER), FiatCurrency::Eur, rate, 100), Error::<Test>::ExchangeRateUpdateTooEarly);
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::signed(EXCHANGE_RATE_FEEDER), FiatCurrency::Chf, ChfExchangeRate::get(), 100));
+
+        System::set_block_number(1 + MinExchangeRateFeedInterval::get());
+        assert_ok!(LogionLoc::set_exchange_rate(RuntimeOrigin::signed(EXCHANGE_RATE_FEEDER), FiatCurrency::Eur, rate, 100));
     });
 }
 
 #[test]
-fn it_fails_adding_item_with_token_with_zero_issuance() {
+fn it_fails_setting_invalid_exchange_rate() {
     new_test_ext().execute_with(|| {
-        setup_default_balances();
-        assert_ok!(LogionLoc::create_collection_loc(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, LOC_OWNER1, None, Some(1), true));
-        assert_ok!(LogionLoc::close(RuntimeOrigin::signed(LOC_OWNER1), LOC_ID));
-
-        let collection_item_id = BlakeTwo256::hash_of(&"item-id".as_bytes().to_vec());
-        let collection_item_description = sha256(&"item-description".as_bytes().to_vec());
-        let collection_item_files = vec![CollectionItemFile {
-            name: sha256(&"picture.png".as_bytes().to_vec()),
-            content_type: sha256(&"image/png".as_bytes().to_vec()),
-            hash: BlakeTwo256::hash_of(&"file content".as_bytes().to_vec()),
-            size: 123456,
-        }];
-        let collection_item_token = CollectionItemToken {
-            token_type: sha256(&"ethereum_erc721".as_bytes().to_vec()),
-            token_id: sha256(&"{\"contract\":\"0x765df6da33c1ec1f83be42db171d7ee334a46df5\",\"token\":\"4391\"}".as_bytes().to_vec()),
-            token_issuance: 0,
-        };
-        assert_err!(LogionLoc::add_collection_item(RuntimeOrigin::signed(LOC_REQUESTER_ID), LOC_ID, collection_item_id, collection_item_description, collection_item_files.clone(), Some(collection_item_token), true, Vec::new()), Error::<Test>::BadTokenIssuance);
+        assert_err!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, 0, 10), Error::<Test>::InvalidExchangeRate);
+        assert_err!(LogionLoc::set_exchange_rate(RuntimeOrigin::root(), FiatCurrency::Eur, RAISED_EXCHANGE_RATE, 0), Error::<Test>::InvalidExchangeRate);
     });
 }
@@ -1,31 +1,12 @@
-//! Autogenerated weights for pallet_logion_loc
+//! Weights for pallet_logion_loc
 //!
 //! The [original template](https://github.com/paritytech/substrate/blob/630422d6108cbaaca893ab213dde69f3bdaa1f6b/.maintain/frame-weight-template.hbs)
 //! was disclosed under [Apache 2.0 license](http://www.apache.org/licenses/LICENSE-2.0).
 //!
-//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 3.0.0
-//! DATE: 2022-02-08, STEPS: `[20, ]`, REPEAT: 10, LOW RANGE: `[]`, HIGH RANGE: `[]`
-//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: None, DB CACHE: 128
-
-// Executed Command:
-// ./target/release/logion-node
-// benchmark
-// --execution
-// wasm
-// --wasm-execution
-// compiled
-// --pallet
-// pallet_logion_loc
-// --extrinsic
-// *
-// --steps
-// 20
-// --repeat
-// 10
-// --output
-// ./pallets/logion_loc/src/weights.rs
-// --template
-// ./scripts/weights-template.hbs
+//! NOT GENERATED: these weights are estimates which were not produced by a benchmark run.
+//! Each function has a benchmark in `benchmarking.rs`; this file must be regenerated with
+//! `logion-node benchmark pallet --pallet pallet_logion_loc --extrinsic '*'` on reference hardware
+//! before a runtime using it is released.
 
 
 #![cfg_attr(rustfmt, rustfmt_skip)]
@@ -41,22 +22,43 @@ pub trait WeightInfo {
     fn create_logion_identity_loc() -> Weight;
     fn create_polkadot_transaction_loc() -> Weight;
     fn create_logion_transaction_loc() -> Weight;
-    fn add_metadata() -> Weight;
-    fn add_file() -> Weight;
-    fn add_link() -> Weight;
-    fn close() -> Weight;
+    fn add_metadata(m: u32, ) -> Weight;
+    fn add_file(f: u32, ) -> Weight;
+    fn add_link(l: u32, ) -> Weight;
+    fn close(m: u32, f: u32, l: u32, ) -> Weight;
+    fn close_and_seal(m: u32, f: u32, l: u32, ) -> Weight;
     fn make_void() -> Weight;
     fn make_void_and_replace() -> Weight;
     fn create_collection_loc() -> Weight;
-    fn add_collection_item() -> Weight;
+    fn add_collection_item(f: u32, t: u32, ) -> Weight;
     fn nominate_issuer() -> Weight;
-    fn dismiss_issuer() -> Weight;
-    fn set_issuer_selection() -> Weight;
-    fn add_tokens_record() -> Weight;
+    fn dismiss_issuer(l: u32, ) -> Weight;
+    fn set_issuer_selection(l: u32, ) -> Weight;
+    fn add_tokens_record(f: u32, ) -> Weight;
     fn create_other_identity_loc() -> Weight;
     fn sponsor() -> Weight;
-    fn acknowledge_metadata() -> Weight;
-    fn acknowledge_file() -> Weight;
+    fn withdraw_sponsorship() -> Weight;
+    fn expire_sponsorships(s: u32, ) -> Weight;
+    fn add_metadata_with_ethereum_signature(m: u32, ) -> Weight;
+    fn add_file_with_ethereum_signature(f: u32, ) -> Weight;
+    fn create_other_transaction_loc() -> Weight;
+    fn create_other_collection_loc() -> Weight;
+    fn add_collection_item_with_ethereum_signature(f: u32, t: u32, ) -> Weight;
+    fn add_tokens_record_with_ethereum_signature(f: u32, ) -> Weight;
+    fn acknowledge_metadata(m: u32, ) -> Weight;
+    fn acknowledge_file(f: u32, ) -> Weight;
+    fn delete_metadata(m: u32, ) -> Weight;
+    fn delete_file(f: u32, ) -> Weight;
+    fn delete_link(l: u32, ) -> Weight;
+    fn request_review() -> Weight;
+    fn accept_loc() -> Weight;
+    fn reject_loc() -> Weight;
+    fn cancel_loc() -> Weight;
+    fn expire_legal_fee_holds(h: u32, ) -> Weight;
+    fn set_exchange_rate() -> Weight;
+    fn set_exchange_rate_feeder() -> Weight;
+    fn snapshot_collection_roots(c: u32, ) -> Weight;
+    fn cancel_loc_with_ethereum_signature() -> Weight;
 }
 
 /// Weights for pallet_logion_loc using the Substrate node and recommended hardware.
@@ -65,104 +67,248 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
     fn create_polkadot_identity_loc() -> Weight {
         Weight::from_parts(29_862_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
     fn create_logion_identity_loc() -> Weight {
         Weight::from_parts(20_945_000, 0)
             .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes(2))
     }
     fn create_polkadot_transaction_loc() -> Weight {
         Weight::from_parts(26_316_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
     fn create_logion_transaction_loc() -> Weight {
         Weight::from_parts(30_288_000, 0)
             .saturating_add(T::DbWeight::get().reads(4))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes(3))
     }
-    fn add_metadata() -> Weight {
+    fn add_metadata(m: u32, ) -> Weight {
         Weight::from_parts(11_979_000, 0)
+            .saturating_add(Weight::from_parts(152_000, 0).saturating_mul(m.into()))
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-    fn add_file() -> Weight {
+    fn add_file(f: u32, ) -> Weight {
         Weight::from_parts(11_971_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(Weight::from_parts(187_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
-    fn add_link() -> Weight {
+    fn add_link(l: u32, ) -> Weight {
         Weight::from_parts(16_067_000, 0)
+            .saturating_add(Weight::from_parts(119_000, 0).saturating_mul(l.into()))
             .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-    fn close() -> Weight {
+    fn close(m: u32, f: u32, l: u32, ) -> Weight {
         Weight::from_parts(22_224_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(Weight::from_parts(92_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(99_000, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(71_000, 0).saturating_mul(l.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(8))
+    }
+    fn close_and_seal(m: u32, f: u32, l: u32, ) -> Weight {
+        Weight::from_parts(22_658_000, 0)
+            .saturating_add(Weight::from_parts(92_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(99_000, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(71_000, 0).saturating_mul(l.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(8))
     }
     fn make_void() -> Weight {
         Weight::from_parts(22_360_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(4))
     }
     fn make_void_and_replace() -> Weight {
         Weight::from_parts(32_724_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
     }
     fn create_collection_loc() -> Weight {
         Weight::from_parts(29_219_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(2))
-    }
-    fn add_collection_item() -> Weight {
-        Weight::from_parts(31_621_000, 0)
-            .saturating_add(T::DbWeight::get().reads(3))
-            .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    fn add_collection_item(f: u32, t: u32, ) -> Weight {
+        Weight::from_parts(52_316_000, 0)
+            .saturating_add(Weight::from_parts(1_248_000, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(3_107_000, 0).saturating_mul(t.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+            .saturating_add(T::DbWeight::get().writes(39))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
     }
     fn nominate_issuer() -> Weight {
         Weight::from_parts(11_971_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads(2))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-    fn dismiss_issuer() -> Weight {
+    fn dismiss_issuer(l: u32, ) -> Weight {
         Weight::from_parts(11_971_000, 0)
+            .saturating_add(Weight::from_parts(5_843_000, 0).saturating_mul(l.into()))
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(l.into())))
     }
-    fn set_issuer_selection() -> Weight {
+    fn set_issuer_selection(l: u32, ) -> Weight {
         Weight::from_parts(11_971_000, 0)
-            .saturating_add(T::DbWeight::get().reads(1))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(Weight::from_parts(1_962_000, 0).saturating_mul(l.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(l.into())))
+            .saturating_add(T::DbWeight::get().writes(2))
     }
-    fn add_tokens_record() -> Weight {
+    fn add_tokens_record(f: u32, ) -> Weight {
         Weight::from_parts(31_621_000, 0)
+            .saturating_add(Weight::from_parts(1_193_000, 0).saturating_mul(f.into()))
             .saturating_add(T::DbWeight::get().reads(3))
             .saturating_add(T::DbWeight::get().writes(2))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
     }
     fn create_other_identity_loc() -> Weight {
         Weight::from_parts(20_945_000, 0)
-            .saturating_add(T::DbWeight::get().reads(2))
-            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
     }
     fn sponsor() -> Weight {
-        Weight::from_parts(20_945_000, 0)
+        Weight::from_parts(22_604_000, 0)
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn withdraw_sponsorship() -> Weight {
+        Weight::from_parts(19_318_000, 0)
             .saturating_add(T::DbWeight::get().reads(2))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn expire_sponsorships(s: u32, ) -> Weight {
+        Weight::from_parts(4_215_000, 0)
+            .saturating_add(Weight::from_parts(6_842_000, 0).saturating_mul(s.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
             .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
     }
-    fn acknowledge_metadata() -> Weight {
+    fn add_metadata_with_ethereum_signature(m: u32, ) -> Weight {
+        Weight::from_parts(62_418_000, 0)
+            .saturating_add(Weight::from_parts(152_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(3))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn add_file_with_ethereum_signature(f: u32, ) -> Weight {
+        Weight::from_parts(62_735_000, 0)
+            .saturating_add(Weight::from_parts(187_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    fn create_other_transaction_loc() -> Weight {
+        Weight::from_parts(21_308_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(6))
+    }
+    fn create_other_collection_loc() -> Weight {
+        Weight::from_parts(21_754_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+    fn add_collection_item_with_ethereum_signature(f: u32, t: u32, ) -> Weight {
+        Weight::from_parts(103_859_000, 0)
+            .saturating_add(Weight::from_parts(1_248_000, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(3_107_000, 0).saturating_mul(t.into()))
+            .saturating_add(T::DbWeight::get().reads(8))
+            .saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(t.into())))
+            .saturating_add(T::DbWeight::get().writes(41))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+    }
+    fn add_tokens_record_with_ethereum_signature(f: u32, ) -> Weight {
+        Weight::from_parts(82_917_000, 0)
+            .saturating_add(Weight::from_parts(1_193_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(4))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(f.into())))
+    }
+    fn acknowledge_metadata(m: u32, ) -> Weight {
         Weight::from_parts(11_979_000, 0)
+            .saturating_add(Weight::from_parts(98_000, 0).saturating_mul(m.into()))
             .saturating_add(T::DbWeight::get().reads(1))
             .saturating_add(T::DbWeight::get().writes(1))
     }
-
-    fn acknowledge_file() -> Weight {
+    fn acknowledge_file(f: u32, ) -> Weight {
         Weight::from_parts(11_979_000, 0)
+            .saturating_add(Weight::from_parts(101_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn delete_metadata(m: u32, ) -> Weight {
+        Weight::from_parts(12_604_000, 0)
+            .saturating_add(Weight::from_parts(131_000, 0).saturating_mul(m.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn delete_file(f: u32, ) -> Weight {
+        Weight::from_parts(13_187_000, 0)
+            .saturating_add(Weight::from_parts(137_000, 0).saturating_mul(f.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(2))
+    }
+    fn delete_link(l: u32, ) -> Weight {
+        Weight::from_parts(12_451_000, 0)
+            .saturating_add(Weight::from_parts(109_000, 0).saturating_mul(l.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn request_review() -> Weight {
+        Weight::from_parts(23_912_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    fn accept_loc() -> Weight {
+        Weight::from_parts(31_450_000, 0)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+    fn reject_loc() -> Weight {
+        Weight::from_parts(19_527_000, 0)
+            .saturating_add(T::DbWeight::get().reads(5))
+            .saturating_add(T::DbWeight::get().writes(5))
+    }
+    fn cancel_loc() -> Weight {
+        Weight::from_parts(24_836_000, 0)
+            .saturating_add(T::DbWeight::get().reads(6))
+            .saturating_add(T::DbWeight::get().writes(7))
+    }
+    fn expire_legal_fee_holds(h: u32, ) -> Weight {
+        Weight::from_parts(3_981_000, 0)
+            .saturating_add(Weight::from_parts(17_336_000, 0).saturating_mul(h.into()))
+            .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(h.into())))
+            .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((3_u64).saturating_mul(h.into())))
+    }
+    fn set_exchange_rate() -> Weight {
+        Weight::from_parts(16_482_000, 0)
+            .saturating_add(T::DbWeight::get().reads(4))
+            .saturating_add(T::DbWeight::get().writes(3))
+    }
+    fn set_exchange_rate_feeder() -> Weight {
+        Weight::from_parts(9_118_000, 0)
+            .saturating_add(T::DbWeight::get().writes(1))
+    }
+    fn snapshot_collection_roots(c: u32, ) -> Weight {
+        Weight::from_parts(3_214_000, 0)
+            .saturating_add(Weight::from_parts(12_486_000, 0).saturating_mul(c.into()))
             .saturating_add(T::DbWeight::get().reads(1))
+            .saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(c.into())))
             .saturating_add(T::DbWeight::get().writes(1))
+            .saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(c.into())))
+    }
+    fn cancel_loc_with_ethereum_signature() -> Weight {
+        Weight::from_parts(76_089_000, 0)
+            .saturating_add(T::DbWeight::get().reads(7))
+            .saturating_add(T::DbWeight::get().writes(8))
     }
 }
 
@@ -170,102 +316,247 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 impl WeightInfo for () {
     fn create_polkadot_identity_loc() -> Weight {
         Weight::from_parts(29_862_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
     fn create_logion_identity_loc() -> Weight {
         Weight::from_parts(20_945_000, 0)
             .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
     }
     fn create_polkadot_transaction_loc() -> Weight {
         Weight::from_parts(26_316_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
     fn create_logion_transaction_loc() -> Weight {
         Weight::from_parts(30_288_000, 0)
             .saturating_add(RocksDbWeight::get().reads(4))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes(3))
     }
-    fn add_metadata() -> Weight {
+    fn add_metadata(m: u32, ) -> Weight {
         Weight::from_parts(11_979_000, 0)
+            .saturating_add(Weight::from_parts(152_000, 0).saturating_mul(m.into()))
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
-    fn add_file() -> Weight {
+    fn add_file(f: u32, ) -> Weight {
         Weight::from_parts(11_971_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(Weight::from_parts(187_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
-    fn add_link() -> Weight {
+    fn add_link(l: u32, ) -> Weight {
         Weight::from_parts(16_067_000, 0)
+            .saturating_add(Weight::from_parts(119_000, 0).saturating_mul(l.into()))
             .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
-    fn close() -> Weight {
+    fn close(m: u32, f: u32, l: u32, ) -> Weight {
         Weight::from_parts(22_224_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(Weight::from_parts(92_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(99_000, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(71_000, 0).saturating_mul(l.into()))
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(8))
+    }
+    fn close_and_seal(m: u32, f: u32, l: u32, ) -> Weight {
+        Weight::from_parts(22_658_000, 0)
+            .saturating_add(Weight::from_parts(92_000, 0).saturating_mul(m.into()))
+            .saturating_add(Weight::from_parts(99_000, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(71_000, 0).saturating_mul(l.into()))
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(8))
     }
     fn make_void() -> Weight {
         Weight::from_parts(22_360_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(4))
     }
     fn make_void_and_replace() -> Weight {
         Weight::from_parts(32_724_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(5))
     }
     fn create_collection_loc() -> Weight {
         Weight::from_parts(29_219_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(2))
-    }
-    fn add_collection_item() -> Weight {
-        Weight::from_parts(31_621_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(3))
-            .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+    fn add_collection_item(f: u32, t: u32, ) -> Weight {
+        Weight::from_parts(52_316_000, 0)
+            .saturating_add(Weight::from_parts(1_248_000, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(3_107_000, 0).saturating_mul(t.into()))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t.into())))
+            .saturating_add(RocksDbWeight::get().writes(39))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
     }
     fn nominate_issuer() -> Weight {
         Weight::from_parts(11_971_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads(2))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
-    fn dismiss_issuer() -> Weight {
+    fn dismiss_issuer(l: u32, ) -> Weight {
         Weight::from_parts(11_971_000, 0)
+            .saturating_add(Weight::from_parts(5_843_000, 0).saturating_mul(l.into()))
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(l.into())))
     }
-    fn set_issuer_selection() -> Weight {
+    fn set_issuer_selection(l: u32, ) -> Weight {
         Weight::from_parts(11_971_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(1))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(Weight::from_parts(1_962_000, 0).saturating_mul(l.into()))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(l.into())))
+            .saturating_add(RocksDbWeight::get().writes(2))
     }
-    fn add_tokens_record() -> Weight {
+    fn add_tokens_record(f: u32, ) -> Weight {
         Weight::from_parts(31_621_000, 0)
+            .saturating_add(Weight::from_parts(1_193_000, 0).saturating_mul(f.into()))
             .saturating_add(RocksDbWeight::get().reads(3))
             .saturating_add(RocksDbWeight::get().writes(2))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
     }
     fn create_other_identity_loc() -> Weight {
         Weight::from_parts(20_945_000, 0)
-            .saturating_add(RocksDbWeight::get().reads(2))
-            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
     }
     fn sponsor() -> Weight {
-        Weight::from_parts(20_945_000, 0)
+        Weight::from_parts(22_604_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn withdraw_sponsorship() -> Weight {
+        Weight::from_parts(19_318_000, 0)
             .saturating_add(RocksDbWeight::get().reads(2))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn expire_sponsorships(s: u32, ) -> Weight {
+        Weight::from_parts(4_215_000, 0)
+            .saturating_add(Weight::from_parts(6_842_000, 0).saturating_mul(s.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
             .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
     }
-    fn acknowledge_metadata() -> Weight {
+    fn add_metadata_with_ethereum_signature(m: u32, ) -> Weight {
+        Weight::from_parts(62_418_000, 0)
+            .saturating_add(Weight::from_parts(152_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn add_file_with_ethereum_signature(f: u32, ) -> Weight {
+        Weight::from_parts(62_735_000, 0)
+            .saturating_add(Weight::from_parts(187_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn create_other_transaction_loc() -> Weight {
+        Weight::from_parts(21_308_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(6))
+    }
+    fn create_other_collection_loc() -> Weight {
+        Weight::from_parts(21_754_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(7))
+    }
+    fn add_collection_item_with_ethereum_signature(f: u32, t: u32, ) -> Weight {
+        Weight::from_parts(103_859_000, 0)
+            .saturating_add(Weight::from_parts(1_248_000, 0).saturating_mul(f.into()))
+            .saturating_add(Weight::from_parts(3_107_000, 0).saturating_mul(t.into()))
+            .saturating_add(RocksDbWeight::get().reads(8))
+            .saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(t.into())))
+            .saturating_add(RocksDbWeight::get().writes(41))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+    }
+    fn add_tokens_record_with_ethereum_signature(f: u32, ) -> Weight {
+        Weight::from_parts(82_917_000, 0)
+            .saturating_add(Weight::from_parts(1_193_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(4))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(f.into())))
+    }
+    fn acknowledge_metadata(m: u32, ) -> Weight {
         Weight::from_parts(11_979_000, 0)
+            .saturating_add(Weight::from_parts(98_000, 0).saturating_mul(m.into()))
             .saturating_add(RocksDbWeight::get().reads(1))
             .saturating_add(RocksDbWeight::get().writes(1))
     }
-    fn acknowledge_file() -> Weight {
+    fn acknowledge_file(f: u32, ) -> Weight {
         Weight::from_parts(11_979_000, 0)
+            .saturating_add(Weight::from_parts(101_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn delete_metadata(m: u32, ) -> Weight {
+        Weight::from_parts(12_604_000, 0)
+            .saturating_add(Weight::from_parts(131_000, 0).saturating_mul(m.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn delete_file(f: u32, ) -> Weight {
+        Weight::from_parts(13_187_000, 0)
+            .saturating_add(Weight::from_parts(137_000, 0).saturating_mul(f.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn delete_link(l: u32, ) -> Weight {
+        Weight::from_parts(12_451_000, 0)
+            .saturating_add(Weight::from_parts(109_000, 0).saturating_mul(l.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn request_review() -> Weight {
+        Weight::from_parts(23_912_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn accept_loc() -> Weight {
+        Weight::from_parts(31_450_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(7))
+    }
+    fn reject_loc() -> Weight {
+        Weight::from_parts(19_527_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(5))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
+    fn cancel_loc() -> Weight {
+        Weight::from_parts(24_836_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(6))
+            .saturating_add(RocksDbWeight::get().writes(7))
+    }
+    fn expire_legal_fee_holds(h: u32, ) -> Weight {
+        Weight::from_parts(3_981_000, 0)
+            .saturating_add(Weight::from_parts(17_336_000, 0).saturating_mul(h.into()))
             .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(h.into())))
             .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((3_u64).saturating_mul(h.into())))
+    }
+    fn set_exchange_rate() -> Weight {
+        Weight::from_parts(16_482_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(3))
+    }
+    fn set_exchange_rate_feeder() -> Weight {
+        Weight::from_parts(9_118_000, 0)
+            .saturating_add(RocksDbWeight::get().writes(1))
+    }
+    fn snapshot_collection_roots(c: u32, ) -> Weight {
+        Weight::from_parts(3_214_000, 0)
+            .saturating_add(Weight::from_parts(12_486_000, 0).saturating_mul(c.into()))
+            .saturating_add(RocksDbWeight::get().reads(1))
+            .saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(c.into())))
+            .saturating_add(RocksDbWeight::get().writes(1))
+            .saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(c.into())))
+    }
+    fn cancel_loc_with_ethereum_signature() -> Weight {
+        Weight::from_parts(76_089_000, 0)
+            .saturating_add(RocksDbWeight::get().reads(7))
+            .saturating_add(RocksDbWeight::get().writes(8))
     }
 }